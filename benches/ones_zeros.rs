@@ -0,0 +1,59 @@
+//! Compares word-skipping `ones`/`zeros` with bit-by-bit iteration over sparse and dense bitsets.
+//!
+//! Run with `cargo bench --bench ones_zeros`.
+
+use bitworks::prelude::*;
+use std::{hint::black_box, time::Instant};
+
+const ROUNDS: u32 = 100_000;
+
+fn measure(name: &str, mut f: impl FnMut() -> usize) {
+    let start = Instant::now();
+    let mut total = 0;
+    for _ in 0..ROUNDS {
+        total += black_box(f());
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<40} {:>10.1?}/iter (checksum {total})",
+        elapsed / ROUNDS
+    );
+}
+
+fn compare<T>(name: &str, bitset: T)
+where
+    T: Bitset,
+{
+    measure(&format!("{name} ones"), || {
+        black_box(&bitset).ones().map(usize::from).sum()
+    });
+    measure(&format!("{name} ones (bit by bit)"), || {
+        black_box(&bitset)
+            .bits_ref()
+            .enumerate()
+            .filter_map(|(i, bit)| (*bit == One).then_some(i))
+            .sum()
+    });
+    measure(&format!("{name} zeros"), || {
+        black_box(&bitset).zeros().map(usize::from).sum()
+    });
+    measure(&format!("{name} zeros (bit by bit)"), || {
+        black_box(&bitset)
+            .bits_ref()
+            .enumerate()
+            .filter_map(|(i, bit)| (*bit == Zero).then_some(i))
+            .sum()
+    });
+}
+
+fn main() {
+    compare("Bitset128 sparse", Bitset128::new(1 << 3 | 1 << 97));
+    compare("Bitset128 dense", Bitset128::new(!(1 << 3 | 1 << 97)));
+
+    let mut sparse = Byteset::<64>::NONE;
+    sparse
+        .set(Index::try_from(5).unwrap())
+        .set(Index::try_from(400).unwrap());
+    compare("Byteset<64> sparse", sparse);
+    compare("Byteset<64> dense", !sparse);
+}
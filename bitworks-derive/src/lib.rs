@@ -0,0 +1,291 @@
+//! Derive macros for [`bitworks`](https://docs.rs/bitworks).
+//!
+//! Use through feature `"derive"` of `bitworks`, rather than directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, ExprLit, Fields, Ident,
+    Lit, LitInt, Type,
+};
+
+/// Derives `bitworks::flags_enum::FlagsEnum` for a fieldless enum.
+///
+/// Each variant is mapped to the bit at its discriminant. Explicit discriminants have to be integer literals.
+/// `Bitset` defaults to the smallest built-in one, fitting all of the variants,
+/// and can be chosen with `#[flags_enum(bitset = Bitset32)]`.
+#[proc_macro_derive(FlagsEnum, attributes(flags_enum))]
+pub fn derive_flags_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    flags_enum(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn flags_enum(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            "FlagsEnum can only be derived for enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "FlagsEnum can't be derived for generic enums",
+        ));
+    }
+
+    // Variants paired with their discriminants.
+    let mut variants = Vec::new();
+    let mut next = 0usize;
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.fields.span(),
+                "FlagsEnum variants can't have fields",
+            ));
+        }
+        let index = match &variant.discriminant {
+            Some((
+                _,
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(int), ..
+                }),
+            )) => int.base10_parse::<usize>()?,
+            Some((_, expr)) => {
+                return Err(Error::new(
+                    expr.span(),
+                    "FlagsEnum discriminants have to be integer literals",
+                ))
+            }
+            None => next,
+        };
+        next = index + 1;
+        variants.push((&variant.ident, index));
+    }
+    variants.sort_by_key(|(_, index)| *index);
+
+    let max = variants.last().map_or(0, |(_, index)| *index);
+    let bitset: Type = match bitset_attr(&input, "flags_enum")? {
+        Some(bitset) => bitset,
+        None => match max {
+            0..=7 => syn::parse_quote!(::bitworks::prelude::Bitset8),
+            8..=15 => syn::parse_quote!(::bitworks::prelude::Bitset16),
+            16..=31 => syn::parse_quote!(::bitworks::prelude::Bitset32),
+            32..=63 => syn::parse_quote!(::bitworks::prelude::Bitset64),
+            64..=127 => syn::parse_quote!(::bitworks::prelude::Bitset128),
+            128..=255 => syn::parse_quote!(::bitworks::prelude::Bitset256),
+            256..=511 => syn::parse_quote!(::bitworks::prelude::Bitset512),
+            _ => {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "FlagsEnum variants don't fit into any fixed size Bitset, \
+                     choose one with #[flags_enum(bitset = ...)]",
+                ))
+            }
+        },
+    };
+
+    let message = format!("variants of `{name}` don't fit into the Bitset");
+    let idents = variants.iter().map(|(ident, _)| ident);
+    let arms = variants.iter().map(|(ident, index)| {
+        quote! { Self::#ident => ::bitworks::index::Index::from_usize(#index) }
+    });
+    let name_arms = variants.iter().map(|(ident, _)| {
+        let name = ident.to_string();
+        quote! { Self::#ident => #name }
+    });
+
+    Ok(quote! {
+        const _: () = assert!(
+            #max < <#bitset as ::bitworks::bitset::Bitset>::BYTE_SIZE * 8,
+            #message
+        );
+
+        impl ::bitworks::flags_enum::FlagsEnum for #name {
+            type Bitset = #bitset;
+            const VARIANTS: &'static [Self] = &[#(Self::#idents),*];
+
+            #[inline(always)]
+            fn index(self) -> ::bitworks::index::Index<Self::Bitset> {
+                match self {
+                    #(#arms),*
+                }
+            }
+
+            #[inline(always)]
+            fn name(self) -> &'static str {
+                match self {
+                    #(#name_arms),*
+                }
+            }
+        }
+    })
+}
+
+/// Derives `bitworks::bitset_struct::BitsetStruct` for a struct with named fields.
+///
+/// Fields are packed into consecutive bit ranges of the `Bitset`, chosen with `#[bitset_struct(bitset = Bitset16)]`,
+/// starting from bit 0, in order of declaration.
+/// Width of each field is set with `#[bits(N)]`, up to 64 bits; `bool` fields default to 1 bit.
+/// Field types have to implement `Into<u64>` and `TryFrom<u64>`, or be `bool`.
+///
+/// Also generates `get_<field>` and `set_<field>` associated functions,
+/// reading and writing a single field of the packed `Bitset`.
+#[proc_macro_derive(BitsetStruct, attributes(bitset_struct, bits))]
+pub fn derive_bitset_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    bitset_struct(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn bitset_struct(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            "BitsetStruct can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "BitsetStruct can only be derived for structs with named fields",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "BitsetStruct can't be derived for generic structs",
+        ));
+    }
+    let Some(bitset) = bitset_attr(&input, "bitset_struct")? else {
+        return Err(Error::new(
+            Span::call_site(),
+            "BitsetStruct requires #[bitset_struct(bitset = ...)]",
+        ));
+    };
+
+    let mut offset = 0usize;
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+    let mut accessors = Vec::new();
+    let mut checks = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let is_bool = matches!(ty, Type::Path(path) if path.path.is_ident("bool"));
+        let width = match bits_attr(field)? {
+            Some(width) => width,
+            None if is_bool => 1,
+            None => {
+                return Err(Error::new(
+                    field.span(),
+                    "BitsetStruct fields require #[bits(N)], unless they are `bool`",
+                ))
+            }
+        };
+        if width == 0 || width > 64 || (is_bool && width != 1) {
+            return Err(Error::new(
+                field.span(),
+                "BitsetStruct fields have to be 1 to 64 bits wide, and `bool` fields exactly 1 bit",
+            ));
+        }
+
+        let read = if is_bool {
+            quote! { Ok(::bitworks::bitset::Bitset::extract_bits::<u8>(bits, #offset, #width)? != 0) }
+        } else {
+            quote! { ::bitworks::bitset::Bitset::extract_bits::<#ty>(bits, #offset, #width) }
+        };
+        let write = quote! {
+            ::bitworks::bitset::Bitset::insert_bits(bits, #offset, #width, ::core::clone::Clone::clone(value))?;
+        };
+        let message = format!("field `{ident}` of `{name}` is wider than its type");
+        if !is_bool {
+            checks.push(quote! {
+                const _: () = assert!(#width <= ::core::mem::size_of::<#ty>() * 8, #message);
+            });
+        }
+
+        let get = Ident::new(&format!("get_{ident}"), ident.span());
+        let set = Ident::new(&format!("set_{ident}"), ident.span());
+        let get_doc = format!("Returns field `{ident}` of the packed `Bitset`.");
+        let set_doc = format!("Writes field `{ident}` into the packed `Bitset`.");
+        accessors.push(quote! {
+            #[doc = #get_doc]
+            #[inline]
+            pub fn #get(bits: &#bitset) -> ::bitworks::error::ConvResult<#ty> {
+                #read
+            }
+
+            #[doc = #set_doc]
+            #[inline]
+            pub fn #set(bits: &mut #bitset, value: &#ty) -> ::bitworks::error::ConvResult<()> {
+                #write
+                Ok(())
+            }
+        });
+        reads.push(quote! { #ident: Self::#get(bits)? });
+        writes.push(quote! { Self::#set(&mut bits, &self.#ident)?; });
+        offset += width;
+    }
+
+    let message = format!("fields of `{name}` don't fit into the Bitset");
+    Ok(quote! {
+        const _: () = assert!(
+            #offset <= <#bitset as ::bitworks::bitset::Bitset>::BYTE_SIZE * 8,
+            #message
+        );
+        #(#checks)*
+
+        impl #name {
+            #(#accessors)*
+        }
+
+        impl ::bitworks::bitset_struct::BitsetStruct for #name {
+            type Bitset = #bitset;
+            const WIDTH: usize = #offset;
+
+            fn from_bits(bits: &Self::Bitset) -> ::bitworks::error::ConvResult<Self> {
+                Ok(Self {
+                    #(#reads),*
+                })
+            }
+
+            fn to_bits(&self) -> ::bitworks::error::ConvResult<Self::Bitset> {
+                let mut bits = <Self::Bitset as ::bitworks::bitset::Bitset>::NONE.clone();
+                #(#writes)*
+                Ok(bits)
+            }
+        }
+    })
+}
+
+// Parses `#[bits(N)]` attribute of a field.
+fn bits_attr(field: &syn::Field) -> syn::Result<Option<usize>> {
+    let mut width = None;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("bits")) {
+        width = Some(attr.parse_args::<LitInt>()?.base10_parse()?);
+    }
+    Ok(width)
+}
+
+// Parses `#[<name>(bitset = Type)]` attribute, e.g. `#[flags_enum(bitset = Type)]`.
+fn bitset_attr(input: &DeriveInput, name: &str) -> syn::Result<Option<Type>> {
+    let mut bitset = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident(name)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bitset") {
+                bitset = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `bitset = Type`"))
+            }
+        })?;
+    }
+    Ok(bitset)
+}
@@ -0,0 +1,424 @@
+//! Module containing access-controlled views into bit ranges of a [`Bitset`]:
+//! [`Field`] with [`ReadOnly`], [`WriteOnly`] and [`ReadWrite`] access markers, and [`ReservedMask`],
+//! as well as [`FieldSpec`], adapter for externally generated field descriptions.
+//!
+//! Useful for register-mapped bitsets, where some bits can only be read, some can only be written,
+//! and some are reserved and must be preserved by writes.
+//! Forbidden accesses are prevented at compile time.
+
+use crate::bitset::Bitset;
+use std::marker::PhantomData;
+
+/// Marker of the kind of access allowed to a [`Field`].
+pub trait Access {}
+
+/// [`Access`] marker, allowing reads.
+pub trait Readable: Access {}
+
+/// [`Access`] marker, allowing writes.
+pub trait Writable: Access {}
+
+/// [`Access`] marker of read-only [`Field`]s.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ReadOnly;
+
+/// [`Access`] marker of write-only [`Field`]s.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WriteOnly;
+
+/// [`Access`] marker of [`Field`]s, that can be both read and written.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ReadWrite;
+
+impl Access for ReadOnly {}
+impl Access for WriteOnly {}
+impl Access for ReadWrite {}
+
+impl Readable for ReadOnly {}
+impl Readable for ReadWrite {}
+
+impl Writable for WriteOnly {}
+impl Writable for ReadWrite {}
+
+/// Range of `WIDTH` (up to 64) bits of a [`Bitset`] `T`, starting from bit `OFFSET`,
+/// access to which is controlled by marker `A`.
+///
+/// Bounds of the range are checked at compile time.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{access::ReadOnlyBits, prelude::*};
+///
+/// const STATUS: ReadOnlyBits<Bitset16, 4, 3> = ReadOnlyBits::new();
+///
+/// let register = Bitset16::new(0b0000000001010000);
+///
+/// assert_eq!(STATUS.read(&register), 0b101);
+/// // Doesn't compile, as `STATUS` is read-only:
+/// // STATUS.write(&mut register, 0b111);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Field<T, A, const OFFSET: usize, const WIDTH: usize>(PhantomData<(T, A)>)
+where
+    T: Bitset,
+    A: Access;
+
+/// Read-only [`Field`].
+pub type ReadOnlyBits<T, const OFFSET: usize, const WIDTH: usize> =
+    Field<T, ReadOnly, OFFSET, WIDTH>;
+
+/// Write-only [`Field`].
+pub type WriteOnlyBits<T, const OFFSET: usize, const WIDTH: usize> =
+    Field<T, WriteOnly, OFFSET, WIDTH>;
+
+/// [`Field`], that can be both read and written.
+pub type ReadWriteBits<T, const OFFSET: usize, const WIDTH: usize> =
+    Field<T, ReadWrite, OFFSET, WIDTH>;
+
+impl<T, A, const OFFSET: usize, const WIDTH: usize> Field<T, A, OFFSET, WIDTH>
+where
+    T: Bitset,
+    A: Access,
+{
+    const IN_BOUNDS: () = assert!(
+        WIDTH <= 64 && OFFSET + WIDTH <= T::BYTE_SIZE * 8,
+        "field must be at most 64 bits wide and fit into the Bitset"
+    );
+
+    /// Constructs a new value of `Field`.
+    #[allow(clippy::let_unit_value)]
+    pub const fn new() -> Self {
+        let _ = Self::IN_BOUNDS;
+        Self(PhantomData)
+    }
+
+    /// Returns the offset of the `Field` in bits.
+    #[inline(always)]
+    pub const fn offset(&self) -> usize {
+        OFFSET
+    }
+
+    /// Returns the width of the `Field` in bits.
+    #[inline(always)]
+    pub const fn width(&self) -> usize {
+        WIDTH
+    }
+
+    /// Returns `Bitset` with only the bits of the `Field` set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{access::ReadWriteBits, prelude::*};
+    ///
+    /// let field = ReadWriteBits::<Bitset8, 2, 3>::new();
+    ///
+    /// assert_eq!(field.mask(), Bitset8::new(0b00011100));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn mask(&self) -> T {
+        T::NONE.clone().insert_range(OFFSET..OFFSET + WIDTH).build()
+    }
+}
+
+impl<T, A, const OFFSET: usize, const WIDTH: usize> Field<T, A, OFFSET, WIDTH>
+where
+    T: Bitset,
+    A: Readable,
+{
+    /// Returns bits of the `Field` in `bitset`, packed into [`u64`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{access::ReadWriteBits, prelude::*};
+    ///
+    /// let field = ReadWriteBits::<Bitset8, 2, 3>::new();
+    ///
+    /// assert_eq!(field.read(&Bitset8::new(0b11101011)), 0b010);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn read(&self, bitset: &T) -> u64 {
+        bitset._bits(OFFSET, WIDTH)
+    }
+}
+
+impl<T, A, const OFFSET: usize, const WIDTH: usize> Field<T, A, OFFSET, WIDTH>
+where
+    T: Bitset,
+    A: Writable,
+{
+    /// Writes lowest `WIDTH` bits of `value` into the `Field` in `bitset`.
+    /// Other bits of `value` are ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{access::WriteOnlyBits, prelude::*};
+    ///
+    /// let field = WriteOnlyBits::<Bitset8, 2, 3>::new();
+    /// let mut bitset = Bitset8::new(0b11111111);
+    /// field.write(&mut bitset, 0b010);
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b11101011);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn write(&self, bitset: &mut T, value: u64) {
+        bitset._set_bits(OFFSET, WIDTH, value);
+    }
+}
+
+impl<T, A, const OFFSET: usize, const WIDTH: usize> Clone for Field<T, A, OFFSET, WIDTH>
+where
+    T: Bitset,
+    A: Access,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, A, const OFFSET: usize, const WIDTH: usize> Copy for Field<T, A, OFFSET, WIDTH>
+where
+    T: Bitset,
+    A: Access,
+{
+}
+
+impl<T, A, const OFFSET: usize, const WIDTH: usize> Default for Field<T, A, OFFSET, WIDTH>
+where
+    T: Bitset,
+    A: Access,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Description of a field of a register, like the ones generated from SVD files by svd2rust.
+///
+/// Implementors can be used with [`Bitset::extract`] and [`Bitset::insert`]
+/// to read and write typed values of the field, e.g. enumerated values.
+/// Bounds of the field are checked at compile time.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{access::FieldSpec, prelude::*};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Mode {
+///     Input = 0,
+///     Output = 1,
+///     Analog = 3,
+/// }
+///
+/// impl From<Mode> for u64 {
+///     fn from(mode: Mode) -> u64 {
+///         mode as u64
+///     }
+/// }
+///
+/// impl TryFrom<u64> for Mode {
+///     type Error = ();
+///
+///     fn try_from(value: u64) -> Result<Self, ()> {
+///         match value {
+///             0 => Ok(Mode::Input),
+///             1 => Ok(Mode::Output),
+///             3 => Ok(Mode::Analog),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// struct Mode1;
+///
+/// impl FieldSpec for Mode1 {
+///     type Bitset = Bitset32;
+///     type Value = Mode;
+///     const OFFSET: usize = 2;
+///     const WIDTH: usize = 2;
+/// }
+///
+/// let mut register = Bitset32::NONE;
+/// register.insert::<Mode1>(Mode::Analog);
+///
+/// assert_eq!(register.into_inner(), 0b1100);
+/// assert_eq!(register.extract::<Mode1>(), Some(Mode::Analog));
+/// #   Ok(())
+/// # }
+/// ```
+pub trait FieldSpec {
+    /// `Bitset`, containing the field.
+    type Bitset: Bitset;
+    /// Type of values of the field.
+    type Value: Into<u64> + TryFrom<u64>;
+    /// Offset of the field in bits.
+    const OFFSET: usize;
+    /// Width of the field in bits, up to 64.
+    const WIDTH: usize;
+}
+
+/// Mask of reserved bits of a [`Bitset`], that must be preserved by writes.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{access::ReservedMask, prelude::*};
+///
+/// let reserved = ReservedMask::new(Bitset8::new(0b11110000));
+/// let mut register = Bitset8::new(0b10100000);
+/// reserved.write(&mut register, Bitset8::new(0b01011111));
+///
+/// assert_eq!(register.into_inner(), 0b10101111);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReservedMask<T>
+where
+    T: Bitset,
+{
+    reserved: T,
+}
+
+impl<T> ReservedMask<T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `ReservedMask`, in which set bits of `reserved` are reserved.
+    #[inline(always)]
+    pub fn new(reserved: T) -> Self {
+        Self { reserved }
+    }
+
+    /// Returns `Bitset` with only the reserved bits set.
+    #[inline(always)]
+    pub fn reserved(&self) -> &T {
+        &self.reserved
+    }
+
+    /// Returns [`true`], if `value` doesn't have any of the reserved bits set, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_valid(&self, value: &T) -> bool {
+        !value.intersects(&self.reserved)
+    }
+
+    /// Writes not reserved bits of `value` into `bitset`, preserving the reserved bits of `bitset`.
+    pub fn write(&self, bitset: &mut T, value: T) {
+        let preserved = bitset.clone().intersection(self.reserved.clone());
+        *bitset = value.difference(self.reserved.clone()).union(preserved);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset32, Byteset};
+
+    type Tested = Byteset<12>;
+
+    const LOW: ReadOnlyBits<Tested, 0, 8> = Field::new();
+    const CROSSING: ReadWriteBits<Tested, 60, 10> = Field::new();
+    const HIGH: WriteOnlyBits<Tested, 90, 6> = Field::new();
+
+    #[test]
+    fn fields() {
+        let mut bitset = Tested::NONE;
+
+        CROSSING.write(&mut bitset, 0b1111100001);
+        HIGH.write(&mut bitset, !0);
+
+        assert_eq!(LOW.read(&bitset), 0);
+        assert_eq!(CROSSING.read(&bitset), 0b1111100001);
+        assert_eq!(
+            bitset.to_ranges().collect::<Vec<_>>(),
+            [60..61, 65..70, 90..96]
+        );
+        assert_eq!(HIGH.mask().to_ranges().next(), Some(90..96));
+        assert_eq!((CROSSING.offset(), CROSSING.width()), (60, 10));
+    }
+
+    #[test]
+    fn reserved_mask() {
+        let reserved = ReservedMask::new(Bitset32::new(0xFF00FF00));
+        let mut register = Bitset32::new(0x12345678);
+
+        assert!(!reserved.is_valid(&Bitset32::new(0x00000100)));
+        assert!(reserved.is_valid(&Bitset32::new(0x00FF00FF)));
+
+        reserved.write(&mut register, Bitset32::new(0xFFFFFFFF));
+        assert_eq!(register.into_inner(), 0x12FF56FF);
+    }
+
+    struct Wide;
+
+    impl FieldSpec for Wide {
+        type Bitset = Tested;
+        type Value = u64;
+        const OFFSET: usize = 30;
+        const WIDTH: usize = 64;
+    }
+
+    struct Odd;
+
+    impl FieldSpec for Odd {
+        type Bitset = Tested;
+        type Value = u8;
+        const OFFSET: usize = 3;
+        const WIDTH: usize = 3;
+    }
+
+    #[test]
+    fn field_spec() {
+        let mut bitset = Tested::NONE;
+
+        bitset.insert::<Wide>(u64::MAX).insert::<Odd>(0b11111101);
+
+        assert_eq!(bitset.extract::<Wide>(), Some(u64::MAX));
+        assert_eq!(bitset.extract::<Odd>(), Some(0b101));
+        assert_eq!(bitset.count_ones(), 64 + 2);
+    }
+
+    #[test]
+    fn runtime_fields() -> Result<(), crate::error::ConvError> {
+        let mut bitset = Tested::NONE;
+
+        bitset
+            .insert_bits(30, 64, u64::MAX)?
+            .insert_bits(3, 3, 0b101u8)?;
+
+        assert_eq!(bitset.extract_bits::<u64>(30, 64)?, u64::MAX);
+        assert_eq!(bitset.extract_bits::<u8>(3, 3)?, 0b101);
+        assert_eq!(bitset.extract_bits::<u16>(90, 6)?, 0b001111);
+        assert!(bitset.extract_bits::<u8>(26, 9).is_err());
+        assert!(bitset.extract_bits::<u64>(0, 65).is_err());
+        assert!(bitset.extract_bits::<u8>(95, 2).is_err());
+        assert!(bitset.extract_bits::<u8>(usize::MAX, 2).is_err());
+        assert!(bitset.insert_bits(0, 2, 4u8).is_err());
+        assert!(bitset.insert_bits(90, 7, 0u8).is_err());
+        assert_eq!(bitset.count_ones(), 64 + 2);
+        Ok(())
+    }
+}
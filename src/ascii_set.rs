@@ -0,0 +1,256 @@
+//! Module containing [`AsciiSet`], set of ASCII characters over [`Bitset128`].
+
+use crate::{bitset::Bitset, prelude::Bitset128};
+use std::fmt::Debug;
+
+/// Set of ASCII characters, backed by [`Bitset128`], with a bit for each of the 128 characters.
+///
+/// Meant for building tables of allowed characters in parsers. All of the constructors
+/// and set operations are `const`, so whole tables can be built at compile time.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::ascii_set::AsciiSet;
+///
+/// const IDENT_START: AsciiSet = AsciiSet::from_range(b'a', b'z')
+///     .union(AsciiSet::from_range(b'A', b'Z'))
+///     .union(AsciiSet::new("_"));
+/// const IDENT: AsciiSet = IDENT_START.union(AsciiSet::from_range(b'0', b'9'));
+///
+/// let is_ident = |s: &str| {
+///     s.bytes().next().is_some_and(|b| IDENT_START.contains(b)) && s.bytes().all(|b| IDENT.contains(b))
+/// };
+///
+/// assert!(is_ident("snake_case_1"));
+/// assert!(!is_ident("1st"));
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct AsciiSet(Bitset128);
+
+impl AsciiSet {
+    /// Empty `AsciiSet`.
+    pub const NONE: Self = Self(Bitset128::new(0));
+    /// `AsciiSet` containing all of the ASCII characters.
+    pub const ALL: Self = Self(Bitset128::new(u128::MAX));
+
+    /// Constructs a new value of `AsciiSet`, containing all of the characters of `chars`.
+    ///
+    /// # Panics
+    /// Panics, if `chars` contains non-ASCII characters. In `const` context, fails to compile instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::ascii_set::AsciiSet;
+    ///
+    /// const PUNCTUATION: AsciiSet = AsciiSet::new(".,;:!?");
+    ///
+    /// assert!(PUNCTUATION.contains(b'?'));
+    /// assert_eq!(PUNCTUATION.len(), 6);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub const fn new(chars: &str) -> Self {
+        let bytes = chars.as_bytes();
+        let mut inner = 0u128;
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(bytes[i].is_ascii(), "characters should be ASCII");
+            inner |= 1 << bytes[i];
+            i += 1;
+        }
+        Self(Bitset128::new(inner))
+    }
+
+    /// Constructs a new value of `AsciiSet`, containing all of the characters in `start..=end`.
+    ///
+    /// # Panics
+    /// Panics, if `end` is not an ASCII character. In `const` context, fails to compile instead.
+    pub const fn from_range(start: u8, end: u8) -> Self {
+        assert!(end.is_ascii(), "characters should be ASCII");
+        if start > end {
+            return Self::NONE;
+        }
+        let high = u128::MAX >> (127 - end);
+        let low = u128::MAX << start;
+        Self(Bitset128::new(high & low))
+    }
+
+    /// Returns [`true`], if `AsciiSet` contains character `byte`, and [`false`] otherwise.
+    /// Returns [`false`] for non-ASCII bytes.
+    #[inline(always)]
+    pub const fn contains(&self, byte: u8) -> bool {
+        byte.is_ascii() && self.0.into_inner() & (1 << byte) != 0
+    }
+
+    /// Returns [`true`], if `AsciiSet` contains character `c`, and [`false`] otherwise.
+    /// Returns [`false`] for non-ASCII characters.
+    #[inline(always)]
+    pub const fn contains_char(&self, c: char) -> bool {
+        c.is_ascii() && self.contains(c as u8)
+    }
+
+    /// Inserts character `c` into `AsciiSet`.
+    /// Returns [`true`], if `c` was not contained before, and [`false`] otherwise.
+    ///
+    /// Characters, which are not ASCII, are never inserted, same as they are never contained or removed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::ascii_set::AsciiSet;
+    ///
+    /// let mut set = AsciiSet::NONE;
+    ///
+    /// assert!(set.insert('a'));
+    /// assert!(!set.insert('a'));
+    /// assert!(set.contains_char('a'));
+    /// assert!(!set.insert('á'));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn insert(&mut self, c: char) -> bool {
+        if !c.is_ascii() || self.contains_char(c) {
+            return false;
+        }
+        self.0 = Bitset128::new(self.0.into_inner() | 1 << c as u8);
+        true
+    }
+
+    /// Removes character `c` from `AsciiSet`.
+    /// Returns [`true`], if `c` was contained before, and [`false`] otherwise.
+    pub fn remove(&mut self, c: char) -> bool {
+        let contained = self.contains_char(c);
+        if contained {
+            self.0 = Bitset128::new(self.0.into_inner() & !(1 << c as u8));
+        }
+        contained
+    }
+
+    /// Returns union of `self` and `other`.
+    #[inline(always)]
+    pub const fn union(self, other: Self) -> Self {
+        Self(Bitset128::new(self.0.into_inner() | other.0.into_inner()))
+    }
+
+    /// Returns intersection of `self` and `other`.
+    #[inline(always)]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(Bitset128::new(self.0.into_inner() & other.0.into_inner()))
+    }
+
+    /// Returns characters of `self`, that are not in `other`.
+    #[inline(always)]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(Bitset128::new(self.0.into_inner() & !other.0.into_inner()))
+    }
+
+    /// Returns all of the ASCII characters, that are not in `self`.
+    #[inline(always)]
+    pub const fn complement(self) -> Self {
+        Self(Bitset128::new(!self.0.into_inner()))
+    }
+
+    /// Returns count of characters in `AsciiSet`.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.0.into_inner().count_ones() as usize
+    }
+
+    /// Returns [`true`], if `AsciiSet` is empty, and [`false`] otherwise.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.0.into_inner() == 0
+    }
+
+    /// Returns iterator over characters of `AsciiSet`, in ascending order.
+    #[inline(always)]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
+        self.0.ones().map(|i| i.into_inner() as u8 as char)
+    }
+
+    /// Returns inner [`Bitset128`] of `AsciiSet`.
+    #[inline(always)]
+    pub const fn into_inner(&self) -> Bitset128 {
+        self.0
+    }
+}
+
+impl From<Bitset128> for AsciiSet {
+    fn from(value: Bitset128) -> Self {
+        Self(value)
+    }
+}
+
+impl From<AsciiSet> for Bitset128 {
+    fn from(value: AsciiSet) -> Self {
+        value.0
+    }
+}
+
+impl Debug for AsciiSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construction() {
+        const DIGITS: AsciiSet = AsciiSet::new("0123456789");
+
+        assert_eq!(AsciiSet::from_range(b'0', b'9'), DIGITS);
+        assert_eq!(AsciiSet::from_range(0, 127), AsciiSet::ALL);
+        assert_eq!(AsciiSet::from_range(b'9', b'0'), AsciiSet::NONE);
+        assert_eq!(DIGITS.iter().collect::<String>(), "0123456789");
+        assert_eq!(format!("{:?}", AsciiSet::new("ba")), "{'a', 'b'}");
+    }
+
+    #[test]
+    fn membership() {
+        let mut set = AsciiSet::new("abc");
+
+        assert!(set.contains(b'a'));
+        assert!(!set.contains(0xE1));
+        assert!(!set.contains_char('á'));
+        assert!(set.remove('a'));
+        assert!(!set.remove('á'));
+        assert_eq!(set.len(), 2);
+        assert!(!set.is_empty());
+    }
+
+    #[test]
+    fn non_ascii() {
+        let mut set = AsciiSet::default();
+
+        assert!(!set.insert('á'));
+        assert!(!set.contains_char('á'));
+        assert!(!set.remove('á'));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn operations() {
+        let letters = AsciiSet::from_range(b'a', b'z');
+        let vowels = AsciiSet::new("aeiou");
+
+        assert_eq!(letters.difference(vowels).len(), 21);
+        assert_eq!(letters.intersection(vowels), vowels);
+        assert_eq!(letters.union(vowels), letters);
+        assert_eq!(letters.complement().len(), 128 - 26);
+    }
+}
@@ -0,0 +1,468 @@
+//! Module containing [`BitReader`] and [`BitWriter`], reading and writing bits over [`std::io`].
+
+use crate::{bit::Bit, bitset::Bitset, index::Index, prelude::Bitset32};
+use std::io::{self, Read, Write};
+
+/// Order, in which bits are packed into bytes of the stream.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum BitOrder {
+    /// Bits fill each byte from the least significant one,
+    /// and multi-bit values are read and written starting from their least significant bit,
+    /// like in DEFLATE.
+    #[default]
+    Lsb0,
+    /// Bits fill each byte from the most significant one,
+    /// and multi-bit values are read and written starting from their most significant bit,
+    /// like in JPEG and MPEG.
+    Msb0,
+}
+
+// Returns error for counts of bits, not fitting into Bitset32.
+fn invalid_count(count: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("can't transfer {count} bits at once, 32 at most"),
+    )
+}
+
+/// Reader of bits and groups of bits from the underlying [`Read`].
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{bit_io::{BitOrder, BitReader}, prelude::*};
+///
+/// let bytes: &[u8] = &[0b10110001, 0b11111111];
+/// let mut reader = BitReader::new(bytes, BitOrder::Msb0);
+///
+/// assert_eq!(reader.read_bit()?, One);
+/// assert_eq!(reader.read_bits(3)?, Bitset32::new(0b011));
+/// assert_eq!(reader.read_bitset::<Bitset8>()?, Bitset8::new(0b00011111));
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BitReader<R> {
+    inner: R,
+    order: BitOrder,
+    current: u8,
+    // Count of not yet read bits of `current`.
+    remaining: u32,
+}
+
+impl<R> BitReader<R>
+where
+    R: Read,
+{
+    /// Constructs a new value of `BitReader`, reading from `inner` in `order`.
+    pub fn new(inner: R, order: BitOrder) -> Self {
+        Self {
+            inner,
+            order,
+            current: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Returns the [`BitOrder`] of `BitReader`.
+    #[inline(always)]
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Returns [`true`], if `BitReader` is at a byte boundary, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_aligned(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Skips the rest of the current byte, so that next read starts at a byte boundary.
+    #[inline(always)]
+    pub fn align(&mut self) {
+        self.remaining = 0;
+    }
+
+    /// Reads a single [`Bit`].
+    ///
+    /// # Errors
+    /// Returns error of the underlying reader,
+    /// which is [`io::ErrorKind::UnexpectedEof`], if the stream has ended.
+    pub fn read_bit(&mut self) -> io::Result<Bit> {
+        if self.remaining == 0 {
+            let mut byte = [0];
+            self.inner.read_exact(&mut byte)?;
+            self.current = byte[0];
+            self.remaining = 8;
+        }
+        self.remaining -= 1;
+        let bit = match self.order {
+            BitOrder::Lsb0 => {
+                let bit = self.current & 1;
+                self.current >>= 1;
+                bit
+            }
+            BitOrder::Msb0 => {
+                let bit = self.current >> 7;
+                self.current <<= 1;
+                bit
+            }
+        };
+        Ok(Bit::from(bit == 1))
+    }
+
+    /// Reads `count` (up to 32) bits, packed into the lowest bits of [`Bitset32`].
+    ///
+    /// # Errors
+    /// Returns [`io::ErrorKind::InvalidInput`] error, if `count` is greater than 32,
+    /// and errors of [`BitReader::read_bit`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bit_io::{BitOrder, BitReader}, prelude::*};
+    ///
+    /// let bytes: &[u8] = &[0b10110001];
+    /// let mut reader = BitReader::new(bytes, BitOrder::Lsb0);
+    ///
+    /// assert_eq!(reader.read_bits(5)?, Bitset32::new(0b10001));
+    /// assert_eq!(reader.read_bits(3)?, Bitset32::new(0b101));
+    /// assert!(reader.read_bits(1).is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn read_bits(&mut self, count: usize) -> io::Result<Bitset32> {
+        if count > 32 {
+            return Err(invalid_count(count));
+        }
+        let mut value = 0u32;
+        for i in 0..count {
+            let bit = u32::from(bool::from(self.read_bit()?));
+            match self.order {
+                BitOrder::Lsb0 => value |= bit << i,
+                BitOrder::Msb0 => value = value << 1 | bit,
+            }
+        }
+        Ok(Bitset32::new(value))
+    }
+
+    /// Reads a whole `Bitset` `T`, one bit per bit of `T`.
+    ///
+    /// # Errors
+    /// Returns errors of [`BitReader::read_bit`].
+    pub fn read_bitset<T>(&mut self) -> io::Result<T>
+    where
+        T: Bitset,
+    {
+        let len = T::BYTE_SIZE * 8;
+        let mut bitset = T::NONE.clone();
+        for i in 0..len {
+            let index = match self.order {
+                BitOrder::Lsb0 => i,
+                BitOrder::Msb0 => len - 1 - i,
+            };
+            let bit = self.read_bit()?;
+            bitset.replace(Index::from_usize(index), bit);
+        }
+        Ok(bitset)
+    }
+
+    /// Returns a reference to the underlying reader.
+    #[inline(always)]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the underlying reader. Not yet read bits of the current byte are lost.
+    #[inline(always)]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Writer of bits and groups of bits into the underlying [`Write`].
+///
+/// Bits are buffered until a whole byte is filled.
+/// Call [`BitWriter::finish`] to write out the last partial byte, padded with zeros.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{bit_io::{BitOrder, BitWriter}, prelude::*};
+///
+/// let mut writer = BitWriter::new(Vec::new(), BitOrder::Msb0);
+/// writer.write_bit(One)?;
+/// writer.write_bits(Bitset32::new(0b011), 3)?;
+/// writer.write_bitset(&Bitset8::new(0b00011111))?;
+///
+/// assert_eq!(writer.finish()?, [0b10110001, 0b11110000]);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<W> {
+    inner: W,
+    order: BitOrder,
+    current: u8,
+    // Count of bits already written into `current`.
+    filled: u32,
+}
+
+impl<W> BitWriter<W>
+where
+    W: Write,
+{
+    /// Constructs a new value of `BitWriter`, writing into `inner` in `order`.
+    pub fn new(inner: W, order: BitOrder) -> Self {
+        Self {
+            inner,
+            order,
+            current: 0,
+            filled: 0,
+        }
+    }
+
+    /// Returns the [`BitOrder`] of `BitWriter`.
+    #[inline(always)]
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Returns [`true`], if `BitWriter` is at a byte boundary, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_aligned(&self) -> bool {
+        self.filled == 0
+    }
+
+    /// Pads the current byte with zeros and writes it out, if it's partially filled,
+    /// so that next write starts at a byte boundary.
+    ///
+    /// # Errors
+    /// Returns errors of the underlying writer.
+    pub fn align(&mut self) -> io::Result<()> {
+        if self.filled > 0 {
+            self.inner.write_all(&[self.current])?;
+            self.current = 0;
+            self.filled = 0;
+        }
+        Ok(())
+    }
+
+    /// Writes a single [`Bit`].
+    ///
+    /// # Errors
+    /// Returns errors of the underlying writer.
+    /// If a complete byte couldn't be written out, it is written again on the next write.
+    pub fn write_bit(&mut self, bit: Bit) -> io::Result<()> {
+        // A full byte is still pending, if writing it out has failed before.
+        if self.filled == 8 {
+            self.align()?;
+        }
+        let bit = u8::from(bool::from(bit));
+        match self.order {
+            BitOrder::Lsb0 => self.current |= bit << self.filled,
+            BitOrder::Msb0 => self.current |= bit << (7 - self.filled),
+        }
+        self.filled += 1;
+        if self.filled == 8 {
+            self.align()?;
+        }
+        Ok(())
+    }
+
+    /// Writes lowest `count` (up to 32) bits of `bits`.
+    ///
+    /// # Errors
+    /// Returns [`io::ErrorKind::InvalidInput`] error, if `count` is greater than 32,
+    /// and errors of the underlying writer.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bit_io::{BitOrder, BitWriter}, prelude::*};
+    ///
+    /// let mut writer = BitWriter::new(Vec::new(), BitOrder::Lsb0);
+    /// writer.write_bits(Bitset32::new(0b10001), 5)?;
+    /// writer.write_bits(Bitset32::new(0b101), 3)?;
+    ///
+    /// assert_eq!(writer.finish()?, [0b10110001]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn write_bits(&mut self, bits: Bitset32, count: usize) -> io::Result<()> {
+        if count > 32 {
+            return Err(invalid_count(count));
+        }
+        for i in 0..count {
+            let i = match self.order {
+                BitOrder::Lsb0 => i,
+                BitOrder::Msb0 => count - 1 - i,
+            };
+            self.write_bit(Bit::from(bits.into_inner() >> i & 1 == 1))?;
+        }
+        Ok(())
+    }
+
+    /// Writes a whole `bitset`, one bit per bit of `T`.
+    ///
+    /// # Errors
+    /// Returns errors of the underlying writer.
+    pub fn write_bitset<T>(&mut self, bitset: &T) -> io::Result<()>
+    where
+        T: Bitset,
+    {
+        if self.is_aligned() && self.order == BitOrder::Lsb0 {
+            // Bytes of the Bitset are already in the stream order.
+            let bytes: Vec<u8> = (0..T::BYTE_SIZE).map(|i| bitset._byte(i)).collect();
+            return self.inner.write_all(&bytes);
+        }
+        let len = T::BYTE_SIZE * 8;
+        for i in 0..len {
+            let index = match self.order {
+                BitOrder::Lsb0 => i,
+                BitOrder::Msb0 => len - 1 - i,
+            };
+            self.write_bit(bitset.bit(Index::from_usize(index)))?;
+        }
+        Ok(())
+    }
+
+    /// Returns a reference to the underlying writer.
+    #[inline(always)]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Writes out the last partial byte, padded with zeros, flushes and returns the underlying writer.
+    ///
+    /// # Errors
+    /// Returns errors of the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.align()?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset128, Bitset16, Bitset8};
+
+    #[test]
+    fn round_trip() -> io::Result<()> {
+        for order in [BitOrder::Lsb0, BitOrder::Msb0] {
+            let mut writer = BitWriter::new(Vec::new(), order);
+            writer.write_bits(Bitset32::new(0b101), 3)?;
+            writer.write_bitset(&Bitset16::new(0xBEEF))?;
+            writer.write_bits(Bitset32::new(u32::MAX), 32)?;
+            writer.write_bitset(&Bitset128::new(1 << 100))?;
+            writer.write_bit(Bit::One)?;
+            let bytes = writer.finish()?;
+
+            assert_eq!(bytes.len(), (3 + 16 + 32 + 128 + 1usize).div_ceil(8));
+
+            let mut reader = BitReader::new(bytes.as_slice(), order);
+            assert_eq!(reader.read_bits(3)?, Bitset32::new(0b101));
+            assert_eq!(reader.read_bitset::<Bitset16>()?, Bitset16::new(0xBEEF));
+            assert_eq!(reader.read_bits(32)?, Bitset32::new(u32::MAX));
+            assert_eq!(reader.read_bitset::<Bitset128>()?, Bitset128::new(1 << 100));
+            assert_eq!(reader.read_bit()?, Bit::One);
+            assert_eq!(reader.read_bits(4)?, Bitset32::new(0));
+            assert!(reader.is_aligned());
+            assert_eq!(
+                reader.read_bit().unwrap_err().kind(),
+                io::ErrorKind::UnexpectedEof
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn orders() -> io::Result<()> {
+        let mut lsb = BitWriter::new(Vec::new(), BitOrder::Lsb0);
+        let mut msb = BitWriter::new(Vec::new(), BitOrder::Msb0);
+        lsb.write_bits(Bitset32::new(0x1234), 16)?;
+        msb.write_bits(Bitset32::new(0x1234), 16)?;
+        msb.write_bitset(&Bitset8::new(0x56))?;
+
+        assert_eq!(lsb.finish()?, [0x34, 0x12]);
+        assert_eq!(msb.finish()?, [0x12, 0x34, 0x56]);
+        Ok(())
+    }
+
+    #[test]
+    fn alignment() -> io::Result<()> {
+        let mut writer = BitWriter::new(Vec::new(), BitOrder::Lsb0);
+        writer.write_bit(Bit::One)?;
+        assert!(!writer.is_aligned());
+        writer.align()?;
+        writer.write_bitset(&Bitset8::new(0xAB))?;
+        let bytes = writer.finish()?;
+
+        assert_eq!(bytes, [0x01, 0xAB]);
+
+        let mut reader = BitReader::new(bytes.as_slice(), BitOrder::Lsb0);
+        reader.read_bit()?;
+        reader.align();
+        assert_eq!(reader.read_bitset::<Bitset8>()?, Bitset8::new(0xAB));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_counts() {
+        let mut writer = BitWriter::new(Vec::new(), BitOrder::Lsb0);
+        let mut reader = BitReader::new([0u8; 8].as_slice(), BitOrder::Lsb0);
+
+        assert_eq!(
+            writer.write_bits(Bitset32::NONE, 33).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        assert_eq!(
+            reader.read_bits(33).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    // Writer, which fails the first `failures` writes.
+    struct Failing {
+        failures: usize,
+        written: Vec<u8>,
+    }
+
+    impl Write for Failing {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.failures > 0 {
+                self.failures -= 1;
+                return Err(io::Error::other("failed"));
+            }
+            self.written.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn failing_writer() -> io::Result<()> {
+        let failing = Failing {
+            failures: 1,
+            written: Vec::new(),
+        };
+        let mut writer = BitWriter::new(failing, BitOrder::Lsb0);
+
+        assert!(writer.write_bits(Bitset32::new(0xFF), 8).is_err());
+        writer.write_bit(Bit::One)?;
+
+        assert_eq!(writer.finish()?.written, [0xFF, 0b00000001]);
+        Ok(())
+    }
+}
@@ -0,0 +1,390 @@
+//! Module containing [`BitmapIndex`].
+
+use crate::{
+    bitset::Bitset,
+    error::{ConvError, ConvResult, ConvTarget},
+    expr::Expr,
+    index::Index,
+};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash};
+
+/// Collection of equal-length [`Bitset`] columns, keyed by `K`.
+///
+/// Each row of the index is a bit position shared by all columns: bit `i` of the column
+/// is set, if row `i` has the property represented by that column.
+/// Rows are appended one at a time, up to the bit size of `T`.
+///
+/// Columns, that were never set, are treated as empty in queries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitmapIndex<K, T>
+where
+    K: Hash + Eq,
+    T: Bitset,
+{
+    columns: HashMap<K, T>,
+    rows: usize,
+}
+
+impl<K, T> BitmapIndex<K, T>
+where
+    K: Hash + Eq,
+    T: Bitset,
+{
+    /// Constructs a new, empty value of `BitmapIndex`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitmap_index::BitmapIndex, prelude::*};
+    ///
+    /// let index = BitmapIndex::<&str, Bitset64>::new();
+    ///
+    /// assert_eq!(index.rows(), 0);
+    /// assert_eq!(index.capacity(), 64);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            columns: HashMap::new(),
+            rows: 0,
+        }
+    }
+
+    /// Returns the count of rows in the `BitmapIndex`.
+    #[inline(always)]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the max count of rows in the `BitmapIndex`, which is the bit size of `T`.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        T::BYTE_SIZE * 8
+    }
+
+    /// Returns the column with the `key`, if it was ever set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitmap_index::BitmapIndex, prelude::*};
+    ///
+    /// let mut index = BitmapIndex::<&str, Bitset8>::new();
+    /// index.push_row(["red"])?;
+    /// index.push_row(["blue", "red"])?;
+    ///
+    /// assert_eq!(index.column("red"), Some(&Bitset8::new(0b00000011)));
+    /// assert_eq!(index.column("green"), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn column<Q>(&self, key: &Q) -> Option<&T>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.columns.get(key)
+    }
+
+    /// Returns iterator over keys of all columns of the `BitmapIndex`, in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.columns.keys()
+    }
+
+    /// Appends a row to the `BitmapIndex`, in which bits of columns with `keys` are set.
+    /// Columns, that don't exist yet, are created.
+    /// Returns [`Index`] of the appended row.
+    ///
+    /// # Errors
+    /// Returns [`ConvError`], if the `BitmapIndex` is already at capacity.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitmap_index::BitmapIndex, prelude::*};
+    ///
+    /// let mut index = BitmapIndex::<&str, Bitset8>::new();
+    ///
+    /// assert_eq!(index.push_row(["red", "big"])?, 0.try_into()?);
+    /// assert_eq!(index.push_row([])?, 1.try_into()?);
+    /// assert_eq!(index.rows(), 2);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn push_row<I>(&mut self, keys: I) -> ConvResult<Index<T>>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let row = Index::<T>::try_from_usize(self.rows).map_err(|_| {
+            ConvError::new(
+                ConvTarget::Raw(self.rows + 1),
+                ConvTarget::Set(self.capacity()),
+            )
+        })?;
+
+        for key in keys {
+            self.columns
+                .entry(key)
+                .or_insert_with(|| T::NONE.clone())
+                .set(row);
+        }
+        self.rows += 1;
+        Ok(row)
+    }
+
+    /// Returns the count of rows, in which bit of the column with `key` is set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitmap_index::BitmapIndex, prelude::*};
+    ///
+    /// let mut index = BitmapIndex::<&str, Bitset8>::new();
+    /// index.push_row(["red"])?;
+    /// index.push_row(["blue", "red"])?;
+    ///
+    /// assert_eq!(index.cardinality("red"), 2);
+    /// assert_eq!(index.cardinality("green"), 0);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn cardinality<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.column(key).map_or(0, T::count_ones)
+    }
+
+    /// Returns `Bitset` with bits set for all existing rows of the `BitmapIndex`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitmap_index::BitmapIndex, prelude::*};
+    ///
+    /// let mut index = BitmapIndex::<&str, Bitset8>::new();
+    /// index.push_row([])?;
+    /// index.push_row([])?;
+    ///
+    /// assert_eq!(index.all_rows(), Bitset8::new(0b00000011));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn all_rows(&self) -> T {
+        T::NONE.clone().insert_range(..self.rows).build()
+    }
+
+    /// Returns rows, in which bits of all columns with `keys` are set.
+    /// Returns all rows, if `keys` are empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitmap_index::BitmapIndex, prelude::*};
+    ///
+    /// let mut index = BitmapIndex::<&str, Bitset8>::new();
+    /// index.push_row(["red", "big"])?;
+    /// index.push_row(["blue", "big"])?;
+    /// index.push_row(["red"])?;
+    ///
+    /// assert_eq!(index.and(["red", "big"]), Bitset8::new(0b00000001));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn and<'a, Q, I>(&self, keys: I) -> T
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        keys.into_iter()
+            .fold(self.all_rows(), |acc, key| match self.column(key) {
+                Some(column) => acc.intersection(column.clone()),
+                None => T::NONE.clone(),
+            })
+    }
+
+    /// Returns rows, in which bit of any of the columns with `keys` is set.
+    /// Returns no rows, if `keys` are empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitmap_index::BitmapIndex, prelude::*};
+    ///
+    /// let mut index = BitmapIndex::<&str, Bitset8>::new();
+    /// index.push_row(["red", "big"])?;
+    /// index.push_row(["blue", "big"])?;
+    /// index.push_row(["green"])?;
+    ///
+    /// assert_eq!(index.or(["red", "blue"]), Bitset8::new(0b00000011));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn or<'a, Q, I>(&self, keys: I) -> T
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        keys.into_iter()
+            .filter_map(|key| self.column(key))
+            .fold(T::NONE.clone(), |acc, column| acc.union(column.clone()))
+    }
+
+    /// Returns rows, in which bit of the column with `key` is not set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitmap_index::BitmapIndex, prelude::*};
+    ///
+    /// let mut index = BitmapIndex::<&str, Bitset8>::new();
+    /// index.push_row(["red", "big"])?;
+    /// index.push_row(["blue", "big"])?;
+    /// index.push_row(["green"])?;
+    ///
+    /// assert_eq!(index.not("big"), Bitset8::new(0b00000100));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn not<Q>(&self, key: &Q) -> T
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.column(key) {
+            Some(column) => self.all_rows().difference(column.clone()),
+            None => self.all_rows(),
+        }
+    }
+
+    /// Returns rows, for which the boolean [`Expr`] over columns is true.
+    /// Columns, that don't exist, are treated as empty.
+    ///
+    /// The expression is evaluated in a single pass over the columns, 64 rows at a time,
+    /// without constructing intermediate `Bitset`s.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitmap_index::BitmapIndex, expr::Expr, prelude::*};
+    ///
+    /// let mut index = BitmapIndex::<String, Bitset8>::new();
+    /// index.push_row(["red".to_owned(), "big".to_owned()])?;
+    /// index.push_row(["blue".to_owned(), "big".to_owned()])?;
+    /// index.push_row(["green".to_owned()])?;
+    ///
+    /// let expr: Expr<String> = "big & !(red | green)".parse()?;
+    ///
+    /// assert_eq!(index.query(&expr), Bitset8::new(0b00000010));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn query<Q>(&self, expr: &Expr<Q>) -> T
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let bit_len = self.capacity();
+        let all_rows = self.all_rows();
+        let mut result = T::NONE.clone();
+        for w in 0..bit_len.div_ceil(64) {
+            let word = expr.eval_word(&|key| self.column(key).map_or(0, |c| c._word(w)));
+            result._set_bits(w * 64, (bit_len - w * 64).min(64), word & all_rows._word(w));
+        }
+        result
+    }
+}
+
+impl<K, T> Default for BitmapIndex<K, T>
+where
+    K: Hash + Eq,
+    T: Bitset,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset8, Byteset};
+    use std::error::Error;
+
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    #[test]
+    fn push_row() -> TestResult {
+        let mut index = BitmapIndex::<&str, Bitset8>::new();
+
+        for i in 0..8 {
+            assert_eq!(index.push_row(["a"])?, i.try_into()?);
+        }
+        assert_eq!(
+            index.push_row(["a"]),
+            Err(ConvError::new(ConvTarget::Raw(9), ConvTarget::Set(8)))
+        );
+        assert_eq!(index.rows(), 8);
+        assert_eq!(index.cardinality("a"), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn queries() -> TestResult {
+        let mut index = BitmapIndex::<String, Byteset<3>>::new();
+
+        for i in 0..20 {
+            let mut keys = vec![];
+            if i % 2 == 0 {
+                keys.push("even".to_owned());
+            }
+            if i % 3 == 0 {
+                keys.push("three".to_owned());
+            }
+            index.push_row(keys)?;
+        }
+
+        let both: Vec<_> = index
+            .and(["even", "three"])
+            .ones()
+            .map(|i| i.into_inner())
+            .collect();
+        assert_eq!(both, [0, 6, 12, 18]);
+
+        assert_eq!(index.or(["even", "three"]).count_ones(), 10 + 7 - 4);
+        assert_eq!(index.not("even").count_ones(), 10);
+        assert_eq!(index.not("missing"), index.all_rows());
+        assert_eq!(index.and(["even", "missing"]), Byteset::NONE);
+        assert_eq!(index.and::<str, _>([]), index.all_rows());
+        assert_eq!(index.or::<str, _>([]), Byteset::NONE);
+
+        let expr = "!(even | three) ^ missing".parse()?;
+        assert_eq!(
+            index.query(&expr),
+            index.not("even").difference(index.or(["three"]))
+        );
+        Ok(())
+    }
+}
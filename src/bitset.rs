@@ -1,9 +1,27 @@
 //! Module containing [`Bitset`].
 
 use crate::{
+    access::FieldSpec,
     bit::{Bit, BitMut, BitRef},
+    cursor::{BitCursor, BitCursorMut},
+    dispatch,
+    display::BitsetDisplay,
+    error::{ConvError, ConvResult, ConvTarget},
     index::Index,
+    iter::{
+        count_in, find_first, find_last, find_nth, Bits, BitsMut, BitsRef, Combinations, DrainOnes,
+        Ones, Ranges, Subsets, Zeros,
+    },
+    parse::{self, ParseBitsetError},
+    prelude::{
+        Bitset128, Bitset16, Bitset256, Bitset32, Bitset512, Bitset64, Bitset8, BitsetSize, Byteset,
+    },
     safety_markers::{Combines, SizeMarker, Smaller, Splits},
+    slice::{BitSliceMut, BitSliceRef},
+};
+use std::{
+    iter::FusedIterator,
+    ops::{Bound, Range, RangeBounds, Shl, ShlAssign, Shr, ShrAssign},
 };
 
 // Length of Bitset in bits.
@@ -14,6 +32,179 @@ where
     T::BYTE_SIZE * 8
 }
 
+// Converts `range` into a `Range` over bits of the Bitset, clamping it to the Bitset length.
+pub(crate) fn clamp_range<T>(range: impl RangeBounds<usize>) -> Range<usize>
+where
+    T: Bitset,
+{
+    clamp_range_to(range, bit_len::<T>())
+}
+
+// Converts `range` into a `Range`, clamping it to `len`.
+pub(crate) fn clamp_range_to(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&i) => i,
+        Bound::Excluded(&i) => i.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&i) => i.saturating_add(1),
+        Bound::Excluded(&i) => i,
+        Bound::Unbounded => len,
+    };
+    let end = end.min(len);
+    start.min(end)..end
+}
+
+// Checks, that the field of `width` bits at `offset` is at most 64 bits wide and fits into the Bitset.
+fn check_field<T>(offset: usize, width: usize) -> ConvResult<()>
+where
+    T: Bitset,
+{
+    match offset.checked_add(width) {
+        Some(end) if width <= 64 && end <= bit_len::<T>() => Ok(()),
+        _ => Err(ConvError::new(
+            ConvTarget::Raw(offset.saturating_add(width)),
+            ConvTarget::Set(bit_len::<T>()),
+        )),
+    }
+}
+
+// Sets all bits of the `bitset` in `range` to `value`, up to 64 bits at a time.
+fn fill_range<T>(bitset: &mut T, range: Range<usize>, value: bool)
+where
+    T: Bitset,
+{
+    let mut k = range.start;
+    while k < range.end {
+        let width = (range.end - k).min(64);
+        let bits = if value { !0 >> (64 - width) } else { 0 };
+        bitset._set_bits(k, width, bits);
+        k += width;
+    }
+}
+
+// Counts set bits of `op` applied to words of `a` and `b`, without constructing the combined Bitset.
+fn count_combined<T>(a: &T, b: &T, op: impl Fn(u64, u64) -> u64) -> usize
+where
+    T: Bitset,
+{
+    (0..bit_len::<T>().div_ceil(64))
+        .map(|w| op(a._word(w), b._word(w)).count_ones() as usize)
+        .sum()
+}
+
+// Spreads bits of `word` to even positions of the result.
+fn spread_bits(word: u32) -> u64 {
+    let mut word = word as u64;
+    word = (word | word << 16) & 0x0000FFFF0000FFFF;
+    word = (word | word << 8) & 0x00FF00FF00FF00FF;
+    word = (word | word << 4) & 0x0F0F0F0F0F0F0F0F;
+    word = (word | word << 2) & 0x3333333333333333;
+    (word | word << 1) & 0x5555555555555555
+}
+
+// Packs bits at even positions of `word` together, reversing `spread_bits`.
+fn compact_bits(word: u64) -> u32 {
+    let mut word = word & 0x5555555555555555;
+    word = (word | word >> 1) & 0x3333333333333333;
+    word = (word | word >> 2) & 0x0F0F0F0F0F0F0F0F;
+    word = (word | word >> 4) & 0x00FF00FF00FF00FF;
+    word = (word | word >> 8) & 0x0000FFFF0000FFFF;
+    (word | word >> 16) as u32
+}
+
+// Shifts bits of the little-endian `bytes` towards the higher indeces, carrying them across bytes.
+pub(crate) fn shift_bytes_left(bytes: &mut [u8], amount: usize) {
+    let byte_shift = (amount / 8).min(bytes.len());
+    let bit_shift = amount % 8;
+
+    bytes.copy_within(..bytes.len() - byte_shift, byte_shift);
+    bytes[..byte_shift].fill(0);
+
+    if bit_shift > 0 {
+        let mut carry = 0;
+        for byte in bytes.iter_mut().skip(byte_shift) {
+            let shifted = *byte << bit_shift | carry;
+            carry = *byte >> (8 - bit_shift);
+            *byte = shifted;
+        }
+    }
+}
+
+// Shifts bits of the little-endian `bytes` towards the lower indeces, carrying them across bytes.
+pub(crate) fn shift_bytes_right(bytes: &mut [u8], amount: usize) {
+    let byte_shift = (amount / 8).min(bytes.len());
+    let bit_shift = amount % 8;
+    let len = bytes.len();
+
+    bytes.copy_within(byte_shift.., 0);
+    bytes[len - byte_shift..].fill(0);
+
+    if bit_shift > 0 {
+        let mut carry = 0;
+        for byte in bytes.iter_mut().rev().skip(byte_shift) {
+            let shifted = *byte >> bit_shift | carry;
+            carry = *byte << (8 - bit_shift);
+            *byte = shifted;
+        }
+    }
+}
+
+// Returns bits of `bitset` of up to 128 bits, packed into `u128`.
+fn packed<T>(bitset: &T) -> u128
+where
+    T: Bitset,
+{
+    debug_assert!(bit_len::<T>() <= 128);
+    bitset._word(0) as u128 | (bitset._word(1) as u128) << 64
+}
+
+// Sets bits of `bitset` of up to 128 bits from `value`, packed into `u128`.
+fn set_packed<T>(bitset: &mut T, value: u128)
+where
+    T: Bitset,
+{
+    let len = bit_len::<T>();
+    debug_assert!(len <= 128);
+    bitset._set_bits(0, len.min(64), value as u64);
+    if len > 64 {
+        bitset._set_bits(64, len - 64, (value >> 64) as u64);
+    }
+}
+
+// Returns `bitset` with bits shifted towards the higher indeces by `amount`, up to 64 bits at a time.
+// Shifting by the length of the Bitset or more shifts out all of the bits.
+fn shifted_left<T>(bitset: &T, amount: usize) -> T
+where
+    T: Bitset,
+{
+    let mut result = T::NONE.clone();
+    let mut k = amount;
+    while k < bit_len::<T>() {
+        let width = (bit_len::<T>() - k).min(64);
+        result._set_bits(k, width, bitset._bits(k - amount, width));
+        k += width;
+    }
+    result
+}
+
+// Returns `bitset` with bits shifted towards the lower indeces by `amount`, up to 64 bits at a time.
+// Shifting by the length of the Bitset or more shifts out all of the bits.
+fn shifted_right<T>(bitset: &T, amount: usize) -> T
+where
+    T: Bitset,
+{
+    let mut result = T::NONE.clone();
+    let mut k = 0;
+    while k + amount < bit_len::<T>() {
+        let width = (bit_len::<T>() - amount - k).min(64);
+        result._set_bits(k, width, bitset._bits(k + amount, width));
+        k += width;
+    }
+    result
+}
+
 /// Trait defining common bitset logic.
 ///
 /// This trait is not meant to be implmented on enums, as beyond some extremely rare cases,
@@ -154,38 +345,69 @@ pub trait Bitset: Sized + Clone + PartialEq + Eq {
     /// ```
     fn from_index(index: &Index<Self>) -> Self;
 
-    /// Expands `Bitset` to a bigger one.<br/>
-    /// If available, you should prefer using [`Bitset::expand_optimized`].
+    /// Constructs `Bitset` with bits at `indices` set. Indices outside of the `Bitset` are skipped.
+    ///
+    /// Same as collecting `indices` into the `Bitset`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let bitset8 = Bitset8::new(0b10101010);
-    /// let bitset16: Bitset16 = bitset8.expand();
+    /// let bitset = Bitset8::from_indices([0, 3, 7, 8]);
+    /// assert_eq!(bitset.into_inner(), 0b10001001);
     ///
-    /// assert_eq!(bitset16.into_inner(), 0b0000000010101010);
+    /// let bitset: Bitset8 = [1, 2].into_iter().collect();
+    /// assert_eq!(bitset.into_inner(), 0b00000110);
     /// #   Ok(())
     /// # }
     /// ```
-    fn expand<Res>(self) -> Res
+    fn from_indices<I>(indices: I) -> Self
     where
-        Res: Bitset,
-        Self::Size: Smaller<Res::Size>,
+        I: IntoIterator<Item = usize>,
     {
-        let result = self
-            .ones()
-            .map(|Index(i, ..)| Index::<Res>::from_usize(i))
-            .fold(&mut Res::NONE.clone(), |acc, i| acc.set(i))
-            .build();
-
+        let mut result = Self::NONE.clone();
+        for index in indices {
+            if let Ok(index) = Index::<Self>::try_from_usize(index) {
+                result.set(index);
+            }
+        }
         result
     }
 
-    /// Expands `Bitset` to a bigger one. Uses `unsafe` optimizations.
+    /// Tries constructing `Bitset` with bits at `indices` set.
+    ///
+    /// # Errors
+    /// Errors with the first index outside of the `Bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::try_from_indices([0, 3, 7])?;
+    /// assert_eq!(bitset.into_inner(), 0b10001001);
+    ///
+    /// assert!(Bitset8::try_from_indices([0, 8]).is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn try_from_indices<I>(indices: I) -> ConvResult<Self>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let mut result = Self::NONE.clone();
+        for index in indices {
+            result.set(Index::<Self>::try_from_usize(index)?);
+        }
+        Ok(result)
+    }
+
+    /// Expands `Bitset` to a bigger one.
     ///
     /// # Examples
     /// ```rust
@@ -195,28 +417,71 @@ pub trait Bitset: Sized + Clone + PartialEq + Eq {
     /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
     ///
     /// let bitset8 = Bitset8::new(0b10101010);
-    /// let bitset16: Bitset16 = bitset8.expand_optimized();
+    /// let bitset16: Bitset16 = bitset8.expand();
     ///
     /// assert_eq!(bitset16.into_inner(), 0b0000000010101010);
     /// #   Ok(())
     /// # }
     /// ```
+    fn expand<Res>(self) -> Res
+    where
+        Res: Bitset,
+        Self::Size: Smaller<Res::Size>,
+    {
+        let mut result = Res::NONE.clone();
+        for i in 0..Self::BYTE_SIZE {
+            result._set_byte(i, self._byte(i));
+        }
+        result
+    }
+
+    /// Same as [`Bitset::expand`], kept for compatibility.
+    #[deprecated(note = "use `Bitset::expand` instead")]
     fn expand_optimized<Res>(self) -> Res
     where
         Self: LeftAligned,
         Res: Bitset + LeftAligned,
         Self::Size: Smaller<Res::Size>,
     {
-        let mut result = Res::NONE.clone();
+        self.expand()
+    }
 
-        unsafe {
-            std::ptr::copy_nonoverlapping(
-                &self as *const _ as *const u8,
-                &mut result as *mut _ as *mut u8,
-                Self::BYTE_SIZE,
-            );
+    /// Converts `Bitset` into another one of any size, preserving [`Index`]es of all set bits.
+    ///
+    /// # Errors
+    /// [`ConvError`], if any set bit doesn't fit into the resulting `Bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Byteset::<3>::new([0b10101010, 0b00000001, 0]);
+    /// let bitset16: Bitset16 = bitset.try_convert()?;
+    ///
+    /// assert_eq!(bitset16.into_inner(), 0b0000000110101010);
+    /// assert!(Byteset::<3>::ALL.try_convert::<Bitset16>().is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn try_convert<Res>(&self) -> ConvResult<Res>
+    where
+        Res: Bitset,
+    {
+        let len = bit_len::<Res>();
+        if let Some(i) = self.highest_one().filter(|i| i.into_inner() >= len) {
+            return Err(ConvError::new(
+                ConvTarget::Set(i.into_inner() + 1),
+                ConvTarget::Set(len),
+            ));
         }
-        result
+        let mut result = Res::NONE.clone();
+        for w in 0..len.div_ceil(64) {
+            result._set_bits(w * 64, (len - w * 64).min(64), self._word(w));
+        }
+        Ok(result)
     }
 
     /// Builds `Bitset` from the collection of [`Bit`] values.<br/>
@@ -253,103 +518,137 @@ pub trait Bitset: Sized + Clone + PartialEq + Eq {
             .build()
     }
 
-    /// Returns the count of all set bits.
+    /// Constructs a new value of the `Bitset` with bits set in every range from `ranges`.
+    /// Ranges can be empty and can overlap; parts of the ranges outside of the `Bitset` are ignored,
+    /// same as in [`Bitset::insert_range`].
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
+    /// use bitworks::prelude::{Bitset, Bitset16};
     ///
-    /// let bitset = Bitset8::new(0b00000111);
+    /// let bitset = Bitset16::from_ranges([1..4, 8..10, 9..12]);
     ///
-    /// assert_eq!(bitset.count_ones(), 3);
+    /// assert_eq!(bitset.into_inner(), 0b0000111100001110);
+    /// assert_eq!(Bitset16::from_ranges([14..20]).into_inner(), 0b1100000000000000);
     /// #   Ok(())
     /// # }
     /// ```
-    #[inline(always)]
-    fn count_ones(&self) -> usize {
-        self.ones().count()
+    fn from_ranges<I>(ranges: I) -> Self
+    where
+        I: IntoIterator<Item = Range<usize>>,
+    {
+        ranges
+            .into_iter()
+            .fold(&mut Self::NONE.clone(), |acc, range| {
+                acc.insert_range(range)
+            })
+            .build()
     }
 
-    /// Returns the number of all unset bits.
+    /// Parses `Bitset` from `digits` of `radix` without a prefix, separated with `_`.
+    /// Bit `i` of the parsed value is bit of the `Bitset` at [`Index`] `i`.
+    ///
+    /// Use [`str::parse`] to parse literals with `0b`, `0o` or `0x` prefixes.
+    ///
+    /// # Errors
+    /// Returns [`ParseBitsetError`], if there are no digits, if there is an invalid digit,
+    /// or if the value doesn't fit into the `Bitset`.
+    ///
+    /// # Panics
+    /// Panics, if `radix` is not a power of two in range `2..=32`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
+    /// use bitworks::prelude::{Bitset, Bitset16};
     ///
-    /// let bitset = Bitset8::new(0b00000111);
+    /// let bitset = Bitset16::try_from_str_radix("0f_f0", 16)?;
     ///
-    /// assert_eq!(bitset.count_zeros(), 5);
+    /// assert_eq!(bitset.into_inner(), 0b0000111111110000);
+    /// assert!(Bitset16::try_from_str_radix("fg", 16).is_err());
+    /// assert!(Bitset16::try_from_str_radix("1_0000_0000_0000_0000", 2).is_err());
     /// #   Ok(())
     /// # }
     /// ```
     #[inline(always)]
-    fn count_zeros(&self) -> usize {
-        self.zeros().count()
+    fn try_from_str_radix(digits: &str, radix: u32) -> Result<Self, ParseBitsetError> {
+        parse::parse_radix(digits, radix, 0)
     }
 
-    /// Replaces the bit at [`index`][Index] to the value. Returns a mutable reference to `self`.
+    /// Constructs a new value of the `Bitset` with every bit set or not with equal probability.
+    ///
+    /// Requires feature `"rand"`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::*;
+    /// use bitworks::prelude::{Bitset, Bitset64};
+    /// use rand::{rngs::StdRng, SeedableRng};
     ///
-    /// let bitset = Bitset8::new(0b01010100)
-    ///     .replace(1.try_into()?, One)
-    ///     .replace(2.try_into()?, Zero)
-    ///     .replace(3.try_into()?, One)
-    ///     .replace(4.try_into()?, Zero)
-    ///     .replace(5.try_into()?, One)
-    ///     .replace(6.try_into()?, Zero)
-    ///     .replace(7.try_into()?, One)
-    ///     .build();
+    /// let mut rng = StdRng::seed_from_u64(19);
+    /// let bitset = Bitset64::random(&mut rng);
     ///
-    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// assert_eq!(bitset.count_ones() + bitset.count_zeros(), 64);
     /// #   Ok(())
     /// # }
     /// ```
-    #[inline(always)]
-    fn replace(&mut self, index: Index<Self>, value: Bit) -> &mut Self {
-        if bool::from(value) {
-            self.set(index);
-        } else {
-            self.unset(index);
+    #[cfg(feature = "rand")]
+    fn random<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let mut bitset = Self::NONE.clone();
+        for b in 0..Self::BYTE_SIZE {
+            bitset._set_byte(b, rng.gen());
         }
-        self
+        bitset
     }
 
-    /// Sets bit at [`index`][Index]. Returns a mutable reference to `self`.
+    /// Constructs a new value of the `Bitset` with exactly `k` bits set,
+    /// sampled uniformly from all such values.
+    ///
+    /// Requires feature `"rand"`.
+    ///
+    /// # Panics
+    /// Panics, if `k` is greater than the bit size of the `Bitset`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
+    /// use bitworks::prelude::{Bitset, Bitset64};
+    /// use rand::{rngs::StdRng, SeedableRng};
     ///
-    /// let bitset = Bitset8::NONE
-    ///     .clone()
-    ///     .set(1.try_into()?)
-    ///     .set(3.try_into()?)
-    ///     .set(5.try_into()?)
-    ///     .set(7.try_into()?)
-    ///     .build();
+    /// let mut rng = StdRng::seed_from_u64(19);
+    /// let bitset = Bitset64::random_with_count_ones(&mut rng, 5);
     ///
-    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// assert_eq!(bitset.count_ones(), 5);
     /// #   Ok(())
     /// # }
     /// ```
-    fn set(&mut self, index: Index<Self>) -> &mut Self;
+    #[cfg(feature = "rand")]
+    fn random_with_count_ones<R>(rng: &mut R, k: usize) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let len = bit_len::<Self>();
+        assert!(k <= len, "k must not exceed the bit size of the Bitset");
+        let mut bitset = Self::NONE.clone();
+        for i in rand::seq::index::sample(rng, len, k) {
+            bitset._set_bits(i, 1, 1);
+        }
+        bitset
+    }
 
-    /// Unsets bit at [`index`][Index]. Returns a mutable reference to `self`.
+    /// Returns the count of all set bits.
     ///
     /// # Examples
     /// ```rust
@@ -358,21 +657,18 @@ pub trait Bitset: Sized + Clone + PartialEq + Eq {
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let bitset = Bitset8::ALL
-    ///     .clone()
-    ///     .unset(0.try_into()?)
-    ///     .unset(2.try_into()?)
-    ///     .unset(4.try_into()?)
-    ///     .unset(6.try_into()?)
-    ///     .build();
+    /// let bitset = Bitset8::new(0b00000111);
     ///
-    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// assert_eq!(bitset.count_ones(), 3);
     /// #   Ok(())
     /// # }
     /// ```
-    fn unset(&mut self, index: Index<Self>) -> &mut Self;
+    #[inline(always)]
+    fn count_ones(&self) -> usize {
+        self.ones().count()
+    }
 
-    /// Flips bit at [`index`][Index]. Returns a mutable reference to `self`.
+    /// Returns the number of all unset bits.
     ///
     /// # Examples
     /// ```rust
@@ -381,76 +677,2040 @@ pub trait Bitset: Sized + Clone + PartialEq + Eq {
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let bitset = Bitset8::new(0b11110000)
-    ///     .flip(1.try_into()?)
-    ///     .flip(3.try_into()?)
-    ///     .flip(4.try_into()?)
-    ///     .flip(6.try_into()?)
-    ///     .build();
+    /// let bitset = Bitset8::new(0b00000111);
     ///
-    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// assert_eq!(bitset.count_zeros(), 5);
     /// #   Ok(())
     /// # }
     /// ```
-    fn flip(&mut self, index: Index<Self>) -> &mut Self;
+    #[inline(always)]
+    fn count_zeros(&self) -> usize {
+        self.zeros().count()
+    }
 
-    /// Includes all set bits of `other` in `self`. Returns a mutable reference to `self`.
+    /// Returns [`true`], if the number of set bits is odd, and [`false`] otherwise.
+    ///
+    /// Same as `self.count_ones() & 1 == 1`, but XORs words of the `Bitset` together first,
+    /// so only a single word has its bits counted.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::*;
-    ///
-    /// let bitset = Bitset8::new(0b00100010)
-    ///     .include(Bitset8::new(0b10001000))
-    ///     .build();
+    /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// assert!(Bitset8::new(0b00000111).parity());
+    /// assert!(!Bitset8::new(0b00000011).parity());
+    /// assert!(!Bitset8::NONE.parity());
     /// #   Ok(())
     /// # }
     /// ```
-    fn include(&mut self, other: Self) -> &mut Self;
+    #[inline(always)]
+    fn parity(&self) -> bool {
+        (0..bit_len::<Self>().div_ceil(64))
+            .fold(0, |acc, w| acc ^ self._word(w))
+            .count_ones()
+            & 1
+            == 1
+    }
 
-    /// Excludes all set bits of `other` from `self`. Returns a mutable reference to `self`.
+    /// Returns `true`, if none of the bits are set.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::*;
-    ///
-    /// let bitset = Bitset8::new(0b11111110)
-    ///     .exclude(Bitset8::new(0b01010100))
-    ///     .build();
+    /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// assert!(Bitset8::NONE.is_empty());
+    /// assert!(!Bitset8::new(0b00000100).is_empty());
     /// #   Ok(())
     /// # }
     /// ```
-    fn exclude(&mut self, other: Self) -> &mut Self;
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.ones().next().is_none()
+    }
 
-    /// Returns a copy of the [`Bit`] at [`index`][Index].
+    /// Returns `true`, if any of the bits are set.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::*;
-    ///
-    /// let bitset = Bitset8::NONE.set(1.try_into()?).build();
+    /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// assert_eq!(bitset.bit(0.try_into()?), Zero);
-    /// assert_eq!(bitset.bit(1.try_into()?), One);
+    /// assert!(Bitset8::new(0b00000100).any());
+    /// assert!(!Bitset8::NONE.any());
     /// #   Ok(())
     /// # }
     /// ```
-    fn bit(&self, index: Index<Self>) -> Bit;
+    #[inline(always)]
+    fn any(&self) -> bool {
+        !self.is_empty()
+    }
 
-    /// Returns a [`BitRef`] holding an immutable reference to the bit at [`index`][Index].
+    /// Returns count of not set bits before the lowest set one, or the size of the `Bitset` in bits, if it is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// assert_eq!(Bitset8::new(0b00101000).trailing_zeros(), 3);
+    /// assert_eq!(Bitset8::NONE.trailing_zeros(), 8);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn trailing_zeros(&self) -> usize {
+        find_first(0, bit_len::<Self>(), |w| self._word(w)).unwrap_or(bit_len::<Self>())
+    }
+
+    /// Returns count of not set bits after the highest set one, or the size of the `Bitset` in bits, if it is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// assert_eq!(Bitset8::new(0b00101000).leading_zeros(), 2);
+    /// assert_eq!(Bitset8::NONE.leading_zeros(), 8);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn leading_zeros(&self) -> usize {
+        find_last(0, bit_len::<Self>(), |w| self._word(w))
+            .map_or(bit_len::<Self>(), |i| bit_len::<Self>() - 1 - i)
+    }
+
+    /// Returns count of set bits before the lowest not set one, or the size of the `Bitset` in bits, if it is full.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// assert_eq!(Bitset8::new(0b11010111).trailing_ones(), 3);
+    /// assert_eq!(Bitset8::ALL.trailing_ones(), 8);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn trailing_ones(&self) -> usize {
+        find_first(0, bit_len::<Self>(), |w| !self._word(w)).unwrap_or(bit_len::<Self>())
+    }
+
+    /// Returns count of set bits after the highest not set one, or the size of the `Bitset` in bits, if it is full.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// assert_eq!(Bitset8::new(0b11010111).leading_ones(), 2);
+    /// assert_eq!(Bitset8::ALL.leading_ones(), 8);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn leading_ones(&self) -> usize {
+        find_last(0, bit_len::<Self>(), |w| !self._word(w))
+            .map_or(bit_len::<Self>(), |i| bit_len::<Self>() - 1 - i)
+    }
+
+    /// Returns [`Index`] of the lowest set bit, or [`None`], if the `Bitset` is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// assert_eq!(Bitset8::new(0b00101000).lowest_one(), Some(3.try_into()?));
+    /// assert_eq!(Bitset8::NONE.lowest_one(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn lowest_one(&self) -> Option<Index<Self>> {
+        find_first(0, bit_len::<Self>(), |w| self._word(w)).map(Index::<Self>::from_usize)
+    }
+
+    /// Returns [`Index`] of the highest set bit, or [`None`], if the `Bitset` is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// assert_eq!(Bitset8::new(0b00101000).highest_one(), Some(5.try_into()?));
+    /// assert_eq!(Bitset8::NONE.highest_one(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn highest_one(&self) -> Option<Index<Self>> {
+        find_last(0, bit_len::<Self>(), |w| self._word(w)).map(Index::<Self>::from_usize)
+    }
+
+    /// Returns [`Index`] of the lowest not set bit, or [`None`], if the `Bitset` is full.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// assert_eq!(Bitset8::new(0b11010111).lowest_zero(), Some(3.try_into()?));
+    /// assert_eq!(Bitset8::ALL.lowest_zero(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn lowest_zero(&self) -> Option<Index<Self>> {
+        find_first(0, bit_len::<Self>(), |w| !self._word(w)).map(Index::<Self>::from_usize)
+    }
+
+    /// Returns [`Index`] of the highest not set bit, or [`None`], if the `Bitset` is full.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// assert_eq!(Bitset8::new(0b11010111).highest_zero(), Some(5.try_into()?));
+    /// assert_eq!(Bitset8::ALL.highest_zero(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn highest_zero(&self) -> Option<Index<Self>> {
+        find_last(0, bit_len::<Self>(), |w| !self._word(w)).map(Index::<Self>::from_usize)
+    }
+
+    /// Returns count of set bits strictly below [`index`][Index].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10110010);
+    ///
+    /// assert_eq!(bitset.rank(0.try_into()?), 0);
+    /// assert_eq!(bitset.rank(5.try_into()?), 2);
+    /// assert_eq!(bitset.rank(7.try_into()?), 3);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn rank(&self, index: Index<Self>) -> usize {
+        count_in(0, index.into_inner(), |w| self._word(w))
+    }
+
+    /// Returns [`Index`] of the `n`-th (starting from 0) set bit, or [`None`], if there are no more than `n` set bits.
+    ///
+    /// Inverse of [`Bitset::rank`] for set bits: `bitset.rank(bitset.select(n)?) == n`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10110010);
+    ///
+    /// assert_eq!(bitset.select(0), Some(1.try_into()?));
+    /// assert_eq!(bitset.select(3), Some(7.try_into()?));
+    /// assert_eq!(bitset.select(4), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn select(&self, n: usize) -> Option<Index<Self>> {
+        find_nth(0, bit_len::<Self>(), n, |w| self._word(w)).map(Index::<Self>::from_usize)
+    }
+
+    /// Returns [`Index`] of the lowest set bit strictly after `after`, or [`None`], if there is no such bit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10100100);
+    ///
+    /// assert_eq!(bitset.next_one(2.try_into()?), Some(5.try_into()?));
+    /// assert_eq!(bitset.next_one(7.try_into()?), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn next_one(&self, after: Index<Self>) -> Option<Index<Self>> {
+        find_first(after.into_inner() + 1, bit_len::<Self>(), |w| self._word(w))
+            .map(Index::<Self>::from_usize)
+    }
+
+    /// Returns [`Index`] of the highest set bit strictly before `before`, or [`None`], if there is no such bit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10100100);
+    ///
+    /// assert_eq!(bitset.prev_one(5.try_into()?), Some(2.try_into()?));
+    /// assert_eq!(bitset.prev_one(2.try_into()?), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn prev_one(&self, before: Index<Self>) -> Option<Index<Self>> {
+        find_last(0, before.into_inner(), |w| self._word(w)).map(Index::<Self>::from_usize)
+    }
+
+    /// Returns [`Index`] of the lowest not set bit strictly after `after`, or [`None`], if there is no such bit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b11011011);
+    ///
+    /// assert_eq!(bitset.next_zero(2.try_into()?), Some(5.try_into()?));
+    /// assert_eq!(bitset.next_zero(5.try_into()?), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn next_zero(&self, after: Index<Self>) -> Option<Index<Self>> {
+        find_first(after.into_inner() + 1, bit_len::<Self>(), |w| {
+            !self._word(w)
+        })
+        .map(Index::<Self>::from_usize)
+    }
+
+    /// Returns [`Index`] of the highest not set bit strictly before `before`, or [`None`], if there is no such bit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b11011011);
+    ///
+    /// assert_eq!(bitset.prev_zero(5.try_into()?), Some(2.try_into()?));
+    /// assert_eq!(bitset.prev_zero(2.try_into()?), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn prev_zero(&self, before: Index<Self>) -> Option<Index<Self>> {
+        find_last(0, before.into_inner(), |w| !self._word(w)).map(Index::<Self>::from_usize)
+    }
+
+    /// Replaces the bit at [`index`][Index] to the value. Returns a mutable reference to `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b01010100)
+    ///     .replace(1.try_into()?, One)
+    ///     .replace(2.try_into()?, Zero)
+    ///     .replace(3.try_into()?, One)
+    ///     .replace(4.try_into()?, Zero)
+    ///     .replace(5.try_into()?, One)
+    ///     .replace(6.try_into()?, Zero)
+    ///     .replace(7.try_into()?, One)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn replace(&mut self, index: Index<Self>, value: Bit) -> &mut Self {
+        if bool::from(value) {
+            self.set(index);
+        } else {
+            self.unset(index);
+        }
+        self
+    }
+
+    /// Sets bit at [`index`][Index]. Returns a mutable reference to `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::NONE
+    ///     .clone()
+    ///     .set(1.try_into()?)
+    ///     .set(3.try_into()?)
+    ///     .set(5.try_into()?)
+    ///     .set(7.try_into()?)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn set(&mut self, index: Index<Self>) -> &mut Self;
+
+    /// Unsets bit at [`index`][Index]. Returns a mutable reference to `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::ALL
+    ///     .clone()
+    ///     .unset(0.try_into()?)
+    ///     .unset(2.try_into()?)
+    ///     .unset(4.try_into()?)
+    ///     .unset(6.try_into()?)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn unset(&mut self, index: Index<Self>) -> &mut Self;
+
+    /// Flips bit at [`index`][Index]. Returns a mutable reference to `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b11110000)
+    ///     .flip(1.try_into()?)
+    ///     .flip(3.try_into()?)
+    ///     .flip(4.try_into()?)
+    ///     .flip(6.try_into()?)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn flip(&mut self, index: Index<Self>) -> &mut Self;
+
+    /// Includes all set bits of `other` in `self`. Returns a mutable reference to `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b00100010)
+    ///     .include(Bitset8::new(0b10001000))
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn include(&mut self, other: Self) -> &mut Self;
+
+    /// Excludes all set bits of `other` from `self`. Returns a mutable reference to `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b11111110)
+    ///     .exclude(Bitset8::new(0b01010100))
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn exclude(&mut self, other: Self) -> &mut Self;
+
+    /// Unsets the lowest set bit of the `Bitset` and returns its [`Index`],
+    /// or returns [`None`], if the `Bitset` is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let mut bitset = Bitset8::new(0b01010100);
+    ///
+    /// assert_eq!(bitset.pop_lowest(), Some(2.try_into()?));
+    /// assert_eq!(bitset.into_inner(), 0b01010000);
+    /// assert_eq!(Bitset8::NONE.clone().pop_lowest(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn pop_lowest(&mut self) -> Option<Index<Self>> {
+        let index = self.ones().next()?;
+        self.unset(index);
+        Some(index)
+    }
+
+    /// Unsets the highest set bit of the `Bitset` and returns its [`Index`],
+    /// or returns [`None`], if the `Bitset` is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let mut bitset = Bitset8::new(0b01010100);
+    ///
+    /// assert_eq!(bitset.pop_highest(), Some(6.try_into()?));
+    /// assert_eq!(bitset.into_inner(), 0b00010100);
+    /// assert_eq!(Bitset8::NONE.clone().pop_highest(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn pop_highest(&mut self) -> Option<Index<Self>> {
+        let index = self.ones().next_back()?;
+        self.unset(index);
+        Some(index)
+    }
+
+    /// Sets all bits in `range`. Returns a mutable reference to `self`.
+    ///
+    /// Accepts any kind of range over `usize`; parts of the range outside of the `Bitset` are ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let bitset = Bitset16::NONE
+    ///     .insert_range(2..=4)
+    ///     .insert_range(12..)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b1111000000011100);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn insert_range<R>(&mut self, range: R) -> &mut Self
+    where
+        R: RangeBounds<usize>,
+    {
+        fill_range(self, clamp_range::<Self>(range), true);
+        self
+    }
+
+    /// Unsets all bits in `range`. Returns a mutable reference to `self`.
+    ///
+    /// Accepts any kind of range over `usize`; parts of the range outside of the `Bitset` are ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let bitset = Bitset16::ALL
+    ///     .remove_range(..4)
+    ///     .remove_range(8..100)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b0000000011110000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn remove_range<R>(&mut self, range: R) -> &mut Self
+    where
+        R: RangeBounds<usize>,
+    {
+        fill_range(self, clamp_range::<Self>(range), false);
+        self
+    }
+
+    /// Flips all bits in `range`. Returns a mutable reference to `self`.
+    ///
+    /// Accepts any kind of range over `usize`; parts of the range outside of the `Bitset` are ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let bitset = Bitset16::new(0b0000000011110000)
+    ///     .flip_range(..8)
+    ///     .flip_range(14..)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b1100000000001111);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn flip_range<R>(&mut self, range: R) -> &mut Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = clamp_range::<Self>(range);
+        let mut k = range.start;
+        while k < range.end {
+            let width = (range.end - k).min(64);
+            let bits = self._bits(k, width);
+            self._set_bits(k, width, !bits & (!0 >> (64 - width)));
+            k += width;
+        }
+        self
+    }
+
+    /// Returns value of the field, described by [`FieldSpec`] `F`,
+    /// or [`None`], if bits of the field don't represent a valid value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{access::FieldSpec, prelude::*};
+    ///
+    /// struct Prescaler;
+    ///
+    /// impl FieldSpec for Prescaler {
+    ///     type Bitset = Bitset16;
+    ///     type Value = u8;
+    ///     const OFFSET: usize = 4;
+    ///     const WIDTH: usize = 8;
+    /// }
+    ///
+    /// let register = Bitset16::new(0b0000101010100000);
+    ///
+    /// assert_eq!(register.extract::<Prescaler>(), Some(0b10101010));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn extract<F>(&self) -> Option<F::Value>
+    where
+        F: FieldSpec<Bitset = Self>,
+    {
+        const {
+            assert!(
+                F::WIDTH <= 64 && F::OFFSET + F::WIDTH <= Self::BYTE_SIZE * 8,
+                "field must be at most 64 bits wide and fit into the Bitset"
+            )
+        };
+        F::Value::try_from(self._bits(F::OFFSET, F::WIDTH)).ok()
+    }
+
+    /// Writes `value` into the field, described by [`FieldSpec`] `F`.
+    /// Bits of `value` that don't fit into the field are ignored.
+    /// Returns a mutable reference to `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{access::FieldSpec, prelude::*};
+    ///
+    /// struct Prescaler;
+    ///
+    /// impl FieldSpec for Prescaler {
+    ///     type Bitset = Bitset16;
+    ///     type Value = u8;
+    ///     const OFFSET: usize = 4;
+    ///     const WIDTH: usize = 8;
+    /// }
+    ///
+    /// let register = Bitset16::ALL.insert::<Prescaler>(0).build();
+    ///
+    /// assert_eq!(register.into_inner(), 0b1111000000001111);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn insert<F>(&mut self, value: F::Value) -> &mut Self
+    where
+        F: FieldSpec<Bitset = Self>,
+    {
+        const {
+            assert!(
+                F::WIDTH <= 64 && F::OFFSET + F::WIDTH <= Self::BYTE_SIZE * 8,
+                "field must be at most 64 bits wide and fit into the Bitset"
+            )
+        };
+        self._set_bits(F::OFFSET, F::WIDTH, value.into());
+        self
+    }
+
+    /// Returns `width` (up to 64) bits of the `Bitset`, starting from bit `offset`, converted into `V`.
+    ///
+    /// Runtime checked counterpart of [`Bitset::extract`], for fields not known at compile time.
+    ///
+    /// # Errors
+    /// Returns [`ConvError`], if the field is wider than 64 bits or doesn't fit into the `Bitset`,
+    /// or if its value doesn't fit into `V`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let register = Bitset16::new(0b0000101010100000);
+    ///
+    /// assert_eq!(register.extract_bits::<u8>(4, 8)?, 0b10101010);
+    /// assert!(register.extract_bits::<u8>(3, 9).is_err());
+    /// assert!(register.extract_bits::<u8>(12, 5).is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn extract_bits<V>(&self, offset: usize, width: usize) -> ConvResult<V>
+    where
+        V: TryFrom<u64>,
+    {
+        check_field::<Self>(offset, width)?;
+        let bits = self._bits(offset, width);
+        V::try_from(bits).map_err(|_| {
+            ConvError::new(
+                ConvTarget::Set(width),
+                ConvTarget::Set(std::mem::size_of::<V>() * 8),
+            )
+        })
+    }
+
+    /// Writes `value` into `width` (up to 64) bits of the `Bitset`, starting from bit `offset`.
+    /// Returns a mutable reference to `self`.
+    ///
+    /// Runtime checked counterpart of [`Bitset::insert`], for fields not known at compile time.
+    ///
+    /// # Errors
+    /// Returns [`ConvError`] and leaves `self` unchanged, if the field is wider than 64 bits
+    /// or doesn't fit into the `Bitset`, or if `value` doesn't fit into `width` bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let register = Bitset16::NONE.insert_bits(4, 3, 0b101u8)?.build();
+    ///
+    /// assert_eq!(register.into_inner(), 0b0000000001010000);
+    /// assert!(Bitset16::NONE.insert_bits(4, 3, 0b1000u8).is_err());
+    /// assert!(Bitset16::NONE.insert_bits(14, 3, 0u8).is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn insert_bits<V>(&mut self, offset: usize, width: usize, value: V) -> ConvResult<&mut Self>
+    where
+        V: Into<u64>,
+    {
+        check_field::<Self>(offset, width)?;
+        let value = value.into();
+        if width < 64 && value >> width != 0 {
+            return Err(ConvError::new(
+                ConvTarget::Raw(value as usize),
+                ConvTarget::Set(width),
+            ));
+        }
+        self._set_bits(offset, width, value);
+        Ok(self)
+    }
+
+    /// Returns bits of the `Bitset` in `range` (up to 128 bits), shifted down to bit `0` and converted into `V`.
+    ///
+    /// Unbounded end of the range is the end of the `Bitset`.
+    ///
+    /// # Errors
+    /// Returns [`ConvError`], if the range is wider than 128 bits or doesn't fit into the `Bitset`,
+    /// or if its value doesn't fit into `V`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let register = Bitset16::new(0b1010101011110000);
+    ///
+    /// assert_eq!(register.get_range::<u8, _>(4..12)?, 0b10101111);
+    /// assert_eq!(register.get_range::<u128, _>(12..)?, 0b1010);
+    /// assert!(register.get_range::<u8, _>(4..=13).is_err());
+    /// assert!(register.get_range::<u32, _>(8..20).is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn get_range<V, R>(&self, range: R) -> ConvResult<V>
+    where
+        V: TryFrom<u128>,
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i.saturating_add(1),
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => bit_len::<Self>(),
+        };
+        if end > bit_len::<Self>() {
+            return Err(ConvError::new(
+                ConvTarget::Raw(end),
+                ConvTarget::Set(bit_len::<Self>()),
+            ));
+        }
+        let width = end.saturating_sub(start);
+        if width > 128 {
+            return Err(ConvError::new(ConvTarget::Set(width), ConvTarget::Set(128)));
+        }
+
+        let low = self._bits(start, width.min(64)) as u128;
+        let high = match width > 64 {
+            true => self._bits(start + 64, width - 64) as u128,
+            false => 0,
+        };
+        V::try_from(high << 64 | low).map_err(|_| {
+            ConvError::new(
+                ConvTarget::Set(width),
+                ConvTarget::Set(std::mem::size_of::<V>() * 8),
+            )
+        })
+    }
+
+    /// Applies `f` to a copy of `self` and stores the result back into `self`,
+    /// so that `self` is read exactly once and written exactly once, regardless of how many changes `f` makes.
+    /// Returns a mutable reference to `self`.
+    ///
+    /// This is the usual read-modify-write pattern for bitsets mapped onto registers.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let mut bitset = Bitset16::new(0b0000000011110000);
+    /// bitset.modify(|r| {
+    ///     r.set(0.try_into().unwrap()).remove_range(4..6);
+    /// });
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b0000000011000001);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn modify<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        let mut value = self.clone();
+        f(&mut value);
+        *self = value;
+        self
+    }
+
+    /// Returns a copy of the [`Bit`] at [`index`][Index].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::NONE.set(1.try_into()?).build();
+    ///
+    /// assert_eq!(bitset.bit(0.try_into()?), Zero);
+    /// assert_eq!(bitset.bit(1.try_into()?), One);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn bit(&self, index: Index<Self>) -> Bit;
+
+    #[doc(hidden)]
+    /// Returns 8 bits of the `Bitset` starting from bit `8 * index`, packed into [`u8`].
+    ///
+    /// Used by byte-level algorithms, such as [`Bitset::expand`].
+    fn _byte(&self, index: usize) -> u8 {
+        (0..8)
+            .filter(|&i| bool::from(self.bit(Index::<Self>::from_usize(index * 8 + i))))
+            .fold(0, |acc, i| acc | 1 << i)
+    }
+
+    #[doc(hidden)]
+    /// Replaces 8 bits of the `Bitset` starting from bit `8 * index` with bits of `byte`.
+    ///
+    /// Used by byte-level algorithms, such as [`Bitset::expand`].
+    fn _set_byte(&mut self, index: usize, byte: u8) {
+        for i in 0..8 {
+            self.replace(
+                Index::<Self>::from_usize(index * 8 + i),
+                Bit::from(byte & 1 << i != 0),
+            );
+        }
+    }
+
+    #[doc(hidden)]
+    /// Returns 64 bits of the `Bitset` starting from bit `64 * index`, packed into [`u64`].
+    ///
+    /// Bits past the end of the `Bitset` are not set.
+    /// Used by word-level algorithms, such as [`Ones`] and [`Zeros`] iterators.
+    fn _word(&self, index: usize) -> u64 {
+        (index * 8..Self::BYTE_SIZE.min(index * 8 + 8))
+            .fold(0, |acc, i| acc | (self._byte(i) as u64) << (i % 8 * 8))
+    }
+
+    #[doc(hidden)]
+    /// Returns `width` (up to 64) bits of the `Bitset` starting from bit `offset`, packed into [`u64`].
+    ///
+    /// Bits past the end of the `Bitset` are not set.
+    fn _bits(&self, offset: usize, width: usize) -> u64 {
+        debug_assert!(width <= 64);
+        let (w, shift) = (offset / 64, offset % 64);
+        let mut bits = self._word(w) >> shift;
+        if shift > 0 && shift + width > 64 {
+            bits |= self._word(w + 1) << (64 - shift);
+        }
+        if width < 64 {
+            bits &= !(!0 << width);
+        }
+        bits
+    }
+
+    #[doc(hidden)]
+    /// Replaces `width` (up to 64) bits of the `Bitset` starting from bit `offset`
+    /// with the lowest bits of `value`.
+    fn _set_bits(&mut self, offset: usize, width: usize, value: u64) {
+        debug_assert!(width <= 64 && offset + width <= bit_len::<Self>());
+        let end = offset + width;
+        let mut b = offset / 8;
+        while b * 8 < end {
+            let start = b * 8;
+            let low = offset.saturating_sub(start);
+            let high = (end - start).min(8);
+            let mask = (0xFFu16 >> (8 - (high - low)) << low) as u8;
+            let new = if start >= offset {
+                (value >> (start - offset)) as u8
+            } else {
+                (value << low) as u8
+            };
+            self._set_byte(b, (self._byte(b) & !mask) | (new & mask));
+            b += 1;
+        }
+    }
+
+    /// Returns a [`BitRef`] holding an immutable reference to the bit at [`index`][Index].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::NONE.set(1.try_into()?).build();
+    ///
+    /// assert_eq!(*bitset.bit_ref(0.try_into()?), Zero);
+    /// assert_eq!(*bitset.bit_ref(1.try_into()?), One);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn bit_ref(&self, index: Index<Self>) -> BitRef<'_, Self>;
+
+    /// Returns a [`BitMut`] holding a mutable reference to the bit at [`index`][Index].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let mut bitset = Bitset8::NONE;
+    ///
+    /// assert_eq!(bitset.bit(0.try_into()?), Zero);
+    /// assert_eq!(bitset.bit(1.try_into()?), Zero);
+    ///
+    /// *bitset.bit_mut(0.try_into()?) = One;
+    ///
+    /// assert_eq!(bitset.bit(0.try_into()?), One);
+    /// assert_eq!(bitset.bit(1.try_into()?), Zero);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn bit_mut(&mut self, index: Index<Self>) -> BitMut<'_, Self>;
+
+    /// Returns Set complement (`self′`) of `Bitset`.<br/>
+    /// Alias for [`!`][core::ops::Not] operator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11110000);
+    /// let b = a.complement();
+    ///
+    /// assert_eq!(a.into_inner(), 0b11110000);
+    /// assert_eq!(b.into_inner(), 0b00001111);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn complement(self) -> Self;
+
+    /// Returns Set union (`self ∪ other`) of two `Bitset`s.<br/>
+    /// Alias for [`|`][core::ops::BitOr] operator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11001100);
+    /// let b = Bitset8::new(0b11110000);
+    /// let c = a.union(b);
+    ///
+    /// assert_eq!(c.into_inner(), 0b11111100);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn union(self, other: Self) -> Self;
+
+    /// Returns Set intersection (`self ∩ other`) of two `Bitset`s.<br/>
+    /// Alias for [`&`][core::ops::BitAnd] operator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11001100);
+    /// let b = Bitset8::new(0b11110000);
+    /// let c = a.intersection(b);
+    ///
+    /// assert_eq!(c.into_inner(), 0b11000000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn intersection(self, other: Self) -> Self;
+
+    /// Returns Set difference (`self \ other`) of two `Bitset`s.<br/>
+    /// Alias for [`-`][core::ops::Sub] operator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11001100);
+    /// let b = Bitset8::new(0b11110000);
+    /// let c = a.difference(b);
+    ///
+    /// assert_eq!(c.into_inner(), 0b00001100);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn difference(self, other: Self) -> Self {
+        self.intersection(other.complement())
+    }
+
+    /// Returns Set symmetric difference (`self Δ other`) of two `Bitset`s.<br/>
+    /// Alias for [`^`][core::ops::BitXor] operator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11001100);
+    /// let b = Bitset8::new(0b11110000);
+    /// let c = a.sym_difference(b);
+    ///
+    /// assert_eq!(c.into_inner(), 0b00111100);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn sym_difference(self, other: Self) -> Self;
+
+    /// Returns Set union of all `Bitset`s of `iter`, or [`Bitset::NONE`], if there are none.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let masks = [Bitset8::new(0b0001), Bitset8::new(0b0100), Bitset8::new(0b1100)];
+    ///
+    /// assert_eq!(Bitset8::union_of(masks).into_inner(), 0b1101);
+    /// assert_eq!(Bitset8::union_of([]), Bitset8::NONE);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn union_of<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        iter.into_iter().fold(Self::NONE.clone(), Self::union)
+    }
+
+    /// Returns Set intersection of all `Bitset`s of `iter`, or [`Bitset::ALL`], if there are none.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let masks = [Bitset8::new(0b0111), Bitset8::new(0b0110), Bitset8::new(0b1100)];
+    ///
+    /// assert_eq!(Bitset8::intersection_of(masks).into_inner(), 0b0100);
+    /// assert_eq!(Bitset8::intersection_of([]), Bitset8::ALL);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn intersection_of<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        iter.into_iter().fold(Self::ALL.clone(), Self::intersection)
+    }
+
+    /// Merges changes of `ours` and `theirs`, both made to `base`.
+    /// Returns the merged `Bitset` and the mask of bits changed on both sides.
+    ///
+    /// Each bit of the result takes the changed value, if either side changed it.
+    /// A single bit can only be changed in one way, so changes of both sides never disagree,
+    /// but the mask of concurrent changes lets callers detect and audit them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let base = Bitset8::new(0b00001111);
+    /// let ours = Bitset8::new(0b00011110);
+    /// let theirs = Bitset8::new(0b00101110);
+    /// let (merged, concurrent) = Bitset8::merge3(&base, &ours, &theirs);
+    ///
+    /// assert_eq!(merged.into_inner(), 0b00111110);
+    /// assert_eq!(concurrent.into_inner(), 0b00000001);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn merge3(base: &Self, ours: &Self, theirs: &Self) -> (Self, Self) {
+        let ours_changes = base.clone().sym_difference(ours.clone());
+        let theirs_changes = base.clone().sym_difference(theirs.clone());
+        let merged = base
+            .clone()
+            .sym_difference(ours_changes.clone().union(theirs_changes.clone()));
+        (merged, ours_changes.intersection(theirs_changes))
+    }
+
+    /// Returns bit blend of `self` and `other` under `mask`: bits of `other`, where `mask` is set,
+    /// and bits of `self` elsewhere, same as `(self & !mask) | (other & mask)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let register = Bitset8::new(0b1010_1010);
+    /// let value = Bitset8::new(0b0000_0101);
+    /// let write_mask = Bitset8::new(0b0000_1111);
+    ///
+    /// assert_eq!(register.merge(value, write_mask).into_inner(), 0b1010_0101);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn merge(self, other: Self, mask: Self) -> Self {
+        self.difference(mask.clone())
+            .union(other.intersection(mask))
+    }
+
+    /// Replaces bits of `self`, where `mask` is set, with bits of `other`.
+    /// Returns a mutable reference to `self`.
+    ///
+    /// In-place variant of [`Bitset::merge`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let mut register = Bitset8::new(0b1010_1010);
+    /// register
+    ///     .merge_in(Bitset8::new(0b0000_0101), Bitset8::new(0b0000_1111))
+    ///     .merge_in(Bitset8::ALL, Bitset8::new(0b1000_0000));
+    ///
+    /// assert_eq!(register.into_inner(), 0b1010_0101);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn merge_in(&mut self, other: Self, mask: Self) -> &mut Self {
+        *self = self.clone().merge(other, mask);
+        self
+    }
+
+    /// Returns [`true`], if `self` contains all of the set bits from `other` and [`false`] otherwise.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11111100);
+    /// let b = Bitset8::new(0b11110000);
+    /// let c = Bitset8::new(0b00001111);
+    ///
+    /// assert!(a.includes(&b));
+    /// assert!(!a.includes(&c));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn includes(&self, other: &Self) -> bool {
+        for i in other.ones() {
+            if !bool::from(self.bit(i)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns [`true`], if `self` shares any set bits with `other` and [`false`] otherwise.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11111100);
+    /// let b = Bitset8::new(0b00001111);
+    /// let c = Bitset8::new(0b00000011);
+    ///
+    /// assert!(a.intersects(&b));
+    /// assert!(!a.intersects(&c));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn intersects(&self, other: &Self) -> bool {
+        for i in other.ones() {
+            if bool::from(self.bit(i)) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns [`true`], if all of the set bits of `self` are set in `other` (`self ⊆ other`), and [`false`] otherwise.
+    ///
+    /// Same as `other.includes(self)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11110000);
+    /// let b = Bitset8::new(0b11111100);
+    ///
+    /// assert!(a.is_subset(&b));
+    /// assert!(!b.is_subset(&a));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn is_subset(&self, other: &Self) -> bool {
+        other.includes(self)
+    }
+
+    /// Returns [`true`], if all of the set bits of `other` are set in `self` (`self ⊇ other`), and [`false`] otherwise.
+    ///
+    /// Same as `self.includes(other)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11111100);
+    /// let b = Bitset8::new(0b11110000);
+    ///
+    /// assert!(a.is_superset(&b));
+    /// assert!(!b.is_superset(&a));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn is_superset(&self, other: &Self) -> bool {
+        self.includes(other)
+    }
+
+    /// Returns [`true`], if `self` and `other` share no set bits (`self ∩ other = ∅`), and [`false`] otherwise.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b11110000);
+    /// let b = Bitset8::new(0b00001111);
+    ///
+    /// assert!(a.is_disjoint(&b));
+    /// assert!(!a.is_disjoint(&a));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn is_disjoint(&self, other: &Self) -> bool {
+        !self.intersects(other)
+    }
+
+    /// Returns [`true`], if bits of `self`, where `care_mask` is set, are equal to the bits of `pattern`,
+    /// and [`false`] otherwise. Bits outside of `care_mask` are "don't care" and never affect the result.
+    ///
+    /// Same as `self & care_mask == pattern & care_mask`, but compares in a single pass
+    /// without constructing the masked `Bitset`s.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// // Opcodes matching 0b10xx_x1xx.
+    /// let pattern = Bitset8::new(0b1000_0100);
+    /// let care_mask = Bitset8::new(0b1100_0100);
+    ///
+    /// assert!(Bitset8::new(0b1011_0110).matches_masked(&pattern, &care_mask));
+    /// assert!(!Bitset8::new(0b1111_0110).matches_masked(&pattern, &care_mask));
+    /// assert!(!Bitset8::new(0b1000_0000).matches_masked(&pattern, &care_mask));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn matches_masked(&self, pattern: &Self, care_mask: &Self) -> bool {
+        (0..bit_len::<Self>().div_ceil(64))
+            .all(|w| (self._word(w) ^ pattern._word(w)) & care_mask._word(w) == 0)
+    }
+
+    /// Returns the count of bits set in both `self` and `other`.
+    ///
+    /// Same as `self.intersection(other).count_ones()`, but counts in a single pass
+    /// without constructing the combined `Bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b00001111);
+    /// let b = Bitset8::new(0b00111100);
+    ///
+    /// assert_eq!(a.intersection_count(&b), 2);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn intersection_count(&self, other: &Self) -> usize {
+        count_combined(self, other, |a, b| a & b)
+    }
+
+    /// Returns the count of bits set in either `self` or `other`.
+    ///
+    /// Same as `self.union(other).count_ones()`, but counts in a single pass
+    /// without constructing the combined `Bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b00001111);
+    /// let b = Bitset8::new(0b00111100);
+    ///
+    /// assert_eq!(a.union_count(&b), 6);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn union_count(&self, other: &Self) -> usize {
+        count_combined(self, other, |a, b| a | b)
+    }
+
+    /// Returns the count of bits set in `self`, but not in `other`.
+    ///
+    /// Same as `self.difference(other).count_ones()`, but counts in a single pass
+    /// without constructing the combined `Bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b00001111);
+    /// let b = Bitset8::new(0b00111100);
+    ///
+    /// assert_eq!(a.difference_count(&b), 2);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn difference_count(&self, other: &Self) -> usize {
+        count_combined(self, other, |a, b| a & !b)
+    }
+
+    /// Returns the count of bits set in exactly one of `self` and `other`.
+    ///
+    /// Same as `self.sym_difference(other).count_ones()`, but counts in a single pass
+    /// without constructing the combined `Bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b00001111);
+    /// let b = Bitset8::new(0b00111100);
+    ///
+    /// assert_eq!(a.sym_difference_count(&b), 4);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn sym_difference_count(&self, other: &Self) -> usize {
+        count_combined(self, other, |a, b| a ^ b)
+    }
+
+    /// Returns Hamming distance between `self` and `other`: the count of positions, at which their bits differ.
+    ///
+    /// Same as [`sym_difference_count`][Bitset::sym_difference_count].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b10110100);
+    /// let b = Bitset8::new(0b10011101);
+    ///
+    /// assert_eq!(a.hamming_distance(&b), 3);
+    /// assert_eq!(a.hamming_distance(&a), 0);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn hamming_distance(&self, other: &Self) -> usize {
+        self.sym_difference_count(other)
+    }
+
+    /// Returns Jaccard similarity of `self` and `other`:
+    /// the count of bits set in both, divided by the count of bits set in either.
+    ///
+    /// Returns `1.0` if both `Bitset`s are empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b00001111);
+    /// let b = Bitset8::new(0b00111100);
+    ///
+    /// assert_eq!(a.jaccard(&b), 2.0 / 6.0);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn jaccard(&self, other: &Self) -> f64 {
+        let union = self.union_count(other);
+        if union == 0 {
+            return 1.0;
+        }
+        self.intersection_count(other) as f64 / union as f64
+    }
+
+    /// Returns cosine similarity of `self` and `other`, treated as binary vectors:
+    /// the count of bits set in both, divided by the geometric mean of their counts of set bits.
+    ///
+    /// Returns `0.0` if any of the `Bitset`s is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b00000011);
+    /// let b = Bitset8::new(0b00001110);
+    ///
+    /// assert_eq!(a.cosine_similarity(&b), 1.0 / 6.0f64.sqrt());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn cosine_similarity(&self, other: &Self) -> f64 {
+        let (ones_a, ones_b) = (self.count_ones(), other.count_ones());
+        if ones_a == 0 || ones_b == 0 {
+            return 0.0;
+        }
+        self.intersection_count(other) as f64 / ((ones_a * ones_b) as f64).sqrt()
+    }
+
+    /// Returns [`Index`] of the lowest bit, that differs between `self` and `other`,
+    /// or [`None`], if they are equal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b10110101);
+    /// let b = Bitset8::new(0b10100101);
+    ///
+    /// assert_eq!(a.first_difference(&b), Some(4.try_into()?));
+    /// assert_eq!(a.first_difference(&a), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn first_difference(&self, other: &Self) -> Option<Index<Self>> {
+        (0..Self::BYTE_SIZE.div_ceil(8)).find_map(|w| {
+            let diff = self._word(w) ^ other._word(w);
+            (diff != 0).then(|| Index::<Self>::from_usize(w * 64 + diff.trailing_zeros() as usize))
+        })
+    }
+
+    /// Returns length of the longest common prefix of `self` and `other`,
+    /// starting from [`Index`] `0`: the count of equal bits before the first differing one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(0b10110101);
+    /// let b = Bitset8::new(0b10100101);
+    ///
+    /// assert_eq!(a.common_prefix_len(&b), 4);
+    /// assert_eq!(a.common_prefix_len(&a), 8);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn common_prefix_len(&self, other: &Self) -> usize {
+        self.first_difference(other)
+            .map_or(Self::BYTE_SIZE * 8, |i| i.into_inner())
+    }
+
+    /// Combines two `Bitset`s to create a bigger one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    ///
+    /// let bitset8_1 = Bitset8::new(0b00000001);
+    /// let bitset8_2 = Bitset8::new(0b00000011);
+    /// let bitset16: Bitset16 = bitset8_1.combine(bitset8_2);
+    ///
+    /// assert_eq!(bitset16.into_inner(), 0b0000001100000001);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn combine<Other, Res>(self, other: Other) -> Res
+    where
+        Other: Bitset,
+        Res: Bitset,
+        Self::Size: Combines<Other::Size, Res::Size> + Smaller<Res::Size>,
+        Other::Size: Smaller<Res::Size>,
+    {
+        let mut result = Res::NONE.clone();
+        for i in 0..Self::BYTE_SIZE {
+            result._set_byte(i, self._byte(i));
+        }
+        for i in 0..Other::BYTE_SIZE {
+            result._set_byte(Self::BYTE_SIZE + i, other._byte(i));
+        }
+        result
+    }
+
+    /// Same as [`Bitset::combine`], kept for compatibility.
+    #[deprecated(note = "use `Bitset::combine` instead")]
+    fn combine_optimized<Other, Res>(self, other: Other) -> Res
+    where
+        Self: LeftAligned,
+        Other: Bitset + LeftAligned,
+        Res: Bitset + LeftAligned,
+        Self::Size: Combines<Other::Size, Res::Size> + Smaller<Res::Size>,
+        Other::Size: Smaller<Res::Size>,
+    {
+        self.combine(other)
+    }
+
+    /// Splits `Bitset` into two smaller ones.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    ///
+    /// let bitset16 = Bitset16::from(0b0000001100000001);
+    /// let (bitset8_1, bitset8_2): (Bitset8, Bitset8) = bitset16.split();
+    ///
+    /// assert_eq!(bitset8_1.into_inner(), 0b00000001);
+    /// assert_eq!(bitset8_2.into_inner(), 0b00000011);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn split<Res1, Res2>(self) -> (Res1, Res2)
+    where
+        Res1: Bitset,
+        Res2: Bitset,
+        Self::Size: Splits<Res1::Size, Res2::Size>,
+        Res1::Size: Smaller<Self::Size>,
+        Res2::Size: Smaller<Self::Size>,
+    {
+        let mut result1 = Res1::NONE.clone();
+        let mut result2 = Res2::NONE.clone();
+        for i in 0..Res1::BYTE_SIZE {
+            result1._set_byte(i, self._byte(i));
+        }
+        for i in 0..Res2::BYTE_SIZE {
+            result2._set_byte(i, self._byte(Res1::BYTE_SIZE + i));
+        }
+        (result1, result2)
+    }
+
+    /// Same as [`Bitset::split`], kept for compatibility.
+    #[deprecated(note = "use `Bitset::split` instead")]
+    fn split_optimized<Res1, Res2>(self) -> (Res1, Res2)
+    where
+        Self: LeftAligned,
+        Res1: Bitset + LeftAligned,
+        Res2: Bitset + LeftAligned,
+        Self::Size: Splits<Res1::Size, Res2::Size>,
+        Res1::Size: Smaller<Self::Size>,
+        Res2::Size: Smaller<Self::Size>,
+    {
+        self.split()
+    }
+
+    /// Interleaves bits of two `Bitset`s to create a bigger one: bit `i` of `self` becomes bit `2 * i`
+    /// of the result, and bit `i` of `other` becomes bit `2 * i + 1`.
+    ///
+    /// Encodes a pair of coordinates as a position on Z-order (Morton) curve.
+    /// Unlike [`Bitset::combine`], which concatenates `Bitset`s.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    ///
+    /// let x = Bitset8::new(0b00000101);
+    /// let y = Bitset8::new(0b00000011);
+    /// let morton: Bitset16 = x.interleave(y);
+    ///
+    /// assert_eq!(morton.into_inner(), 0b0000000000011011);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn interleave<Res>(self, other: Self) -> Res
+    where
+        Res: Bitset,
+        Self::Size: Combines<Self::Size, Res::Size> + Smaller<Res::Size>,
+    {
+        let mut result = Res::NONE.clone();
+        for w in 0..bit_len::<Self>().div_ceil(64) {
+            let (a, b) = (self._word(w), other._word(w));
+            for half in 0..2 {
+                let offset = w * 128 + half * 64;
+                if offset >= bit_len::<Res>() {
+                    break;
+                }
+                let shift = half * 32;
+                let word = spread_bits((a >> shift) as u32) | spread_bits((b >> shift) as u32) << 1;
+                result._set_bits(offset, (bit_len::<Res>() - offset).min(64), word);
+            }
+        }
+        result
+    }
+
+    /// Splits `Bitset` into two smaller ones, taking bits at even positions into the first one
+    /// and bits at odd positions into the second one. Reverses [`Bitset::interleave`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    ///
+    /// let morton = Bitset16::new(0b0000000000011011);
+    /// let (x, y): (Bitset8, Bitset8) = morton.deinterleave();
+    ///
+    /// assert_eq!(x.into_inner(), 0b00000101);
+    /// assert_eq!(y.into_inner(), 0b00000011);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn deinterleave<Res>(self) -> (Res, Res)
+    where
+        Res: Bitset,
+        Self::Size: Splits<Res::Size, Res::Size>,
+        Res::Size: Smaller<Self::Size>,
+    {
+        let mut result1 = Res::NONE.clone();
+        let mut result2 = Res::NONE.clone();
+        for w in 0..bit_len::<Res>().div_ceil(64) {
+            let (low, high) = (self._word(w * 2), self._word(w * 2 + 1));
+            let even = compact_bits(low) as u64 | (compact_bits(high) as u64) << 32;
+            let odd = compact_bits(low >> 1) as u64 | (compact_bits(high >> 1) as u64) << 32;
+            let width = (bit_len::<Res>() - w * 64).min(64);
+            result1._set_bits(w * 64, width, even);
+            result2._set_bits(w * 64, width, odd);
+        }
+        (result1, result2)
+    }
+
+    /// Multiplies `Bitset`s as polynomials over GF(2), where bit `i` is the coefficient of `x^i`,
+    /// creating a `Bitset` twice as big to fit the whole product.
+    ///
+    /// Uses `pclmulqdq` instructions, if they are available at runtime.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    ///
+    /// // (x + 1) * (x^2 + x + 1) = x^3 + 1
+    /// let a = Bitset8::new(0b00000011);
+    /// let b = Bitset8::new(0b00000111);
+    /// let product: Bitset16 = a.clmul(b);
+    ///
+    /// assert_eq!(product.into_inner(), 0b0000000000001001);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn clmul<Res>(self, other: Self) -> Res
+    where
+        Res: Bitset,
+        Self::Size: Combines<Self::Size, Res::Size> + Smaller<Res::Size>,
+    {
+        let words = bit_len::<Self>().div_ceil(64);
+        let mut product = vec![0; words * 2];
+        for i in 0..words {
+            let a = self._word(i);
+            if a == 0 {
+                continue;
+            }
+            for j in 0..words {
+                let (low, high) = dispatch::clmul(a, other._word(j));
+                product[i + j] ^= low;
+                product[i + j + 1] ^= high;
+            }
+        }
+
+        let mut result = Res::NONE.clone();
+        for (w, &word) in product.iter().enumerate() {
+            let offset = w * 64;
+            if offset >= bit_len::<Res>() {
+                break;
+            }
+            result._set_bits(offset, (bit_len::<Res>() - offset).min(64), word);
+        }
+        result
+    }
+
+    /// Divides `Bitset`s as polynomials over GF(2), where bit `i` is the coefficient of `x^i`.
+    /// Returns quotient and remainder.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// // x^4 + x + 1 = (x^2 + x) * (x^2 + x + 1) + 1
+    /// let dividend = Bitset8::new(0b00010011);
+    /// let divisor = Bitset8::new(0b00000111);
+    /// let (quotient, remainder) = dividend.poly_div_rem(&divisor);
+    ///
+    /// assert_eq!(quotient.into_inner(), 0b00000110);
+    /// assert_eq!(remainder.into_inner(), 0b00000001);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn poly_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_degree = divisor
+            .highest_one()
+            .expect("attempt to divide by zero polynomial")
+            .into_inner();
+
+        let mut quotient = Self::NONE.clone();
+        let mut remainder = self.clone();
+        while let Some(degree) = remainder.highest_one() {
+            let degree = degree.into_inner();
+            if degree < divisor_degree {
+                break;
+            }
+            let shift = degree - divisor_degree;
+            remainder = remainder.sym_difference(shifted_left(divisor, shift));
+            quotient.set(Index::from_usize(shift));
+        }
+        (quotient, remainder)
+    }
+
+    /// Returns remainder of dividing `Bitset`s as polynomials over GF(2).
+    ///
+    /// Same as [`Bitset::poly_div_rem`], but discards the quotient.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// // x^8 mod (x^4 + x + 1) = x^2 + 1
+    /// let bitset = Bitset16::new(0b0000000100000000);
+    /// let modulus = Bitset16::new(0b0000000000010011);
+    ///
+    /// assert_eq!(bitset.poly_rem(&modulus).into_inner(), 0b0000000000000101);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn poly_rem(&self, modulus: &Self) -> Self {
+        self.poly_div_rem(modulus).1
+    }
+
+    /// Deposits low bits of the `Bitset` to positions of set bits of `mask`, from the least significant one,
+    /// like `pdep` instruction. Other bits of the result are not set.
+    ///
+    /// Uses `bmi2` instructions, if they are available at runtime. Reverses [`Bitset::extract_masked`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b00000101);
+    /// let mask = Bitset8::new(0b10110010);
+    ///
+    /// assert_eq!(bitset.deposit_masked(&mask).into_inner(), 0b00100010);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn deposit_masked(&self, mask: &Self) -> Self {
+        let mut result = Self::NONE.clone();
+        let mut taken = 0;
+        for w in 0..bit_len::<Self>().div_ceil(64) {
+            let mask_word = mask._word(w);
+            if mask_word == 0 {
+                continue;
+            }
+            let width = mask_word.count_ones() as usize;
+            let bits = dispatch::deposit(self._bits(taken, width), mask_word);
+            result._set_bits(w * 64, (bit_len::<Self>() - w * 64).min(64), bits);
+            taken += width;
+        }
+        result
+    }
+
+    /// Extracts bits of the `Bitset` at positions of set bits of `mask`, packing them into low bits of the result,
+    /// like `pext` instruction. Other bits of the result are not set.
+    ///
+    /// Uses `bmi2` instructions, if they are available at runtime. Reverses [`Bitset::deposit_masked`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10000111);
+    /// let mask = Bitset8::new(0b10110010);
+    ///
+    /// assert_eq!(bitset.extract_masked(&mask).into_inner(), 0b00001001);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn extract_masked(&self, mask: &Self) -> Self {
+        let mut result = Self::NONE.clone();
+        let mut packed = 0;
+        for w in 0..bit_len::<Self>().div_ceil(64) {
+            let mask_word = mask._word(w);
+            if mask_word == 0 {
+                continue;
+            }
+            let width = mask_word.count_ones() as usize;
+            result._set_bits(packed, width, dispatch::extract(self._word(w), mask_word));
+            packed += width;
+        }
+        result
+    }
+
+    /// Collects bits at the [`indeces`][Index] into a new `Bitset`, in order:
+    /// bit at `indices[0]` becomes the least significant bit of the result.
+    ///
+    /// Indeces past the size of the resulting `Bitset` are ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset16::new(0b1000000000000100);
+    /// let indices = [Index16::from_usize(15), Index16::from_usize(0), Index16::from_usize(2)];
+    /// let gathered: Bitset8 = bitset.gather(&indices);
+    ///
+    /// assert_eq!(gathered.into_inner(), 0b00000101);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn gather<Res>(&self, indices: &[Index<Self>]) -> Res
+    where
+        Res: Bitset,
+    {
+        indices
+            .iter()
+            .take(bit_len::<Res>())
+            .enumerate()
+            .fold(&mut Res::NONE.clone(), |acc, (i, &index)| {
+                acc.replace(Index::<Res>::from_usize(i), self.bit(index))
+            })
+            .build()
+    }
+
+    /// Writes bits of `bits` to the [`indeces`][Index] of the `Bitset`, in order:
+    /// the least significant bit of `bits` is written to `indices[0]`.
+    /// Reverses [`Bitset::gather`]. Returns a mutable reference to `self`.
+    ///
+    /// Indeces past the size of `bits` are ignored.
     ///
     /// # Examples
     /// ```rust
@@ -459,60 +2719,502 @@ pub trait Bitset: Sized + Clone + PartialEq + Eq {
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// use bitworks::prelude::*;
     ///
-    /// let bitset = Bitset8::NONE.set(1.try_into()?).build();
+    /// let indices = [Index16::from_usize(15), Index16::from_usize(0), Index16::from_usize(2)];
+    /// let bitset = Bitset16::NONE
+    ///     .scatter(&Bitset8::new(0b00000101), &indices)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b1000000000000100);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn scatter<Src>(&mut self, bits: &Src, indices: &[Index<Self>]) -> &mut Self
+    where
+        Src: Bitset,
+    {
+        for (i, &index) in indices.iter().take(bit_len::<Src>()).enumerate() {
+            self.replace(index, bits.bit(Index::<Src>::from_usize(i)));
+        }
+        self
+    }
+
+    /// Swaps bits at [`indeces`][Index] `i` and `j`. Returns a mutable reference to `self`.
+    ///
+    /// Uses XOR delta-swap without branching, reading and writing bits in the same word only once.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b00000001)
+    ///     .swap_bits(0.try_into()?, 7.try_into()?)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b10000000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn swap_bits(&mut self, i: Index<Self>, j: Index<Self>) -> &mut Self {
+        let (i, j) = (i.into_inner(), j.into_inner());
+        let (w, i_shift, j_shift) = (i / 64, i % 64, j % 64);
+        if w == j / 64 {
+            let width = (bit_len::<Self>() - w * 64).min(64);
+            let word = self._bits(w * 64, width);
+            let delta = (word >> i_shift ^ word >> j_shift) & 1;
+            self._set_bits(w * 64, width, word ^ (delta << i_shift | delta << j_shift));
+        } else {
+            let delta = self._bits(i, 1) ^ self._bits(j, 1);
+            self._set_bits(i, 1, self._bits(i, 1) ^ delta);
+            self._set_bits(j, 1, self._bits(j, 1) ^ delta);
+        }
+        self
+    }
+
+    /// Swaps bits in range `a` with bits in range `b`, using delta-swaps of up to 64 bits at a time.
+    /// Returns a mutable reference to `self`.
+    ///
+    /// # Panics
+    /// Panics if ranges are out of bounds, differ in length or overlap.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let bitset = Bitset16::new(0b0000000000001011)
+    ///     .swap_ranges(0..4, 12..16)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b1011000000000000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn swap_ranges(&mut self, a: Range<usize>, b: Range<usize>) -> &mut Self {
+        assert!(
+            a.start <= a.end && b.start <= b.end,
+            "ranges must not be decreasing"
+        );
+        assert!(
+            a.end <= bit_len::<Self>() && b.end <= bit_len::<Self>(),
+            "ranges were out of range 0..(T::BYTE_SIZE * 8)"
+        );
+        assert_eq!(a.len(), b.len(), "ranges must have the same length");
+        assert!(
+            a.is_empty() || a.end <= b.start || b.end <= a.start,
+            "ranges must not overlap"
+        );
+
+        let mut k = 0;
+        while k < a.len() {
+            let width = (a.len() - k).min(64);
+            let x = self._bits(a.start + k, width);
+            let y = self._bits(b.start + k, width);
+            let delta = x ^ y;
+            self._set_bits(a.start + k, width, x ^ delta);
+            self._set_bits(b.start + k, width, y ^ delta);
+            k += width;
+        }
+        self
+    }
+
+    /// Copies bits in range `src` of `other` to `self`, starting from bit `dst_offset`.
+    /// `other` can be a `Bitset` of any size. Returns a mutable reference to `self`.
+    ///
+    /// Copies up to 64 bits at a time, regardless of how ranges are aligned on bytes.
+    ///
+    /// # Panics
+    /// Panics if `src` is out of bounds of `other`, or if copied bits don't fit into `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    ///
+    /// let other = Bitset8::new(0b00111100);
+    /// let bitset = Bitset16::NONE
+    ///     .copy_bits_from(&other, 2..6, 10)
+    ///     .build();
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b0011110000000000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn copy_bits_from<Src>(
+        &mut self,
+        other: &Src,
+        src: Range<usize>,
+        dst_offset: usize,
+    ) -> &mut Self
+    where
+        Src: Bitset,
+    {
+        assert!(src.start <= src.end, "range must not be decreasing");
+        assert!(
+            src.end <= bit_len::<Src>(),
+            "source range was out of range 0..(Src::BYTE_SIZE * 8)"
+        );
+        assert!(
+            dst_offset + src.len() <= bit_len::<Self>(),
+            "destination was out of range 0..(T::BYTE_SIZE * 8)"
+        );
+
+        let mut k = 0;
+        while k < src.len() {
+            let width = (src.len() - k).min(64);
+            self._set_bits(dst_offset + k, width, other._bits(src.start + k, width));
+            k += width;
+        }
+        self
+    }
+
+    /// Returns [`Some`] `Bitset`, with bits shifted towards the higher [`indeces`][Index] by amount,
+    /// or [`None`], if amount is not less than the size of the `Bitset` in bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10000011);
+    ///
+    /// assert_eq!(bitset.checked_shl(2), Some(Bitset8::new(0b00001100)));
+    /// assert_eq!(bitset.checked_shl(8), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn checked_shl(&self, amount: usize) -> Option<Self> {
+        (amount < bit_len::<Self>()).then(|| shifted_left(self, amount))
+    }
+
+    /// Returns [`Some`] `Bitset`, with bits shifted towards the lower [`indeces`][Index] by amount,
+    /// or [`None`], if amount is not less than the size of the `Bitset` in bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10000011);
+    ///
+    /// assert_eq!(bitset.checked_shr(2), Some(Bitset8::new(0b00100000)));
+    /// assert_eq!(bitset.checked_shr(8), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn checked_shr(&self, amount: usize) -> Option<Self> {
+        (amount < bit_len::<Self>()).then(|| shifted_right(self, amount))
+    }
+
+    /// Returns `Bitset`, with bits shifted towards the higher [`indeces`][Index] by amount,
+    /// taken modulo the size of the `Bitset` in bits, same as [`u8::wrapping_shl`].
+    /// `Bitset`s of zero size are returned unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10000011);
+    ///
+    /// assert_eq!(bitset.wrapping_shl(10), Bitset8::new(0b00001100));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn wrapping_shl(&self, amount: usize) -> Self {
+        shifted_left(self, amount.checked_rem(bit_len::<Self>()).unwrap_or(0))
+    }
+
+    /// Returns `Bitset`, with bits shifted towards the lower [`indeces`][Index] by amount,
+    /// taken modulo the size of the `Bitset` in bits, same as [`u8::wrapping_shr`].
+    /// `Bitset`s of zero size are returned unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10000011);
+    ///
+    /// assert_eq!(bitset.wrapping_shr(10), Bitset8::new(0b00100000));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn wrapping_shr(&self, amount: usize) -> Self {
+        shifted_right(self, amount.checked_rem(bit_len::<Self>()).unwrap_or(0))
+    }
+
+    /// Returns `Bitset`, with bits shifted towards the higher [`indeces`][Index] by amount.
+    /// Shifting by the size of the `Bitset` in bits or more shifts out all of the bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10000011);
+    ///
+    /// assert_eq!(bitset.saturating_shl(2), Bitset8::new(0b00001100));
+    /// assert_eq!(bitset.saturating_shl(100), Bitset8::NONE);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn saturating_shl(&self, amount: usize) -> Self {
+        shifted_left(self, amount.min(bit_len::<Self>()))
+    }
+
+    /// Returns `Bitset`, with bits shifted towards the lower [`indeces`][Index] by amount.
+    /// Shifting by the size of the `Bitset` in bits or more shifts out all of the bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b10000011);
+    ///
+    /// assert_eq!(bitset.saturating_shr(7), Bitset8::new(0b00000001));
+    /// assert_eq!(bitset.saturating_shr(100), Bitset8::NONE);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn saturating_shr(&self, amount: usize) -> Self {
+        shifted_right(self, amount.min(bit_len::<Self>()))
+    }
+
+    /// Returns sum of `self` and `other`, treated as unsigned integers,
+    /// along with [`bool`] indicating, whether the sum overflowed and was wrapped around.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(200);
+    /// let b = Bitset8::new(100);
+    ///
+    /// assert_eq!(a.overflowing_add(Bitset8::new(55)), (Bitset8::new(255), false));
+    /// assert_eq!(a.overflowing_add(b), (Bitset8::new(44), true));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let mut result = Self::NONE.clone();
+        let mut carry = false;
+        let mut k = 0;
+        while k < bit_len::<Self>() {
+            let width = (bit_len::<Self>() - k).min(64);
+            let (sum, c1) = self._bits(k, width).overflowing_add(other._bits(k, width));
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            carry = match width {
+                64 => c1 || c2,
+                _ => sum >> width != 0,
+            };
+            result._set_bits(k, width, sum & (!0 >> (64 - width)));
+            k += width;
+        }
+        (result, carry)
+    }
+
+    /// Returns difference of `self` and `other`, treated as unsigned integers,
+    /// along with [`bool`] indicating, whether the difference overflowed and was wrapped around.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let a = Bitset8::new(100);
+    ///
+    /// assert_eq!(a.overflowing_sub(Bitset8::new(1)), (Bitset8::new(99), false));
+    /// assert_eq!(a.overflowing_sub(Bitset8::new(101)), (Bitset8::new(255), true));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let mut result = Self::NONE.clone();
+        let mut borrow = false;
+        let mut k = 0;
+        while k < bit_len::<Self>() {
+            let width = (bit_len::<Self>() - k).min(64);
+            let (difference, b1) = self._bits(k, width).overflowing_sub(other._bits(k, width));
+            let (difference, b2) = difference.overflowing_sub(borrow as u64);
+            borrow = b1 || b2;
+            result._set_bits(k, width, difference & (!0 >> (64 - width)));
+            k += width;
+        }
+        (result, borrow)
+    }
+
+    /// Returns sum of `self` and `other`, treated as unsigned integers, wrapping around on overflow.
+    ///
+    /// Unlike [`|`][core::ops::BitOr], carries bits over, so the `Bitset` can be used as a counter.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let counter = Bitset8::new(0b11111111);
+    ///
+    /// assert_eq!(counter.wrapping_add(Bitset8::new(1)), Bitset8::NONE);
+    /// assert_eq!(Bitset8::new(0b0111).wrapping_add(Bitset8::new(1)), Bitset8::new(0b1000));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn wrapping_add(self, other: Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Returns difference of `self` and `other`, treated as unsigned integers, wrapping around on overflow.
+    ///
+    /// Unlike [`-`][core::ops::Sub], which is Set difference, borrows bits, so the `Bitset` can be used as a counter.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// assert_eq!(Bitset8::NONE.wrapping_sub(Bitset8::new(1)), Bitset8::ALL);
+    /// assert_eq!(Bitset8::new(0b1000).wrapping_sub(Bitset8::new(1)), Bitset8::new(0b0111));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn wrapping_sub(self, other: Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    /// Rotates bits of the `Bitset` towards the higher [`indeces`][Index] by amount,
+    /// wrapping the bits shifted past the end around to the beginning.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// assert_eq!(*bitset.bit_ref(0.try_into()?), Zero);
-    /// assert_eq!(*bitset.bit_ref(1.try_into()?), One);
+    /// let bitset = Bitset8::new(0b11000001).rotate_left(2);
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b00000111);
     /// #   Ok(())
     /// # }
     /// ```
-    fn bit_ref(&self, index: Index<Self>) -> BitRef<'_, Self>;
+    fn rotate_left(self, amount: usize) -> Self {
+        if bit_len::<Self>() == 0 {
+            return self;
+        }
+        let amount = amount % bit_len::<Self>();
+        self.ones()
+            .map(|Index(i, ..)| Index::<Self>::from_usize((i + amount) % bit_len::<Self>()))
+            .fold(&mut Self::NONE.clone(), |acc, i| acc.set(i))
+            .build()
+    }
 
-    /// Returns a [`BitMut`] holding a mutable reference to the bit at [`index`][Index].
+    /// Rotates bits of the `Bitset` towards the lower [`indeces`][Index] by amount,
+    /// wrapping the bits shifted past the beginning around to the end.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::*;
+    /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let mut bitset = Bitset8::NONE;
+    /// let bitset = Bitset8::new(0b00000111).rotate_right(2);
     ///
-    /// assert_eq!(bitset.bit(0.try_into()?), Zero);
-    /// assert_eq!(bitset.bit(1.try_into()?), Zero);
+    /// assert_eq!(bitset.into_inner(), 0b11000001);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn rotate_right(self, amount: usize) -> Self {
+        if bit_len::<Self>() == 0 {
+            return self;
+        }
+        let amount = amount % bit_len::<Self>();
+        self.rotate_left(bit_len::<Self>() - amount)
+    }
+
+    /// Rotates bits of the `Bitset` towards the higher [`indeces`][Index] by [`Index`] `amount`.
     ///
-    /// *bitset.bit_mut(0.try_into()?) = One;
+    /// Same as [`Bitset::rotate_left`], but `amount` is already known to be less than the size of the `Bitset`.
     ///
-    /// assert_eq!(bitset.bit(0.try_into()?), One);
-    /// assert_eq!(bitset.bit(1.try_into()?), Zero);
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8, Index8};
+    ///
+    /// let bitset = Bitset8::new(0b11000001).rotate_left_by_index(Index8::from_usize(2));
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b00000111);
     /// #   Ok(())
     /// # }
     /// ```
-    fn bit_mut(&mut self, index: Index<Self>) -> BitMut<'_, Self>;
+    #[inline(always)]
+    fn rotate_left_by_index(self, amount: Index<Self>) -> Self {
+        self.rotate_left(amount.into_inner())
+    }
 
-    /// Returns Set complement (`self′`) of `Bitset`.<br/>
-    /// Alias for [`!`][core::ops::Not] operator.
+    /// Rotates bits of the `Bitset` towards the lower [`indeces`][Index] by [`Index`] `amount`.
+    ///
+    /// Same as [`Bitset::rotate_right`], but `amount` is already known to be less than the size of the `Bitset`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
+    /// use bitworks::prelude::{Bitset, Bitset8, Index8};
     ///
-    /// let a = Bitset8::new(0b11110000);
-    /// let b = a.complement();
+    /// let bitset = Bitset8::new(0b00000111).rotate_right_by_index(Index8::from_usize(2));
     ///
-    /// assert_eq!(a.into_inner(), 0b11110000);
-    /// assert_eq!(b.into_inner(), 0b00001111);
+    /// assert_eq!(bitset.into_inner(), 0b11000001);
     /// #   Ok(())
     /// # }
     /// ```
-    fn complement(self) -> Self;
+    #[inline(always)]
+    fn rotate_right_by_index(self, amount: Index<Self>) -> Self {
+        self.rotate_right(amount.into_inner())
+    }
 
-    /// Returns Set union (`self ∪ other`) of two `Bitset`s.<br/>
-    /// Alias for [`|`][core::ops::BitOr] operator.
+    /// Reverses the order of bits of the `Bitset`:
+    /// the least significant bit becomes the most significant one and vice versa.
     ///
     /// # Examples
     /// ```rust
@@ -521,79 +3223,110 @@ pub trait Bitset: Sized + Clone + PartialEq + Eq {
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let a = Bitset8::new(0b11001100);
-    /// let b = Bitset8::new(0b11110000);
-    /// let c = a.union(b);
+    /// let bitset = Bitset8::new(0b11000010).reverse();
     ///
-    /// assert_eq!(c.into_inner(), 0b11111100);
+    /// assert_eq!(bitset.into_inner(), 0b01000011);
     /// #   Ok(())
     /// # }
     /// ```
-    fn union(self, other: Self) -> Self;
+    fn reverse(self) -> Self {
+        self.ones()
+            .map(|Index(i, ..)| Index::<Self>::from_usize(bit_len::<Self>() - 1 - i))
+            .fold(&mut Self::NONE.clone(), |acc, i| acc.set(i))
+            .build()
+    }
 
-    /// Returns Set intersection (`self ∩ other`) of two `Bitset`s.<br/>
-    /// Alias for [`&`][core::ops::BitAnd] operator.
+    /// Returns iterator over bits of the `Bitset` in [`Bit`] representation.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
+    /// use bitworks::prelude::*;
     ///
-    /// let a = Bitset8::new(0b11001100);
-    /// let b = Bitset8::new(0b11110000);
-    /// let c = a.intersection(b);
+    /// let bitset = Bitset8::new(0b01010100);
+    /// let mut iter = bitset.bits();
     ///
-    /// assert_eq!(c.into_inner(), 0b11000000);
+    /// assert_eq!(iter.next(), Some(Zero)); // 0
+    /// assert_eq!(iter.next(), Some(Zero)); // 0
+    /// assert_eq!(iter.next(), Some(One));  // 1
+    /// assert_eq!(iter.next(), Some(Zero)); // 0
+    /// assert_eq!(iter.next(), Some(One));  // 1
+    /// assert_eq!(iter.next(), Some(Zero)); // 0
+    /// assert_eq!(iter.next(), Some(One));  // 1
+    /// assert_eq!(iter.next(), Some(Zero)); // 0
+    /// assert_eq!(iter.next(), None);
     /// #   Ok(())
     /// # }
     /// ```
-    fn intersection(self, other: Self) -> Self;
+    #[inline(always)]
+    fn bits(self) -> Bits<Self> {
+        Bits::new(self)
+    }
 
-    /// Returns Set difference (`self \ other`) of two `Bitset`s.
+    /// Returns iterator over bits of the `Bitset` in [`Bit`] representation,
+    /// paired with their [`Index`].
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
+    /// use bitworks::prelude::*;
     ///
-    /// let a = Bitset8::new(0b11001100);
-    /// let b = Bitset8::new(0b11110000);
-    /// let c = a.difference(b);
+    /// let bitset = Bitset8::new(0b01010100);
+    /// let mut iter = bitset.indexed_bits().skip(2);
     ///
-    /// assert_eq!(c.into_inner(), 0b00001100);
+    /// assert_eq!(iter.next(), Some((2.try_into()?, One)));
+    /// assert_eq!(iter.next(), Some((3.try_into()?, Zero)));
+    /// assert_eq!(iter.next(), Some((4.try_into()?, One)));
+    /// assert_eq!(iter.len(), 3);
     /// #   Ok(())
     /// # }
     /// ```
     #[inline(always)]
-    fn difference(self, other: Self) -> Self {
-        self.intersection(other.complement())
+    fn indexed_bits(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Index<Self>, Bit)> + ExactSizeIterator + FusedIterator
+    {
+        (0..bit_len::<Self>())
+            .map(|i| Index::<Self>::from_usize(i))
+            .map(|i| (i, self.bit(i)))
     }
 
-    /// Returns Set symmetric difference (`self Δ other`) of two `Bitset`s.<br/>
-    /// Alias for [`^`][core::ops::BitXor] operator.
+    /// Returns iterator over [`BitRef`] holding immutable references
+    /// to bits of the `Bitset`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
+    /// use bitworks::prelude::*;
     ///
-    /// let a = Bitset8::new(0b11001100);
-    /// let b = Bitset8::new(0b11110000);
-    /// let c = a.sym_difference(b);
+    /// let bitset = Bitset8::new(0b01010100);
+    /// let mut iter = bitset.bits_ref();
     ///
-    /// assert_eq!(c.into_inner(), 0b00111100);
+    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
+    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
+    /// assert_eq!(iter.next().as_deref(), Some(&One));  // 1
+    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
+    /// assert_eq!(iter.next().as_deref(), Some(&One));  // 1
+    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
+    /// assert_eq!(iter.next().as_deref(), Some(&One));  // 1
+    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
+    /// assert_eq!(iter.next(), None);
     /// #   Ok(())
     /// # }
     /// ```
-    fn sym_difference(self, other: Self) -> Self;
+    #[inline(always)]
+    fn bits_ref(&self) -> BitsRef<'_, Self> {
+        BitsRef::new(self)
+    }
 
-    /// Returns [`true`], if `self` contains all of the set bits from `other` and [`false`] otherwise.
+    /// Returns iterator over [`BitMut`] holding mutable references
+    /// to bits of the `Bitset`.
     ///
     /// # Examples
     /// ```rust
@@ -602,26 +3335,23 @@ pub trait Bitset: Sized + Clone + PartialEq + Eq {
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let a = Bitset8::new(0b11111100);
-    /// let b = Bitset8::new(0b11110000);
-    /// let c = Bitset8::new(0b00001111);
+    /// let mut bitset = Bitset8::new(0b01010101);
+    /// let mut iter = bitset.bits_mut();
     ///
-    /// assert!(a.includes(&b));
-    /// assert!(!a.includes(&c));
+    /// for mut bit in iter {
+    ///     *bit = !*bit;
+    /// }
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b10101010);
     /// #   Ok(())
     /// # }
     /// ```
     #[inline(always)]
-    fn includes(&self, other: &Self) -> bool {
-        for i in other.ones() {
-            if !bool::from(self.bit(i)) {
-                return false;
-            }
-        }
-        true
+    fn bits_mut(&mut self) -> BitsMut<'_, Self> {
+        BitsMut::new(self)
     }
 
-    /// Returns [`true`], if `self` shares any set bits with `other` and [`false`] otherwise.
+    /// Returns iterator over [`indeces`][Index] of the set bits of the `Bitset`.
     ///
     /// # Examples
     /// ```rust
@@ -630,366 +3360,293 @@ pub trait Bitset: Sized + Clone + PartialEq + Eq {
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let a = Bitset8::new(0b11111100);
-    /// let b = Bitset8::new(0b00001111);
-    /// let c = Bitset8::new(0b00000011);
+    /// let bitset = Bitset8::new(0b01010100);
+    /// let mut iter = bitset.ones();
     ///
-    /// assert!(a.intersects(&b));
-    /// assert!(!a.intersects(&c));
+    /// assert_eq!(iter.next(), Some(2.try_into()?));
+    /// assert_eq!(iter.next(), Some(4.try_into()?));
+    /// assert_eq!(iter.next(), Some(6.try_into()?));
+    /// assert_eq!(iter.next(), None);
     /// #   Ok(())
     /// # }
     /// ```
     #[inline(always)]
-    fn intersects(&self, other: &Self) -> bool {
-        for i in other.ones() {
-            if bool::from(self.bit(i)) {
-                return true;
-            }
-        }
-        false
+    fn ones(&self) -> Ones<'_, Self> {
+        Ones::new(self)
     }
 
-    /// Combines two `Bitset`s to create a bigger one.<br/>
-    /// If available, you should prefer using [`Bitset::combine_optimized`].
+    /// Returns iterator over [`indeces`][Index] of the not set bits of the `Bitset`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let bitset8_1 = Bitset8::new(0b00000001);
-    /// let bitset8_2 = Bitset8::new(0b00000011);
-    /// let bitset16: Bitset16 = bitset8_1.combine(bitset8_2);
+    /// let bitset = Bitset8::new(0b01010100);
+    /// let mut iter = bitset.zeros();
     ///
-    /// assert_eq!(bitset16.into_inner(), 0b0000001100000001);
+    /// assert_eq!(iter.next(), Some(0.try_into()?));
+    /// assert_eq!(iter.next(), Some(1.try_into()?));
+    /// assert_eq!(iter.next(), Some(3.try_into()?));
+    /// assert_eq!(iter.next(), Some(5.try_into()?));
+    /// assert_eq!(iter.next(), Some(7.try_into()?));
+    /// assert_eq!(iter.next(), None);
     /// #   Ok(())
     /// # }
     /// ```
-    fn combine<Other, Res>(self, other: Other) -> Res
-    where
-        Other: Bitset,
-        Res: Bitset,
-        Self::Size: Combines<Other::Size, Res::Size> + Smaller<Res::Size>,
-        Other::Size: Smaller<Res::Size>,
-    {
-        let mut result = self
-            .ones()
-            .map(|Index(i, ..)| Index::<Res>::from_usize(i))
-            .fold(&mut Res::NONE.clone(), |acc, i| acc.set(i))
-            .build();
-
-        let result = other
-            .ones()
-            .map(|Index(i, ..)| Index::<Res>::from_usize(i + bit_len::<Self>()))
-            .fold(&mut result, |acc, i| acc.set(i))
-            .build();
-        result
+    #[inline(always)]
+    fn zeros(&self) -> Zeros<'_, Self> {
+        Zeros::new(self)
     }
 
-    /// Combines two `Bitset`s to create a bigger one. Uses `unsafe` optimizations.
+    /// Returns [`BitSliceRef`] view over bits of the `Bitset` in `range`.
+    ///
+    /// `range` is clamped to the size of the `Bitset`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    /// use bitworks::prelude::*;
     ///
-    /// let bitset8_1 = Bitset8::new(0b00000001);
-    /// let bitset8_2 = Bitset8::new(0b00000011);
-    /// let bitset16: Bitset16 = bitset8_1.combine_optimized(bitset8_2);
+    /// let bitset = Bitset16::new(0b0000_0101_0000_0001);
+    /// let high = bitset.slice(8..16);
     ///
-    /// assert_eq!(bitset16.into_inner(), 0b0000001100000001);
+    /// assert_eq!(high.count_ones(), 2);
+    /// assert_eq!(high.to_string(), "00000101");
     /// #   Ok(())
     /// # }
     /// ```
-    fn combine_optimized<Other, Res>(self, other: Other) -> Res
+    #[inline(always)]
+    fn slice<R>(&self, range: R) -> BitSliceRef<'_, Self>
     where
-        Self: LeftAligned,
-        Other: Bitset + LeftAligned,
-        Res: Bitset + LeftAligned,
-        Self::Size: Combines<Other::Size, Res::Size> + Smaller<Res::Size>,
-        Other::Size: Smaller<Res::Size>,
+        R: RangeBounds<usize>,
     {
-        let mut result = Res::NONE.clone();
-
-        unsafe {
-            std::ptr::copy_nonoverlapping(
-                &self as *const _ as *const u8,
-                &mut result as *mut _ as *mut u8,
-                Self::BYTE_SIZE,
-            );
-
-            std::ptr::copy_nonoverlapping(
-                &other as *const _ as *const u8,
-                (&mut result as *mut _ as *mut u8).add(Self::BYTE_SIZE),
-                Other::BYTE_SIZE,
-            );
-        }
-        result
+        BitSliceRef::new(self, range)
     }
 
-    /// Splits `Bitset` into two smaller ones.<br/>
-    /// If available, you should prefer using [`Bitset::split_optimized`].
+    /// Returns [`BitSliceMut`] mutable view over bits of the `Bitset` in `range`.
+    ///
+    /// `range` is clamped to the size of the `Bitset`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    /// use bitworks::prelude::*;
     ///
-    /// let bitset16 = Bitset16::from(0b0000001100000001);
-    /// let (bitset8_1, bitset8_2): (Bitset8, Bitset8) = bitset16.split();
+    /// let mut bitset = Bitset16::NONE;
+    /// bitset.slice_mut(8..12).fill(One);
     ///
-    /// assert_eq!(bitset8_1.into_inner(), 0b00000001);
-    /// assert_eq!(bitset8_2.into_inner(), 0b00000011);
+    /// assert_eq!(bitset.into_inner(), 0b0000_1111_0000_0000);
     /// #   Ok(())
     /// # }
     /// ```
-    fn split<Res1, Res2>(self) -> (Res1, Res2)
+    #[inline(always)]
+    fn slice_mut<R>(&mut self, range: R) -> BitSliceMut<'_, Self>
     where
-        Res1: Bitset,
-        Res2: Bitset,
-        Self::Size: Splits<Res1::Size, Res2::Size>,
-        Res1::Size: Smaller<Self::Size>,
-        Res2::Size: Smaller<Self::Size>,
+        R: RangeBounds<usize>,
     {
-        let result1 = self
-            .bits_ref()
-            .take(bit_len::<Res1>())
-            .enumerate()
-            .map(|(i, bit)| (Index::<Res1>::from_usize(i), bit))
-            .fold(&mut Res1::NONE.clone(), |acc, (i, bit)| {
-                acc.replace(i, *bit)
-            })
-            .build();
+        BitSliceMut::new(self, range)
+    }
 
-        let result2 = self
-            .bits_ref()
-            .skip(bit_len::<Res1>())
-            .enumerate()
-            .map(|(i, bit)| (Index::<Res2>::from_usize(i), bit))
-            .fold(&mut Res2::NONE.clone(), |acc, (i, bit)| {
-                acc.replace(i, *bit)
-            })
-            .build();
+    /// Returns iterator over set bits of the `Bitset` as maximal ranges of consecutive set bits,
+    /// in ascending order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let bitset = Bitset16::new(0b1000111100001110);
+    /// let ranges: Vec<_> = bitset.to_ranges().collect();
+    ///
+    /// assert_eq!(ranges, [1..4, 8..12, 15..16]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn to_ranges(&self) -> impl DoubleEndedIterator<Item = Range<usize>> {
+        Ranges::new(self)
+    }
 
-        (result1, result2)
+    /// Returns iterator over [`indeces`][Index] of the set bits of the `Bitset`, unsetting each of them.
+    ///
+    /// Dropping the iterator resets the whole `Bitset` to [`Bitset::NONE`], even if it was not fully consumed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let mut bitset = Bitset8::new(0b01010100);
+    /// let drained: Vec<_> = bitset.drain_ones().map(|i| i.into_inner()).collect();
+    ///
+    /// assert_eq!(drained, [2, 4, 6]);
+    /// assert!(bitset.is_empty());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn drain_ones(&mut self) -> DrainOnes<'_, Self> {
+        DrainOnes::new(self)
     }
 
-    /// Splits `Bitset` into two smaller ones. Uses `unsafe` optimizations.
+    /// Returns iterator over all `2^n` subsets of the `Bitset` with `n` set bits,
+    /// in ascending order, starting from the empty one.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8, Bitset16};
+    /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let bitset16 = Bitset16::from(0b0000001100000001);
-    /// let (bitset8_1, bitset8_2): (Bitset8, Bitset8) = bitset16.split_optimized();
+    /// let mask = Bitset8::new(0b10010);
+    /// let subsets: Vec<_> = mask.subsets().map(|s| s.into_inner()).collect();
     ///
-    /// assert_eq!(bitset8_1.into_inner(), 0b00000001);
-    /// assert_eq!(bitset8_2.into_inner(), 0b00000011);
+    /// assert_eq!(subsets, [0b00000, 0b00010, 0b10000, 0b10010]);
     /// #   Ok(())
     /// # }
     /// ```
-    fn split_optimized<Res1, Res2>(self) -> (Res1, Res2)
-    where
-        Self: LeftAligned,
-        Res1: Bitset + LeftAligned,
-        Res2: Bitset + LeftAligned,
-        Self::Size: Splits<Res1::Size, Res2::Size>,
-        Res1::Size: Smaller<Self::Size>,
-        Res2::Size: Smaller<Self::Size>,
-    {
-        let mut result1 = Res1::NONE.clone();
-        let mut result2 = Res2::NONE.clone();
-
-        unsafe {
-            std::ptr::copy_nonoverlapping(
-                &self as *const _ as *const u8,
-                &mut result1 as *mut _ as *mut u8,
-                Res1::BYTE_SIZE,
-            );
-
-            std::ptr::copy_nonoverlapping(
-                (&self as *const _ as *const u8).add(Res1::BYTE_SIZE),
-                &mut result2 as *mut _ as *mut u8,
-                Res2::BYTE_SIZE,
-            );
-        }
-        (result1, result2)
+    #[inline(always)]
+    fn subsets(&self) -> Subsets<Self> {
+        Subsets::new(self.clone())
     }
 
-    /// Returns iterator over bits of the `Bitset` in [`Bit`] representation.
+    /// Returns iterator over every value of the `Bitset`, in ascending order, starting from [`Bitset::NONE`].
+    ///
+    /// Same as subsets of [`Bitset::ALL`]. Values are generated lazily, so the iterator can be
+    /// constructed for any `Bitset`, but iterating over all `2^n` values of `Bitset` with `n` bits
+    /// is only practical for small ones, like [`Bitset8`][crate::prelude::Bitset8] and
+    /// [`Bitset16`][crate::prelude::Bitset16].
+    ///
+    /// Meant for exhaustive testing of functions over all combinations of flags.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::*;
+    /// use bitworks::prelude::{Bitset, Bitset8, Byteset};
     ///
-    /// let bitset = Bitset8::new(0b01010100);
-    /// let mut iter = bitset.bits();
+    /// assert_eq!(Bitset8::all_values().count(), 256);
+    /// assert!(Bitset8::all_values().all(|value| !!value == value));
     ///
-    /// assert_eq!(iter.next(), Some(Zero)); // 0
-    /// assert_eq!(iter.next(), Some(Zero)); // 0
-    /// assert_eq!(iter.next(), Some(One));  // 1
-    /// assert_eq!(iter.next(), Some(Zero)); // 0
-    /// assert_eq!(iter.next(), Some(One));  // 1
-    /// assert_eq!(iter.next(), Some(Zero)); // 0
-    /// assert_eq!(iter.next(), Some(One));  // 1
-    /// assert_eq!(iter.next(), Some(Zero)); // 0
-    /// assert_eq!(iter.next(), None);
+    /// let mut values = Byteset::<64>::all_values();
+    /// assert_eq!(values.nth(3), Some(Byteset::from_indices([0, 1])));
     /// #   Ok(())
     /// # }
     /// ```
     #[inline(always)]
-    fn bits(self) -> impl Iterator<Item = Bit> + DoubleEndedIterator {
-        (0..bit_len::<Self>())
-            .map(|i| Index::<Self>::from_usize(i))
-            .map(move |i| self.bit(i))
+    fn all_values() -> Subsets<Self> {
+        Subsets::new(Self::ALL.clone())
     }
 
-    /// Returns iterator over [`BitRef`] holding immutable references
-    /// to bits of the `Bitset`.
+    /// Returns iterator over all subsets of the `Bitset` with exactly `k` set bits, in ascending order.
+    ///
+    /// The iterator's [`count`][Iterator::count] is computed with binomial coefficients, without iterating.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::*;
+    /// use bitworks::prelude::{Bitset, Bitset8};
     ///
-    /// let bitset = Bitset8::new(0b01010100);
-    /// let mut iter = bitset.bits_ref();
+    /// let mask = Bitset8::new(0b10110);
+    /// let pairs: Vec<_> = mask.combinations(2).map(|s| s.into_inner()).collect();
     ///
-    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
-    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
-    /// assert_eq!(iter.next().as_deref(), Some(&One));  // 1
-    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
-    /// assert_eq!(iter.next().as_deref(), Some(&One));  // 1
-    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
-    /// assert_eq!(iter.next().as_deref(), Some(&One));  // 1
-    /// assert_eq!(iter.next().as_deref(), Some(&Zero)); // 0
-    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(pairs, [0b00110, 0b10010, 0b10100]);
+    /// assert_eq!(Bitset8::ALL.combinations(3).count(), 56);
     /// #   Ok(())
     /// # }
     /// ```
     #[inline(always)]
-    fn bits_ref(&self) -> impl Iterator<Item = BitRef<'_, Self>> + DoubleEndedIterator {
-        (0..bit_len::<Self>())
-            .map(|i| Index::<Self>::from_usize(i))
-            .map(|i| self.bit_ref(i))
+    fn combinations(&self, k: usize) -> Combinations<Self> {
+        Combinations::new(self.clone(), k)
     }
 
-    /// Returns iterator over [`BitMut`] holding mutable references
-    /// to bits of the `Bitset`.
+    /// Returns [`BitCursor`] over the bits of the `Bitset`, at position `0`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
-    ///
-    /// let mut bitset = Bitset8::new(0b01010101);
-    /// let mut iter = bitset.bits_mut();
+    /// use bitworks::prelude::*;
     ///
-    /// for mut bit in iter {
-    ///     *bit = !*bit;
-    /// }
+    /// let bitset = Bitset8::new(0b00010000);
+    /// let mut cursor = bitset.cursor();
     ///
-    /// assert_eq!(bitset.into_inner(), 0b10101010);
+    /// assert_eq!(cursor.next_one(), Some(4.try_into()?));
+    /// assert_eq!(cursor.position(), 5);
     /// #   Ok(())
     /// # }
     /// ```
     #[inline(always)]
-    fn bits_mut(&mut self) -> impl Iterator<Item = BitMut<'_, Self>> + DoubleEndedIterator {
-        let p = self as *mut Self;
-        (0..bit_len::<Self>())
-            .map(|i| Index::<Self>::from_usize(i))
-            .map(move |i| unsafe { p.as_mut().unwrap().bit_mut(i) })
+    fn cursor(&self) -> BitCursor<'_, Self> {
+        BitCursor::new(self)
     }
 
-    /// Returns iterator over [`indeces`][Index] of the set bits of the `Bitset`.
+    /// Returns [`BitCursorMut`] for writing the bits of the `Bitset`, at position `0`.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
+    /// use bitworks::prelude::*;
     ///
-    /// let bitset = Bitset8::new(0b01010100);
-    /// let mut iter = bitset.ones();
+    /// let mut bitset = Bitset8::NONE;
+    /// bitset.cursor_mut().write(3, 0b101u8)?.write_bool(true)?;
     ///
-    /// assert_eq!(iter.next(), Some(2.try_into()?));
-    /// assert_eq!(iter.next(), Some(4.try_into()?));
-    /// assert_eq!(iter.next(), Some(6.try_into()?));
-    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(bitset.into_inner(), 0b00001101);
     /// #   Ok(())
     /// # }
     /// ```
     #[inline(always)]
-    fn ones(&self) -> impl Iterator<Item = Index<Self>> + DoubleEndedIterator {
-        self.bits_ref().filter_map(|bit| {
-            if bool::from(*bit) {
-                Some(BitRef::index(&bit))
-            } else {
-                None
-            }
-        })
+    fn cursor_mut(&mut self) -> BitCursorMut<'_, Self> {
+        BitCursorMut::new(self)
     }
 
-    /// Returns iterator over [`indeces`][Index] of the not set bits of the `Bitset`.
+    /// Returns [`BitsetDisplay`] adapter for formatting the `Bitset`
+    /// with configurable grouping, bit order and separators.
     ///
     /// # Examples
     /// ```rust
     /// # use std::error::Error;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use bitworks::prelude::{Bitset, Bitset8};
+    /// use bitworks::prelude::*;
     ///
-    /// let bitset = Bitset8::new(0b01010100);
-    /// let mut iter = bitset.zeros();
+    /// let bitset = Bitset8::new(0b11110000);
     ///
-    /// assert_eq!(iter.next(), Some(0.try_into()?));
-    /// assert_eq!(iter.next(), Some(1.try_into()?));
-    /// assert_eq!(iter.next(), Some(3.try_into()?));
-    /// assert_eq!(iter.next(), Some(5.try_into()?));
-    /// assert_eq!(iter.next(), Some(7.try_into()?));
-    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(bitset.display().group_bits(4).separator('_').to_string(), "1111_0000");
+    /// assert_eq!(bitset.display().msb_first(false).to_string(), "00001111");
     /// #   Ok(())
     /// # }
     /// ```
     #[inline(always)]
-    fn zeros(&self) -> impl Iterator<Item = Index<Self>> + DoubleEndedIterator {
-        self.bits_ref().filter_map(|bit| {
-            if bool::from(!*bit) {
-                Some(BitRef::index(&bit))
-            } else {
-                None
-            }
-        })
+    fn display(&self) -> BitsetDisplay<'_, Self> {
+        BitsetDisplay::new(self)
     }
 }
 
 /// Left-aligned [`Bitset`].
 ///
-/// Implementors of this trait get blanket implementation of `Bitset`.
-/// They also get access to these methods defined on `Bitset`:
-/// * [`Bitset::expand_optimized()`]
-/// * [`Bitset::combine_optimized()`]
-/// * [`Bitset::split_optimized()`]
-///
-/// All the methods above have corresponding versions without `_optimized` suffix, which contains no `unsafe` code
-/// and aren't restricted to only `LeftAligned` types.
+/// Implementors of this trait get blanket implementation of `Bitset`,
+/// which accesses the inner representation of the bitset directly as bytes.
+/// This makes methods like [`Bitset::expand()`], [`Bitset::combine()`] and [`Bitset::split()`]
+/// as fast as copying memory.
 ///
 /// # Safety
 /// If you implement this trait, you are responsible for making sure, that part in memory of the implementor,
@@ -1138,55 +3795,97 @@ pub unsafe trait LeftAligned: Bitset + Sized + Clone + PartialEq + Eq {
     /// Shifts bit representation of the `Bitset` left by amount.
     /// Has signature and use identical to [`core::ops::Shl<Index<Self>>`][core::ops::Shl].
     fn shift_left(mut self, amount: Index<Self>) -> Self {
-        let byte_shift = amount.byte_index();
-        let bit_shift = amount.bit_index();
-
-        let ptr = &mut self as *mut _ as *mut u8;
-
-        if byte_shift > 0 {
-            unsafe {
-                std::ptr::copy(ptr.add(byte_shift), ptr, Self::BYTE_SIZE - byte_shift);
-                std::ptr::write_bytes(ptr.add(Self::BYTE_SIZE - byte_shift), 0, byte_shift);
-            }
-        }
-
-        if bit_shift > 0 {
-            let bytes: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(ptr, Self::BYTE_SIZE) };
-            let mut carry = 0;
-            for byte in bytes.iter_mut().rev() {
-                let shifted = *byte << bit_shift | carry;
-                carry = *byte >> (8 - bit_shift);
-                *byte = shifted;
-            }
-        }
+        let bytes: &mut [u8] = unsafe {
+            std::slice::from_raw_parts_mut(&mut self as *mut _ as *mut u8, Self::BYTE_SIZE)
+        };
+        shift_bytes_left(bytes, amount.into_inner());
         self
     }
 
     /// Shifts bit representation of the `Bitset` right by amount.
     /// Has signature and use identical to [`core::ops::Shr<Index<Self>>`][core::ops::Shr].
     fn shift_right(mut self, amount: Index<Self>) -> Self {
-        let byte_shift = amount.byte_index();
-        let bit_shift = amount.bit_index();
+        let bytes: &mut [u8] = unsafe {
+            std::slice::from_raw_parts_mut(&mut self as *mut _ as *mut u8, Self::BYTE_SIZE)
+        };
+        shift_bytes_right(bytes, amount.into_inner());
+        self
+    }
 
-        let ptr = &mut self as *mut _ as *mut u8;
+    /// Returns bytes of the `Bitset` part of the value, without copying.
+    ///
+    /// Byte `i` contains bits at [`Index`]es `8 * i..8 * i + 8`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitset::LeftAligned, prelude::*};
+    ///
+    /// let bitset = Bitset16::new(0b00000001_10000000);
+    ///
+    /// assert_eq!(bitset.as_bytes(), &[0b10000000, 0b00000001]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, Self::BYTE_SIZE) }
+    }
 
-        if byte_shift > 0 {
-            unsafe {
-                std::ptr::copy(ptr, ptr.add(byte_shift), Self::BYTE_SIZE - byte_shift);
-                std::ptr::write_bytes(ptr, 0, byte_shift);
-            }
-        }
+    /// Returns mutable bytes of the `Bitset` part of the value, without copying.
+    ///
+    /// Byte `i` contains bits at [`Index`]es `8 * i..8 * i + 8`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitset::LeftAligned, prelude::*};
+    ///
+    /// let mut bitset = Bitset16::NONE;
+    /// bitset.as_bytes_mut().copy_from_slice(&[0b10000000, 0b00000001]);
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b00000001_10000000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self as *mut _ as *mut u8, Self::BYTE_SIZE) }
+    }
 
-        if bit_shift > 0 {
-            let bytes: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(ptr, Self::BYTE_SIZE) };
-            let mut carry = 0;
-            for byte in bytes.iter_mut() {
-                let shifted = *byte >> bit_shift | carry;
-                carry = *byte << (8 - bit_shift);
-                *byte = shifted;
-            }
+    /// Constructs a new value of the `Bitset` from `bytes`, in the same order as [`LeftAligned::as_bytes`].
+    ///
+    /// # Errors
+    /// [`ConvError`], if length of `bytes` isn't equal to [`Bitset::BYTE_SIZE`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{bitset::LeftAligned, prelude::*};
+    ///
+    /// let bitset = Bitset16::from_bytes(&[0b10000000, 0b00000001])?;
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b00000001_10000000);
+    /// assert!(Bitset16::from_bytes(&[0]).is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn from_bytes(bytes: &[u8]) -> ConvResult<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(ConvError::new(
+                ConvTarget::Set(bytes.len() * 8),
+                ConvTarget::Set(Self::BYTE_SIZE * 8),
+            ));
         }
-        self
+        let mut result = Self::_NONE;
+        result.as_bytes_mut().copy_from_slice(bytes);
+        Ok(result)
     }
 }
 
@@ -1215,15 +3914,24 @@ where
         let bytes: &[u8] =
             unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, Self::BYTE_SIZE) };
 
-        bytes.iter().fold(0, |acc, &byte| acc + byte.count_ones()) as usize
+        dispatch::count_ones(bytes)
     }
 
     #[inline(always)]
     fn count_zeros(&self) -> usize {
+        bit_len::<Self>() - self.count_ones()
+    }
+
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
         let bytes: &[u8] =
             unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, Self::BYTE_SIZE) };
 
-        bytes.iter().fold(0, |acc, &byte| acc + byte.count_zeros()) as usize
+        let chunks = bytes.chunks_exact(8);
+        chunks.remainder().iter().all(|&byte| byte == 0)
+            && chunks
+                .into_iter()
+                .all(|chunk| u64::from_ne_bytes(chunk.try_into().unwrap()) == 0)
     }
 
     #[inline(always)]
@@ -1263,9 +3971,7 @@ where
         let other_bytes: &[u8] =
             unsafe { std::slice::from_raw_parts(&other as *const _ as *const u8, Self::BYTE_SIZE) };
 
-        for i in 0..Self::BYTE_SIZE {
-            self_bytes[i] |= other_bytes[i];
-        }
+        dispatch::or(self_bytes, other_bytes);
         self
     }
 
@@ -1276,9 +3982,7 @@ where
         let other_bytes: &[u8] =
             unsafe { std::slice::from_raw_parts(&other as *const _ as *const u8, Self::BYTE_SIZE) };
 
-        for i in 0..Self::BYTE_SIZE {
-            self_bytes[i] &= !other_bytes[i];
-        }
+        dispatch::and_not(self_bytes, other_bytes);
         self
     }
 
@@ -1289,6 +3993,26 @@ where
         Bit::from(byte & index.bitmask() != 0)
     }
 
+    #[inline(always)]
+    fn _byte(&self, index: usize) -> u8 {
+        self.as_bytes()[index]
+    }
+
+    #[inline(always)]
+    fn _set_byte(&mut self, index: usize, byte: u8) {
+        self.as_bytes_mut()[index] = byte;
+    }
+
+    #[inline(always)]
+    fn _word(&self, index: usize) -> u64 {
+        let bytes = self.as_bytes();
+        let start = (index * 8).min(Self::BYTE_SIZE);
+        let end = (start + 8).min(Self::BYTE_SIZE);
+        let mut word = [0; 8];
+        word[..end - start].copy_from_slice(&bytes[start..end]);
+        u64::from_le_bytes(word)
+    }
+
     #[inline(always)]
     fn bit_ref(&self, index: Index<Self>) -> BitRef<'_, Self> {
         let self_ptr = self as *const _ as *const u8;
@@ -1323,9 +4047,7 @@ where
         let other_bytes: &[u8] =
             unsafe { std::slice::from_raw_parts(&other as *const _ as *const u8, Self::BYTE_SIZE) };
 
-        for i in 0..Self::BYTE_SIZE {
-            self_bytes[i] |= other_bytes[i];
-        }
+        dispatch::or(self_bytes, other_bytes);
         self
     }
 
@@ -1337,9 +4059,7 @@ where
         let other_bytes: &[u8] =
             unsafe { std::slice::from_raw_parts(&other as *const _ as *const u8, Self::BYTE_SIZE) };
 
-        for i in 0..Self::BYTE_SIZE {
-            self_bytes[i] &= other_bytes[i];
-        }
+        dispatch::and(self_bytes, other_bytes);
         self
     }
 
@@ -1351,9 +4071,7 @@ where
         let other_bytes: &[u8] =
             unsafe { std::slice::from_raw_parts(&other as *const _ as *const u8, Self::BYTE_SIZE) };
 
-        for i in 0..Self::BYTE_SIZE {
-            self_bytes[i] &= !other_bytes[i];
-        }
+        dispatch::and_not(self_bytes, other_bytes);
         self
     }
 
@@ -1365,9 +4083,7 @@ where
         let other_bytes: &[u8] =
             unsafe { std::slice::from_raw_parts(&other as *const _ as *const u8, Self::BYTE_SIZE) };
 
-        for i in 0..Self::BYTE_SIZE {
-            self_bytes[i] ^= other_bytes[i];
-        }
+        dispatch::xor(self_bytes, other_bytes);
         self
     }
 
@@ -1386,6 +4102,101 @@ where
         true
     }
 
+    #[inline(always)]
+    fn intersection_count(&self, other: &Self) -> usize {
+        let self_bytes: &[u8] =
+            unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, Self::BYTE_SIZE) };
+        let other_bytes: &[u8] =
+            unsafe { std::slice::from_raw_parts(other as *const _ as *const u8, Self::BYTE_SIZE) };
+
+        dispatch::count_and(self_bytes, other_bytes)
+    }
+
+    #[inline(always)]
+    fn union_count(&self, other: &Self) -> usize {
+        let self_bytes: &[u8] =
+            unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, Self::BYTE_SIZE) };
+        let other_bytes: &[u8] =
+            unsafe { std::slice::from_raw_parts(other as *const _ as *const u8, Self::BYTE_SIZE) };
+
+        dispatch::count_or(self_bytes, other_bytes)
+    }
+
+    #[inline(always)]
+    fn difference_count(&self, other: &Self) -> usize {
+        let self_bytes: &[u8] =
+            unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, Self::BYTE_SIZE) };
+        let other_bytes: &[u8] =
+            unsafe { std::slice::from_raw_parts(other as *const _ as *const u8, Self::BYTE_SIZE) };
+
+        dispatch::count_and_not(self_bytes, other_bytes)
+    }
+
+    #[inline(always)]
+    fn sym_difference_count(&self, other: &Self) -> usize {
+        let self_bytes: &[u8] =
+            unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, Self::BYTE_SIZE) };
+        let other_bytes: &[u8] =
+            unsafe { std::slice::from_raw_parts(other as *const _ as *const u8, Self::BYTE_SIZE) };
+
+        dispatch::count_xor(self_bytes, other_bytes)
+    }
+
+    fn rotate_left(mut self, amount: usize) -> Self {
+        let len = bit_len::<Self>();
+        if len == 0 {
+            return self;
+        }
+        let amount = amount % len;
+
+        // Bitsets backed by integers, and Bytesets of the same sizes, are rotated as a single integer.
+        if len <= 128 {
+            let value = packed(&self);
+            if amount > 0 {
+                let mask = u128::MAX >> (128 - len);
+                set_packed(
+                    &mut self,
+                    (value << amount | value >> (len - amount)) & mask,
+                );
+            }
+            return self;
+        }
+
+        let mut other = self.clone();
+
+        let bytes: &mut [u8] = unsafe {
+            std::slice::from_raw_parts_mut(&mut self as *mut _ as *mut u8, Self::BYTE_SIZE)
+        };
+        let other_bytes: &mut [u8] = unsafe {
+            std::slice::from_raw_parts_mut(&mut other as *mut _ as *mut u8, Self::BYTE_SIZE)
+        };
+
+        shift_bytes_left(bytes, amount);
+        shift_bytes_right(other_bytes, len - amount);
+        dispatch::or(bytes, other_bytes);
+        self
+    }
+
+    fn reverse(mut self) -> Self {
+        // Bitsets backed by integers, and Bytesets of the same sizes, are reversed as a single integer.
+        let len = bit_len::<Self>();
+        if len > 0 && len <= 128 {
+            let value = packed(&self).reverse_bits() >> (128 - len);
+            set_packed(&mut self, value);
+            return self;
+        }
+
+        let bytes: &mut [u8] = unsafe {
+            std::slice::from_raw_parts_mut(&mut self as *mut _ as *mut u8, Self::BYTE_SIZE)
+        };
+
+        bytes.reverse();
+        for byte in bytes.iter_mut() {
+            *byte = byte.reverse_bits();
+        }
+        self
+    }
+
     #[inline]
     fn intersects(&self, other: &Self) -> bool {
         let self_bytes: &[u8] =
@@ -1401,3 +4212,70 @@ where
         false
     }
 }
+
+// Implements shift operators with usize amounts.
+// Shifting by the length of the Bitset or more panics, use checked, wrapping or saturating shifts instead.
+//
+// Bitsets backed by a primitive integer shift it directly, others shift up to 64 bits at a time.
+macro_rules! impl_shift_usize {
+    (@impl [$($generics:tt)*] $bitset:ty, $shl:expr, $shr:expr) => {
+        impl<$($generics)*> Shl<usize> for $bitset {
+            type Output = Self;
+
+            #[inline(always)]
+            fn shl(self, rhs: usize) -> Self::Output {
+                ($shl)(self, rhs).expect("attempt to shift left with overflow")
+            }
+        }
+
+        impl<$($generics)*> ShlAssign<usize> for $bitset {
+            #[inline(always)]
+            fn shl_assign(&mut self, rhs: usize) {
+                *self = self.clone() << rhs;
+            }
+        }
+
+        impl<$($generics)*> Shr<usize> for $bitset {
+            type Output = Self;
+
+            #[inline(always)]
+            fn shr(self, rhs: usize) -> Self::Output {
+                ($shr)(self, rhs).expect("attempt to shift right with overflow")
+            }
+        }
+
+        impl<$($generics)*> ShrAssign<usize> for $bitset {
+            #[inline(always)]
+            fn shr_assign(&mut self, rhs: usize) {
+                *self = self.clone() >> rhs;
+            }
+        }
+    };
+    (primitive: $($bitset:ident),* ; generic: $([$($generics:tt)*] $other:ty),* $(,)?) => {
+        $(
+            impl_shift_usize!(
+                @impl [] $bitset,
+                |bitset: $bitset, rhs: usize| u32::try_from(rhs)
+                    .ok()
+                    .and_then(|rhs| bitset.0.checked_shl(rhs))
+                    .map($bitset),
+                |bitset: $bitset, rhs: usize| u32::try_from(rhs)
+                    .ok()
+                    .and_then(|rhs| bitset.0.checked_shr(rhs))
+                    .map($bitset)
+            );
+        )*
+        $(
+            impl_shift_usize!(
+                @impl [$($generics)*] $other,
+                |bitset: $other, rhs: usize| bitset.checked_shl(rhs),
+                |bitset: $other, rhs: usize| bitset.checked_shr(rhs)
+            );
+        )*
+    };
+}
+
+impl_shift_usize!(
+    primitive: Bitset8, Bitset16, Bitset32, Bitset64, Bitset128, BitsetSize;
+    generic: [] Bitset256, [] Bitset512, [const N: usize] Byteset<N>,
+);
@@ -3,14 +3,15 @@
 use crate::{
     bit::Bit,
     bitset::{Bitset, LeftAligned},
-    prelude::{Bitset16, Bitset32, Bitset64, Bitset8, Byteset, Index},
+    error::{ConvError, ConvTarget},
+    prelude::{Bitset16, Bitset256, Bitset32, Bitset512, Bitset64, Bitset8, Byteset, Index},
     safety_markers::Size,
 };
 use std::{
     fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
     ops::{
         BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
-        ShrAssign,
+        ShrAssign, Sub, SubAssign,
     },
 };
 
@@ -21,6 +22,23 @@ const BITS: usize = 128;
 /// [`Bitset`] of bit size 128.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Binary)
+)]
 #[repr(transparent)]
 pub struct Bitset128(pub(crate) Inner);
 
@@ -65,6 +83,60 @@ impl Bitset128 {
     pub const fn into_inner(&self) -> Inner {
         self.0
     }
+
+    /// Returns `Bitset128` with the byte order of its inner representation reversed.
+    #[inline(always)]
+    pub const fn swap_bytes(&self) -> Self {
+        Self(self.0.swap_bytes())
+    }
+
+    /// Converts `Bitset128` from big endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_be(value: Self) -> Self {
+        Self(u128::from_be(value.0))
+    }
+
+    /// Converts `Bitset128` from little endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_le(value: Self) -> Self {
+        Self(u128::from_le(value.0))
+    }
+
+    /// Converts `Bitset128` to big endian from the target's endianness.
+    #[inline(always)]
+    pub const fn to_be(&self) -> Self {
+        Self(self.0.to_be())
+    }
+
+    /// Converts `Bitset128` to little endian from the target's endianness.
+    #[inline(always)]
+    pub const fn to_le(&self) -> Self {
+        Self(self.0.to_le())
+    }
+
+    /// Constructs a new value of `Bitset128` from its representation as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    /// Constructs a new value of `Bitset128` from its representation as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_le_bytes(bytes))
+    }
+
+    /// Returns the memory representation of `Bitset128` as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn to_be_bytes(&self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the memory representation of `Bitset128` as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn to_le_bytes(&self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
 }
 
 unsafe impl LeftAligned for Bitset128 {
@@ -104,7 +176,14 @@ impl From<BIndex> for Bitset128 {
 impl From<Byteset<16>> for Bitset128 {
     #[inline(always)]
     fn from(value: Byteset<16>) -> Self {
-        unsafe { std::mem::transmute_copy(&value) }
+        Self::from_le_bytes(value.into_inner())
+    }
+}
+
+impl From<Bitset128> for Byteset<16> {
+    #[inline(always)]
+    fn from(value: Bitset128) -> Self {
+        Self::new(value.to_le_bytes())
     }
 }
 
@@ -136,6 +215,30 @@ impl From<Bitset64> for Bitset128 {
     }
 }
 
+impl TryFrom<Bitset256> for Bitset128 {
+    type Error = ConvError;
+
+    #[inline(always)]
+    fn try_from(value: Bitset256) -> Result<Self, Self::Error> {
+        match value.into_inner() {
+            [low, 0] => Ok(Self(low)),
+            _ => Err(ConvError::new(ConvTarget::Set(256), ConvTarget::Set(128))),
+        }
+    }
+}
+
+impl TryFrom<Bitset512> for Bitset128 {
+    type Error = ConvError;
+
+    #[inline(always)]
+    fn try_from(value: Bitset512) -> Result<Self, Self::Error> {
+        match value.into_inner() {
+            [low, high, 0, 0, 0, 0, 0, 0] => Ok(Self(low as Inner | (high as Inner) << 64)),
+            _ => Err(ConvError::new(ConvTarget::Set(512), ConvTarget::Set(128))),
+        }
+    }
+}
+
 impl Not for Bitset128 {
     type Output = Self;
 
@@ -193,6 +296,22 @@ impl BitXorAssign for Bitset128 {
     }
 }
 
+impl Sub for Bitset128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 & !rhs.0)
+    }
+}
+
+impl SubAssign for Bitset128 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 &= !rhs.0;
+    }
+}
+
 impl Shl<BIndex> for Bitset128 {
     type Output = Self;
 
@@ -273,14 +392,37 @@ impl BitXorAssign<BIndex> for Bitset128 {
     }
 }
 
+impl Sub<BIndex> for Bitset128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: BIndex) -> Self::Output {
+        Self(self.0 & !Self::from(rhs).0)
+    }
+}
+
+impl SubAssign<BIndex> for Bitset128 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: BIndex) {
+        self.0 &= !Self::from(rhs).0;
+    }
+}
+
 impl FromIterator<Bit> for Bitset128 {
     fn from_iter<T: IntoIterator<Item = Bit>>(iter: T) -> Self {
         iter.into_iter()
             .take(BITS)
             .enumerate()
-            .filter_map(|(i, bit)| if bool::from(bit) { Some(i) } else { None })
-            .filter_map(|i| BIndex::try_from(i).ok())
-            .fold(Self::NONE, |acc, i| acc | Self(1) << i)
+            .fold(Self::NONE, |acc, (i, bit)| {
+                Self(acc.0 | Inner::from(bool::from(bit)) << i)
+            })
+    }
+}
+
+impl FromIterator<bool> for Bitset128 {
+    #[inline(always)]
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        iter.into_iter().map(Bit::from).collect()
     }
 }
 
@@ -456,10 +598,10 @@ mod tests {
     fn shl() -> TestResult {
         let bitset: Tested = 0b00000001.into();
 
-        assert_eq!(bitset << 1.try_into()?, 0b00000010.into());
+        assert_eq!(bitset << Index::try_from(1)?, 0b00000010.into());
 
         let mut bitset: Tested = 0b00000001.into();
-        bitset <<= 1.try_into()?;
+        bitset <<= Index::try_from(1)?;
 
         assert_eq!(bitset, 0b00000010.into());
         Ok(())
@@ -469,15 +611,34 @@ mod tests {
     fn shr() -> TestResult {
         let bitset: Tested = 0b00000010.into();
 
-        assert_eq!(bitset >> 1.try_into()?, 0b00000001.into());
+        assert_eq!(bitset >> Index::try_from(1)?, 0b00000001.into());
 
         let mut bitset: Tested = 0b00000010.into();
-        bitset >>= 1.try_into()?;
+        bitset >>= Index::try_from(1)?;
 
         assert_eq!(bitset, 0b00000001.into());
         Ok(())
     }
 
+    #[test]
+    fn usize_shifts() {
+        let bitset: Tested = 0b00000011.into();
+
+        assert_eq!(bitset << 127, Tested::new(1 << 127));
+        assert_eq!(Tested::new(1 << 127) >> 127, 0b00000001.into());
+
+        let mut shifted = bitset;
+        shifted <<= 1;
+        shifted >>= 1;
+        assert_eq!(shifted, bitset);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn usize_shift_overflow() {
+        let _ = Tested::ALL << (u32::MAX as usize + 1);
+    }
+
     #[test]
     fn not() {
         let a: Tested = Tested::NONE;
@@ -527,6 +688,22 @@ mod tests {
         assert_eq!(a, 0b00111100.into());
     }
 
+    #[test]
+    fn sub() -> TestResult {
+        let a: Tested = 0b11110000.into();
+        let b: Tested = 0b11001100.into();
+
+        assert_eq!(a - b, 0b00110000.into());
+        assert_eq!(a - Index::<Tested>::try_from(4)?, 0b11100000.into());
+
+        let mut a: Tested = 0b11110000.into();
+        a -= b;
+        a -= Index::<Tested>::try_from(5)?;
+
+        assert_eq!(a, 0b00010000.into());
+        Ok(())
+    }
+
     #[test]
     fn complement() {
         let a: Tested = Tested::NONE;
@@ -628,7 +805,6 @@ mod tests {
         }
 
         assert_eq!(iter.next(), None);
-        drop(iter);
 
         for mut bit in bitset.bits_mut() {
             *bit = !*bit;
@@ -703,6 +879,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn conversion_from_bigger() -> TestResult {
+        let bitset = Tested::new(u128::MAX - 1);
+
+        assert_eq!(Tested::try_from(Bitset256::from(bitset))?, bitset);
+        assert_eq!(Tested::try_from(Bitset512::from(bitset))?, bitset);
+        assert!(Tested::try_from(Bitset256::ALL).is_err());
+        assert!(Tested::try_from(Bitset512::ALL).is_err());
+        Ok(())
+    }
+
     #[test]
     fn from_slice_bool() {
         // Same index order
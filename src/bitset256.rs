@@ -0,0 +1,691 @@
+//! Module containing [`Bitset256`].
+
+use crate::{
+    bit::Bit,
+    bitset::{Bitset, LeftAligned},
+    error::{ConvError, ConvTarget},
+    prelude::{Bitset128, Bitset16, Bitset32, Bitset512, Bitset64, Bitset8, Byteset, Index},
+    safety_markers::Size,
+};
+use std::{
+    fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
+    ops::{
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+        ShrAssign, Sub, SubAssign,
+    },
+};
+
+type Inner = [u128; 2];
+type BIndex = Index<Bitset256>;
+const BITS: usize = 256;
+const WORDS: usize = 2;
+
+/// [`Bitset`] of bit size 256.
+///
+/// Backed by two [`u128`] words, the least significant one first.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(transparent)]
+pub struct Bitset256(pub(crate) Inner);
+
+impl Bitset256 {
+    /// Constructs a new value of `Bitset256` from words, the least significant one first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset256};
+    ///
+    /// let bitset = Bitset256::new([19, 1]);
+    ///
+    /// assert_eq!(bitset, Bitset256::from_repr([19, 1]));
+    /// assert_eq!(bitset.count_ones(), 4);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn new(inner: Inner) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the inner `[u128; 2]` representation of `Bitset256`, the least significant word first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::Bitset256;
+    ///
+    /// let bitset = Bitset256::new([19, 1]);
+    /// let inner: [u128; 2] = bitset.into_inner();
+    ///
+    /// assert_eq!(inner, [19, 1]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn into_inner(&self) -> Inner {
+        self.0
+    }
+
+    /// Returns `Bitset256` with the byte order of its inner representation reversed,
+    /// including the order of the words.
+    #[inline(always)]
+    pub const fn swap_bytes(&self) -> Self {
+        let value = self;
+        let mut inner = [0; 2];
+        let mut w = 0;
+        while w < 2 {
+            inner[w] = value.0[1 - w].swap_bytes();
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Converts `Bitset256` from big endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_be(value: Self) -> Self {
+        let mut inner = [0; 2];
+        let mut w = 0;
+        while w < 2 {
+            inner[w] = u128::from_be(value.0[1 - w]);
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Converts `Bitset256` from little endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_le(value: Self) -> Self {
+        let mut inner = [0; 2];
+        let mut w = 0;
+        while w < 2 {
+            inner[w] = u128::from_le(value.0[w]);
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Converts `Bitset256` to big endian from the target's endianness,
+    /// so that its memory representation is the bytes of the whole `Bitset256` in big endian byte order.
+    #[inline(always)]
+    pub const fn to_be(&self) -> Self {
+        let value = self;
+        let mut inner = [0; 2];
+        let mut w = 0;
+        while w < 2 {
+            inner[w] = value.0[1 - w].to_be();
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Converts `Bitset256` to little endian from the target's endianness,
+    /// so that its memory representation is the bytes of the whole `Bitset256` in little endian byte order.
+    #[inline(always)]
+    pub const fn to_le(&self) -> Self {
+        let value = self;
+        let mut inner = [0; 2];
+        let mut w = 0;
+        while w < 2 {
+            inner[w] = value.0[w].to_le();
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Constructs a new value of `Bitset256` from its representation as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        // SAFETY: `[u8; 32]` and `Inner` have the same size, and any bit pattern is valid for both.
+        Self::from_be(Self(unsafe {
+            std::mem::transmute::<[u8; 32], Inner>(bytes)
+        }))
+    }
+
+    /// Constructs a new value of `Bitset256` from its representation as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        // SAFETY: `[u8; 32]` and `Inner` have the same size, and any bit pattern is valid for both.
+        Self::from_le(Self(unsafe {
+            std::mem::transmute::<[u8; 32], Inner>(bytes)
+        }))
+    }
+
+    /// Returns the memory representation of `Bitset256` as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn to_be_bytes(&self) -> [u8; 32] {
+        // SAFETY: `[u8; 32]` and `Inner` have the same size, and any bit pattern is valid for both.
+        unsafe { std::mem::transmute::<Inner, [u8; 32]>(self.to_be().0) }
+    }
+
+    /// Returns the memory representation of `Bitset256` as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn to_le_bytes(&self) -> [u8; 32] {
+        // SAFETY: `[u8; 32]` and `Inner` have the same size, and any bit pattern is valid for both.
+        unsafe { std::mem::transmute::<Inner, [u8; 32]>(self.to_le().0) }
+    }
+}
+
+unsafe impl LeftAligned for Bitset256 {
+    type _Repr = Inner;
+    type _Size = Size<32>;
+    const _BYTE_SIZE: usize = 32;
+    const _ALL: Self = Self([u128::MAX; WORDS]);
+    const _NONE: Self = Self([u128::MIN; WORDS]);
+
+    #[inline(always)]
+    fn _from_repr(value: Self::Repr) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Inner> for Bitset256 {
+    #[inline(always)]
+    fn from(value: Inner) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bitset256> for Inner {
+    #[inline(always)]
+    fn from(value: Bitset256) -> Self {
+        value.0
+    }
+}
+
+impl From<BIndex> for Bitset256 {
+    #[inline(always)]
+    fn from(value: BIndex) -> Self {
+        let mut inner = [0; WORDS];
+        inner[value.into_inner() / 128] = 1 << (value.into_inner() % 128);
+        Self(inner)
+    }
+}
+
+impl From<Byteset<32>> for Bitset256 {
+    #[inline(always)]
+    fn from(value: Byteset<32>) -> Self {
+        Self::from_le_bytes(value.into_inner())
+    }
+}
+
+impl From<Bitset256> for Byteset<32> {
+    #[inline(always)]
+    fn from(value: Bitset256) -> Self {
+        Self::new(value.to_le_bytes())
+    }
+}
+
+impl From<Bitset8> for Bitset256 {
+    #[inline(always)]
+    fn from(value: Bitset8) -> Self {
+        Self([value.into_inner() as u128, 0])
+    }
+}
+
+impl From<Bitset16> for Bitset256 {
+    #[inline(always)]
+    fn from(value: Bitset16) -> Self {
+        Self([value.into_inner() as u128, 0])
+    }
+}
+
+impl From<Bitset32> for Bitset256 {
+    #[inline(always)]
+    fn from(value: Bitset32) -> Self {
+        Self([value.into_inner() as u128, 0])
+    }
+}
+
+impl From<Bitset64> for Bitset256 {
+    #[inline(always)]
+    fn from(value: Bitset64) -> Self {
+        Self([value.into_inner() as u128, 0])
+    }
+}
+
+impl From<Bitset128> for Bitset256 {
+    #[inline(always)]
+    fn from(value: Bitset128) -> Self {
+        Self([value.into_inner(), 0])
+    }
+}
+
+impl TryFrom<Bitset512> for Bitset256 {
+    type Error = ConvError;
+
+    #[inline(always)]
+    fn try_from(value: Bitset512) -> Result<Self, Self::Error> {
+        let (low, high): (Self, Self) = value.split();
+        if high.is_empty() {
+            Ok(low)
+        } else {
+            Err(ConvError::new(ConvTarget::Set(512), ConvTarget::Set(256)))
+        }
+    }
+}
+
+impl Not for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        Self(self.0.map(|word| !word))
+    }
+}
+
+impl BitAnd for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self([self.0[0] & rhs.0[0], self.0[1] & rhs.0[1]])
+    }
+}
+
+impl BitAndAssign for Bitset256 {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl BitOr for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self([self.0[0] | rhs.0[0], self.0[1] | rhs.0[1]])
+    }
+}
+
+impl BitOrAssign for Bitset256 {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl BitXor for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self([self.0[0] ^ rhs.0[0], self.0[1] ^ rhs.0[1]])
+    }
+}
+
+impl BitXorAssign for Bitset256 {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
+impl Sub for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self & !rhs
+    }
+}
+
+impl SubAssign for Bitset256 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Shl<BIndex> for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shl(self, rhs: BIndex) -> Self::Output {
+        self.shift_left(rhs)
+    }
+}
+
+impl ShlAssign<BIndex> for Bitset256 {
+    #[inline(always)]
+    fn shl_assign(&mut self, rhs: BIndex) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl Shr<BIndex> for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shr(self, rhs: BIndex) -> Self::Output {
+        self.shift_right(rhs)
+    }
+}
+
+impl ShrAssign<BIndex> for Bitset256 {
+    #[inline(always)]
+    fn shr_assign(&mut self, rhs: BIndex) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl BitAnd<BIndex> for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: BIndex) -> Self::Output {
+        self & Self::from(rhs)
+    }
+}
+
+impl BitAndAssign<BIndex> for Bitset256 {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: BIndex) {
+        *self &= Self::from(rhs);
+    }
+}
+
+impl BitOr<BIndex> for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: BIndex) -> Self::Output {
+        self | Self::from(rhs)
+    }
+}
+
+impl BitOrAssign<BIndex> for Bitset256 {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: BIndex) {
+        *self |= Self::from(rhs);
+    }
+}
+
+impl BitXor<BIndex> for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: BIndex) -> Self::Output {
+        self ^ Self::from(rhs)
+    }
+}
+
+impl BitXorAssign<BIndex> for Bitset256 {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: BIndex) {
+        *self ^= Self::from(rhs);
+    }
+}
+
+impl Sub<BIndex> for Bitset256 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: BIndex) -> Self::Output {
+        self - Self::from(rhs)
+    }
+}
+
+impl SubAssign<BIndex> for Bitset256 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: BIndex) {
+        *self -= Self::from(rhs);
+    }
+}
+
+impl FromIterator<Bit> for Bitset256 {
+    fn from_iter<T: IntoIterator<Item = Bit>>(iter: T) -> Self {
+        iter.into_iter()
+            .take(BITS)
+            .enumerate()
+            .fold(Self::NONE, |mut acc, (i, bit)| {
+                acc.0[i / 128] |= u128::from(bool::from(bit)) << (i % 128);
+                acc
+            })
+    }
+}
+
+impl FromIterator<bool> for Bitset256 {
+    #[inline(always)]
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        iter.into_iter().map(Bit::from).collect()
+    }
+}
+
+impl Debug for Bitset256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Bitset256({self:#b})")
+    }
+}
+
+impl Display for Bitset256 {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:0128b}{:0128b}", self.0[1], self.0[0])
+    }
+}
+
+impl Binary for Bitset256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0b{self}")
+    }
+}
+
+impl Octal for Bitset256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // 256 bits are 85 full octal digits, topped by a single bit.
+        let digits = (0..BITS.div_ceil(3))
+            .rev()
+            .fold(String::new(), |mut acc, d| {
+                let digit = (3 * d..(3 * d + 3).min(BITS)).fold(0, |digit, i| {
+                    digit | (self.0[i / 128] >> (i % 128) & 1) << (i - 3 * d)
+                });
+                acc.push(char::from(b'0' + digit as u8));
+                acc
+            });
+        write!(f, "0o{digits}")
+    }
+}
+
+impl UpperHex for Bitset256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#034X}{:032X}", self.0[1], self.0[0])
+    }
+}
+
+impl LowerHex for Bitset256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#034x}{:032x}", self.0[1], self.0[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::{bit::Bit::*, prelude::Bitset};
+
+    use super::*;
+    type Tested = Bitset256;
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    #[test]
+    fn construction() -> TestResult {
+        let bitset = Tested::NONE
+            .clone()
+            .replace(0.try_into()?, One)
+            .set(200.try_into()?)
+            .unset(0.try_into()?)
+            .build();
+
+        assert_eq!(bitset, Tested::new([0, 1 << 72]));
+        Ok(())
+    }
+
+    #[test]
+    fn conversion_from_index() {
+        assert_eq!(Tested::from(Index::<Tested>::MIN).0, [1, 0]);
+        assert_eq!(Tested::from(Index::<Tested>::MAX).0, [0, 1 << 127]);
+    }
+
+    #[test]
+    fn conversion_from_smaller() -> TestResult {
+        assert_eq!(Tested::from(Bitset8::new(3)), Tested::new([3, 0]));
+        assert_eq!(Tested::from(Bitset128::new(u128::MAX)).count_ones(), 128);
+        assert_eq!(
+            Tested::from(Byteset::<32>::ALL),
+            Tested::new([u128::MAX; 2])
+        );
+        assert_eq!(Tested::try_from(Bitset512::from(Tested::ALL))?, Tested::ALL);
+        assert!(Tested::try_from(Bitset512::from(Index::<Bitset512>::MAX)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn bit() -> TestResult {
+        let bitset = Tested::new([0, 1]);
+
+        assert_eq!(bitset.bit(128.try_into()?), One);
+        assert_eq!(bitset.bit(127.try_into()?), Zero);
+        Ok(())
+    }
+
+    #[test]
+    fn shl() -> TestResult {
+        let bitset = Tested::new([u128::MAX, 0]);
+
+        assert_eq!(
+            bitset << Index::try_from(64)?,
+            Tested::new([u128::MAX << 64, u64::MAX as u128])
+        );
+        assert_eq!(bitset << Index::try_from(255)?, Tested::new([0, 1 << 127]));
+        Ok(())
+    }
+
+    #[test]
+    fn shr() -> TestResult {
+        let bitset = Tested::new([0, u128::MAX]);
+
+        assert_eq!(
+            bitset >> Index::try_from(64)?,
+            Tested::new([u128::MAX << 64, u64::MAX as u128])
+        );
+        assert_eq!(bitset >> Index::try_from(255)?, Tested::new([1, 0]));
+        Ok(())
+    }
+
+    #[test]
+    fn operators() -> TestResult {
+        let a = Tested::new([0b1100, 0b1010]);
+        let b = Tested::new([0b1010, 0b0110]);
+
+        assert_eq!(a & b, Tested::new([0b1000, 0b0010]));
+        assert_eq!(a | b, Tested::new([0b1110, 0b1110]));
+        assert_eq!(a ^ b, Tested::new([0b0110, 0b1100]));
+        assert_eq!(a - b, Tested::new([0b0100, 0b1000]));
+        assert_eq!(
+            a - Index::<Tested>::try_from(131)?,
+            Tested::new([0b1100, 0b0010])
+        );
+        assert_eq!(!Tested::NONE, Tested::ALL);
+        assert_eq!(a.complement().count_ones(), 252);
+        assert_eq!(
+            a | Index::<Tested>::try_from(129)?,
+            Tested::new([0b1100, 0b1010])
+        );
+        assert_eq!(
+            a ^ Index::<Tested>::try_from(131)?,
+            Tested::new([0b1100, 0b0010])
+        );
+        assert_eq!(a & Index::<Tested>::try_from(2)?, Tested::new([0b0100, 0]));
+        Ok(())
+    }
+
+    #[test]
+    fn combine_split() {
+        let low = Bitset128::new(1);
+        let high = Bitset128::new(1 << 127);
+        let bitset: Tested = low.combine(high);
+
+        assert_eq!(bitset, Tested::new([1, 1 << 127]));
+        assert_eq!(bitset.split::<Bitset128, Bitset128>(), (low, high));
+        assert_eq!(Bitset64::new(5).expand::<Tested>(), Tested::new([5, 0]));
+    }
+
+    #[test]
+    fn ones() -> TestResult {
+        let bitset = Tested::new([1, 1 << 127]);
+        let mut iter = bitset.ones();
+
+        assert_eq!(iter.next(), Some(0.try_into()?));
+        assert_eq!(iter.next(), Some(255.try_into()?));
+        assert_eq!(iter.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn collect_from_bits() {
+        let bits = (0..256).map(|i| Bit::from(i % 2 == 1));
+        let bitset: Tested = bits.collect();
+
+        assert_eq!(bitset, Tested::new([u128::MAX / 3 * 2; 2]));
+    }
+
+    #[test]
+    fn formatting() {
+        let bitset = Tested::new([0xF, 1 << 127]);
+
+        assert_eq!(format!("{bitset:x}"), format!("0x8{}f", "0".repeat(62)));
+        assert_eq!(format!("{bitset:X}"), format!("0x8{}F", "0".repeat(62)));
+        assert_eq!(format!("{bitset}"), format!("1{}1111", "0".repeat(251)));
+        assert_eq!(format!("{bitset:b}"), format!("0b{bitset}"));
+        assert_eq!(format!("{bitset:?}"), format!("Bitset256(0b{bitset})"));
+        assert_eq!(format!("{bitset:o}"), format!("0o1{}17", "0".repeat(83)));
+    }
+
+    #[test]
+    fn byte_order() {
+        let bitset = Tested::new([0x0102030405060708_090A0B0C0D0E0F10, 1 << 127]);
+        let mut bytes = bitset.to_le_bytes();
+
+        assert_eq!(bytes[..3], [0x10, 0x0F, 0x0E]);
+        assert_eq!(bytes[31], 0x80);
+        assert_eq!(Tested::from_le_bytes(bytes), bitset);
+        assert_eq!(Tested::from_be_bytes(bitset.to_be_bytes()), bitset);
+        assert_eq!(Tested::from_be(bitset.to_be()), bitset);
+
+        bytes.reverse();
+        assert_eq!(bitset.to_be_bytes(), bytes);
+        assert_eq!(bitset.swap_bytes().to_le_bytes(), bytes);
+        assert_eq!(Tested::from_le(bitset.to_le()), bitset);
+    }
+
+    #[test]
+    fn test_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Tested>();
+    }
+
+    #[test]
+    fn test_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Tested>();
+    }
+}
@@ -0,0 +1,697 @@
+//! Module containing [`Bitset512`].
+
+use crate::{
+    bit::Bit,
+    bitset::{Bitset, LeftAligned},
+    prelude::{Bitset128, Bitset16, Bitset256, Bitset32, Bitset64, Bitset8, Byteset, Index},
+    safety_markers::Size,
+};
+use std::{
+    fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
+    ops::{
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+        ShrAssign, Sub, SubAssign,
+    },
+};
+
+type Inner = [u64; 8];
+type BIndex = Index<Bitset512>;
+const BITS: usize = 512;
+const WORDS: usize = 8;
+
+/// [`Bitset`] of bit size 512.
+///
+/// Backed by eight [`u64`] words, the least significant one first.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(transparent)]
+pub struct Bitset512(pub(crate) Inner);
+
+impl Bitset512 {
+    /// Constructs a new value of `Bitset512` from words, the least significant one first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset512};
+    ///
+    /// let bitset = Bitset512::new([19, 0, 0, 0, 0, 0, 0, 1]);
+    ///
+    /// assert_eq!(bitset, Bitset512::from_repr([19, 0, 0, 0, 0, 0, 0, 1]));
+    /// assert_eq!(bitset.count_ones(), 4);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn new(inner: Inner) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the inner `[u64; 8]` representation of `Bitset512`, the least significant word first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::Bitset512;
+    ///
+    /// let bitset = Bitset512::new([19, 0, 0, 0, 0, 0, 0, 1]);
+    /// let inner: [u64; 8] = bitset.into_inner();
+    ///
+    /// assert_eq!(inner, [19, 0, 0, 0, 0, 0, 0, 1]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn into_inner(&self) -> Inner {
+        self.0
+    }
+
+    /// Returns `Bitset512` with the byte order of its inner representation reversed,
+    /// including the order of the words.
+    #[inline(always)]
+    pub const fn swap_bytes(&self) -> Self {
+        let value = self;
+        let mut inner = [0; 8];
+        let mut w = 0;
+        while w < 8 {
+            inner[w] = value.0[7 - w].swap_bytes();
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Converts `Bitset512` from big endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_be(value: Self) -> Self {
+        let mut inner = [0; 8];
+        let mut w = 0;
+        while w < 8 {
+            inner[w] = u64::from_be(value.0[7 - w]);
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Converts `Bitset512` from little endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_le(value: Self) -> Self {
+        let mut inner = [0; 8];
+        let mut w = 0;
+        while w < 8 {
+            inner[w] = u64::from_le(value.0[w]);
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Converts `Bitset512` to big endian from the target's endianness,
+    /// so that its memory representation is the bytes of the whole `Bitset512` in big endian byte order.
+    #[inline(always)]
+    pub const fn to_be(&self) -> Self {
+        let value = self;
+        let mut inner = [0; 8];
+        let mut w = 0;
+        while w < 8 {
+            inner[w] = value.0[7 - w].to_be();
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Converts `Bitset512` to little endian from the target's endianness,
+    /// so that its memory representation is the bytes of the whole `Bitset512` in little endian byte order.
+    #[inline(always)]
+    pub const fn to_le(&self) -> Self {
+        let value = self;
+        let mut inner = [0; 8];
+        let mut w = 0;
+        while w < 8 {
+            inner[w] = value.0[w].to_le();
+            w += 1;
+        }
+        Self(inner)
+    }
+
+    /// Constructs a new value of `Bitset512` from its representation as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; 64]) -> Self {
+        // SAFETY: `[u8; 64]` and `Inner` have the same size, and any bit pattern is valid for both.
+        Self::from_be(Self(unsafe {
+            std::mem::transmute::<[u8; 64], Inner>(bytes)
+        }))
+    }
+
+    /// Constructs a new value of `Bitset512` from its representation as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; 64]) -> Self {
+        // SAFETY: `[u8; 64]` and `Inner` have the same size, and any bit pattern is valid for both.
+        Self::from_le(Self(unsafe {
+            std::mem::transmute::<[u8; 64], Inner>(bytes)
+        }))
+    }
+
+    /// Returns the memory representation of `Bitset512` as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn to_be_bytes(&self) -> [u8; 64] {
+        // SAFETY: `[u8; 64]` and `Inner` have the same size, and any bit pattern is valid for both.
+        unsafe { std::mem::transmute::<Inner, [u8; 64]>(self.to_be().0) }
+    }
+
+    /// Returns the memory representation of `Bitset512` as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn to_le_bytes(&self) -> [u8; 64] {
+        // SAFETY: `[u8; 64]` and `Inner` have the same size, and any bit pattern is valid for both.
+        unsafe { std::mem::transmute::<Inner, [u8; 64]>(self.to_le().0) }
+    }
+}
+
+unsafe impl LeftAligned for Bitset512 {
+    type _Repr = Inner;
+    type _Size = Size<64>;
+    const _BYTE_SIZE: usize = 64;
+    const _ALL: Self = Self([u64::MAX; WORDS]);
+    const _NONE: Self = Self([u64::MIN; WORDS]);
+
+    #[inline(always)]
+    fn _from_repr(value: Self::Repr) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Inner> for Bitset512 {
+    #[inline(always)]
+    fn from(value: Inner) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bitset512> for Inner {
+    #[inline(always)]
+    fn from(value: Bitset512) -> Self {
+        value.0
+    }
+}
+
+impl From<BIndex> for Bitset512 {
+    #[inline(always)]
+    fn from(value: BIndex) -> Self {
+        let mut inner = [0; WORDS];
+        inner[value.into_inner() / 64] = 1 << (value.into_inner() % 64);
+        Self(inner)
+    }
+}
+
+impl From<Byteset<64>> for Bitset512 {
+    #[inline(always)]
+    fn from(value: Byteset<64>) -> Self {
+        Self::from_le_bytes(value.into_inner())
+    }
+}
+
+impl From<Bitset512> for Byteset<64> {
+    #[inline(always)]
+    fn from(value: Bitset512) -> Self {
+        Self::new(value.to_le_bytes())
+    }
+}
+
+impl From<Bitset8> for Bitset512 {
+    #[inline(always)]
+    fn from(value: Bitset8) -> Self {
+        Self::from(Bitset64::from(value))
+    }
+}
+
+impl From<Bitset16> for Bitset512 {
+    #[inline(always)]
+    fn from(value: Bitset16) -> Self {
+        Self::from(Bitset64::from(value))
+    }
+}
+
+impl From<Bitset32> for Bitset512 {
+    #[inline(always)]
+    fn from(value: Bitset32) -> Self {
+        Self::from(Bitset64::from(value))
+    }
+}
+
+impl From<Bitset64> for Bitset512 {
+    #[inline(always)]
+    fn from(value: Bitset64) -> Self {
+        let mut inner = [0; WORDS];
+        inner[0] = value.into_inner();
+        Self(inner)
+    }
+}
+
+impl From<Bitset128> for Bitset512 {
+    #[inline(always)]
+    fn from(value: Bitset128) -> Self {
+        let mut inner = [0; WORDS];
+        inner[0] = value.into_inner() as u64;
+        inner[1] = (value.into_inner() >> 64) as u64;
+        Self(inner)
+    }
+}
+
+impl From<Bitset256> for Bitset512 {
+    #[inline(always)]
+    fn from(value: Bitset256) -> Self {
+        value.expand()
+    }
+}
+
+impl Not for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        Self(self.0.map(|word| !word))
+    }
+}
+
+impl BitAnd for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|i| self.0[i] & rhs.0[i]))
+    }
+}
+
+impl BitAndAssign for Bitset512 {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl BitOr for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|i| self.0[i] | rhs.0[i]))
+    }
+}
+
+impl BitOrAssign for Bitset512 {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl BitXor for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|i| self.0[i] ^ rhs.0[i]))
+    }
+}
+
+impl BitXorAssign for Bitset512 {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
+impl Sub for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self & !rhs
+    }
+}
+
+impl SubAssign for Bitset512 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Shl<BIndex> for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shl(self, rhs: BIndex) -> Self::Output {
+        self.shift_left(rhs)
+    }
+}
+
+impl ShlAssign<BIndex> for Bitset512 {
+    #[inline(always)]
+    fn shl_assign(&mut self, rhs: BIndex) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl Shr<BIndex> for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shr(self, rhs: BIndex) -> Self::Output {
+        self.shift_right(rhs)
+    }
+}
+
+impl ShrAssign<BIndex> for Bitset512 {
+    #[inline(always)]
+    fn shr_assign(&mut self, rhs: BIndex) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl BitAnd<BIndex> for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: BIndex) -> Self::Output {
+        self & Self::from(rhs)
+    }
+}
+
+impl BitAndAssign<BIndex> for Bitset512 {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: BIndex) {
+        *self &= Self::from(rhs);
+    }
+}
+
+impl BitOr<BIndex> for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: BIndex) -> Self::Output {
+        self | Self::from(rhs)
+    }
+}
+
+impl BitOrAssign<BIndex> for Bitset512 {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: BIndex) {
+        *self |= Self::from(rhs);
+    }
+}
+
+impl BitXor<BIndex> for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: BIndex) -> Self::Output {
+        self ^ Self::from(rhs)
+    }
+}
+
+impl BitXorAssign<BIndex> for Bitset512 {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: BIndex) {
+        *self ^= Self::from(rhs);
+    }
+}
+
+impl Sub<BIndex> for Bitset512 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: BIndex) -> Self::Output {
+        self - Self::from(rhs)
+    }
+}
+
+impl SubAssign<BIndex> for Bitset512 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: BIndex) {
+        *self -= Self::from(rhs);
+    }
+}
+
+impl FromIterator<Bit> for Bitset512 {
+    fn from_iter<T: IntoIterator<Item = Bit>>(iter: T) -> Self {
+        iter.into_iter()
+            .take(BITS)
+            .enumerate()
+            .fold(Self::NONE, |mut acc, (i, bit)| {
+                acc.0[i / 64] |= u64::from(bool::from(bit)) << (i % 64);
+                acc
+            })
+    }
+}
+
+impl FromIterator<bool> for Bitset512 {
+    #[inline(always)]
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        iter.into_iter().map(Bit::from).collect()
+    }
+}
+
+impl Debug for Bitset512 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Bitset512({self:#b})")
+    }
+}
+
+impl Display for Bitset512 {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0
+            .iter()
+            .rev()
+            .try_for_each(|word| write!(f, "{word:064b}"))
+    }
+}
+
+impl Binary for Bitset512 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0b{self}")
+    }
+}
+
+impl Octal for Bitset512 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // 512 bits are 170 full octal digits, topped by two bits.
+        let digits = (0..BITS.div_ceil(3))
+            .rev()
+            .fold(String::new(), |mut acc, d| {
+                let digit = (3 * d..(3 * d + 3).min(BITS)).fold(0, |digit, i| {
+                    digit | (self.0[i / 64] >> (i % 64) & 1) << (i - 3 * d)
+                });
+                acc.push(char::from(b'0' + digit as u8));
+                acc
+            });
+        write!(f, "0o{digits}")
+    }
+}
+
+impl UpperHex for Bitset512 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0x")?;
+        self.0
+            .iter()
+            .rev()
+            .try_for_each(|word| write!(f, "{word:016X}"))
+    }
+}
+
+impl LowerHex for Bitset512 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0x")?;
+        self.0
+            .iter()
+            .rev()
+            .try_for_each(|word| write!(f, "{word:016x}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::{bit::Bit::*, prelude::Bitset};
+
+    use super::*;
+    type Tested = Bitset512;
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    #[test]
+    fn construction() -> TestResult {
+        let bitset = Tested::NONE
+            .clone()
+            .replace(0.try_into()?, One)
+            .set(500.try_into()?)
+            .unset(0.try_into()?)
+            .build();
+
+        assert_eq!(bitset, Tested::new([0, 0, 0, 0, 0, 0, 0, 1 << 52]));
+        Ok(())
+    }
+
+    #[test]
+    fn conversion_from_index() {
+        assert_eq!(
+            Tested::from(Index::<Tested>::MIN).0,
+            [1, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            Tested::from(Index::<Tested>::MAX).0,
+            [0, 0, 0, 0, 0, 0, 0, 1 << 63]
+        );
+    }
+
+    #[test]
+    fn conversion_from_smaller() {
+        assert_eq!(
+            Tested::from(Bitset8::new(3)),
+            Tested::new([3, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            Tested::from(Bitset128::new(u128::MAX)),
+            Tested::new([u64::MAX, u64::MAX, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(Tested::from(Bitset256::ALL).count_ones(), 256);
+        assert_eq!(Tested::from(Byteset::<64>::ALL), Tested::ALL);
+    }
+
+    #[test]
+    fn bit() -> TestResult {
+        let bitset = Tested::new([0, 0, 0, 1, 0, 0, 0, 0]);
+
+        assert_eq!(bitset.bit(192.try_into()?), One);
+        assert_eq!(bitset.bit(191.try_into()?), Zero);
+        Ok(())
+    }
+
+    #[test]
+    fn shifts() -> TestResult {
+        let bitset = Tested::new([u64::MAX, 0, 0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(
+            bitset << Index::try_from(96)?,
+            Tested::new([0, u64::MAX << 32, u64::MAX >> 32, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            bitset << Index::try_from(511)?,
+            Tested::new([0, 0, 0, 0, 0, 0, 0, 1 << 63])
+        );
+        assert_eq!(
+            (bitset << Index::try_from(448)?) >> Index::try_from(448)?,
+            bitset
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn operators() -> TestResult {
+        let a = Tested::new([0b1100, 0, 0, 0, 0, 0, 0, 0b1010]);
+        let b = Tested::new([0b1010, 0, 0, 0, 0, 0, 0, 0b0110]);
+
+        assert_eq!(a & b, Tested::new([0b1000, 0, 0, 0, 0, 0, 0, 0b0010]));
+        assert_eq!(a | b, Tested::new([0b1110, 0, 0, 0, 0, 0, 0, 0b1110]));
+        assert_eq!(a ^ b, Tested::new([0b0110, 0, 0, 0, 0, 0, 0, 0b1100]));
+        assert_eq!(a - b, Tested::new([0b0100, 0, 0, 0, 0, 0, 0, 0b1000]));
+        assert_eq!(
+            a - Index::<Tested>::try_from(451)?,
+            Tested::new([0b1100, 0, 0, 0, 0, 0, 0, 0b0010])
+        );
+        assert_eq!(!Tested::NONE, Tested::ALL);
+        assert_eq!(a.complement().count_ones(), 508);
+        assert_eq!(
+            a ^ Index::<Tested>::try_from(449)?,
+            Tested::new([0b1100, 0, 0, 0, 0, 0, 0, 0b1000])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn combine_split() -> TestResult {
+        let low = Bitset256::new([1, 0]);
+        let high = Bitset256::new([0, 1 << 127]);
+        let bitset: Tested = low.combine(high);
+
+        assert_eq!(bitset, Tested::new([1, 0, 0, 0, 0, 0, 0, 1 << 63]));
+        assert_eq!(bitset.split::<Bitset256, Bitset256>(), (low, high));
+        assert_eq!(Bitset256::try_from(Tested::from(low))?, low);
+        assert!(Bitset256::try_from(bitset).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn ones() -> TestResult {
+        let bitset = Tested::new([1, 0, 0, 0, 0, 0, 0, 1 << 63]);
+        let mut iter = bitset.ones();
+
+        assert_eq!(iter.next(), Some(0.try_into()?));
+        assert_eq!(iter.next(), Some(511.try_into()?));
+        assert_eq!(iter.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn collect_from_bits() {
+        let bits = (0..512).map(|i| Bit::from(i % 2 == 1));
+        let bitset: Tested = bits.collect();
+
+        assert_eq!(bitset, Tested::new([u64::MAX / 3 * 2; 8]));
+    }
+
+    #[test]
+    fn formatting() {
+        let bitset = Tested::new([0xF, 0, 0, 0, 0, 0, 0, 1 << 63]);
+
+        assert_eq!(format!("{bitset:x}"), format!("0x8{}f", "0".repeat(126)));
+        assert_eq!(format!("{bitset:X}"), format!("0x8{}F", "0".repeat(126)));
+        assert_eq!(format!("{bitset}"), format!("1{}1111", "0".repeat(507)));
+        assert_eq!(format!("{bitset:b}"), format!("0b{bitset}"));
+        assert_eq!(format!("{bitset:?}"), format!("Bitset512(0b{bitset})"));
+        assert_eq!(format!("{bitset:o}"), format!("0o2{}17", "0".repeat(168)));
+    }
+
+    #[test]
+    fn byte_order() {
+        let bitset = Tested::new(std::array::from_fn(|w| 0x0102030405060708 << w));
+        let mut bytes = bitset.to_le_bytes();
+
+        assert_eq!(bytes[..9], [8, 7, 6, 5, 4, 3, 2, 1, 16]);
+        assert_eq!(Tested::from_le_bytes(bytes), bitset);
+        assert_eq!(Tested::from_be_bytes(bitset.to_be_bytes()), bitset);
+        assert_eq!(Tested::from_be(bitset.to_be()), bitset);
+
+        bytes.reverse();
+        assert_eq!(bitset.to_be_bytes(), bytes);
+        assert_eq!(bitset.swap_bytes().to_le_bytes(), bytes);
+        assert_eq!(Tested::from_le(bitset.to_le()), bitset);
+    }
+
+    #[test]
+    fn test_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Tested>();
+    }
+
+    #[test]
+    fn test_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Tested>();
+    }
+}
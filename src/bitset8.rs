@@ -11,7 +11,7 @@ use std::{
     fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
     ops::{
         BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
-        ShrAssign,
+        ShrAssign, Sub, SubAssign,
     },
 };
 
@@ -22,6 +22,23 @@ const BITS: usize = 8;
 /// [`Bitset`] of bit size 8.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::SmallInt)
+)]
 #[repr(transparent)]
 pub struct Bitset8(pub(crate) Inner);
 
@@ -66,6 +83,60 @@ impl Bitset8 {
     pub const fn into_inner(&self) -> Inner {
         self.0
     }
+
+    /// Returns `Bitset8` with the byte order of its inner representation reversed.
+    #[inline(always)]
+    pub const fn swap_bytes(&self) -> Self {
+        Self(self.0.swap_bytes())
+    }
+
+    /// Converts `Bitset8` from big endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_be(value: Self) -> Self {
+        Self(u8::from_be(value.0))
+    }
+
+    /// Converts `Bitset8` from little endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_le(value: Self) -> Self {
+        Self(u8::from_le(value.0))
+    }
+
+    /// Converts `Bitset8` to big endian from the target's endianness.
+    #[inline(always)]
+    pub const fn to_be(&self) -> Self {
+        Self(self.0.to_be())
+    }
+
+    /// Converts `Bitset8` to little endian from the target's endianness.
+    #[inline(always)]
+    pub const fn to_le(&self) -> Self {
+        Self(self.0.to_le())
+    }
+
+    /// Constructs a new value of `Bitset8` from its representation as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; 1]) -> Self {
+        Self(u8::from_be_bytes(bytes))
+    }
+
+    /// Constructs a new value of `Bitset8` from its representation as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; 1]) -> Self {
+        Self(u8::from_le_bytes(bytes))
+    }
+
+    /// Returns the memory representation of `Bitset8` as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn to_be_bytes(&self) -> [u8; 1] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the memory representation of `Bitset8` as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn to_le_bytes(&self) -> [u8; 1] {
+        self.0.to_le_bytes()
+    }
 }
 
 unsafe impl LeftAligned for Bitset8 {
@@ -105,7 +176,14 @@ impl From<BIndex> for Bitset8 {
 impl From<Byteset<1>> for Bitset8 {
     #[inline(always)]
     fn from(value: Byteset<1>) -> Self {
-        Self(value.into_inner()[0])
+        Self::from_le_bytes(value.into_inner())
+    }
+}
+
+impl From<Bitset8> for Byteset<1> {
+    #[inline(always)]
+    fn from(value: Bitset8) -> Self {
+        Self::new(value.to_le_bytes())
     }
 }
 
@@ -210,6 +288,22 @@ impl BitXorAssign for Bitset8 {
     }
 }
 
+impl Sub for Bitset8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 & !rhs.0)
+    }
+}
+
+impl SubAssign for Bitset8 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 &= !rhs.0;
+    }
+}
+
 impl Shl<BIndex> for Bitset8 {
     type Output = Self;
 
@@ -290,14 +384,37 @@ impl BitXorAssign<BIndex> for Bitset8 {
     }
 }
 
+impl Sub<BIndex> for Bitset8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: BIndex) -> Self::Output {
+        Self(self.0 & !Self::from(rhs).0)
+    }
+}
+
+impl SubAssign<BIndex> for Bitset8 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: BIndex) {
+        self.0 &= !Self::from(rhs).0;
+    }
+}
+
 impl FromIterator<Bit> for Bitset8 {
     fn from_iter<T: IntoIterator<Item = Bit>>(iter: T) -> Self {
         iter.into_iter()
             .take(BITS)
             .enumerate()
-            .filter_map(|(i, bit)| if bool::from(bit) { Some(i) } else { None })
-            .filter_map(|i| BIndex::try_from(i).ok())
-            .fold(Self::NONE, |acc, i| acc | Self(1) << i)
+            .fold(Self::NONE, |acc, (i, bit)| {
+                Self(acc.0 | Inner::from(bool::from(bit)) << i)
+            })
+    }
+}
+
+impl FromIterator<bool> for Bitset8 {
+    #[inline(always)]
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        iter.into_iter().map(Bit::from).collect()
     }
 }
 
@@ -472,10 +589,10 @@ mod tests {
     fn shl() -> TestResult {
         let bitset: Tested = 0b00000001.into();
 
-        assert_eq!(bitset << 1.try_into()?, 0b00000010.into());
+        assert_eq!(bitset << Index::try_from(1)?, 0b00000010.into());
 
         let mut bitset: Tested = 0b00000001.into();
-        bitset <<= 1.try_into()?;
+        bitset <<= Index::try_from(1)?;
 
         assert_eq!(bitset, 0b00000010.into());
         Ok(())
@@ -485,15 +602,34 @@ mod tests {
     fn shr() -> TestResult {
         let bitset: Tested = 0b00000010.into();
 
-        assert_eq!(bitset >> 1.try_into()?, 0b00000001.into());
+        assert_eq!(bitset >> Index::try_from(1)?, 0b00000001.into());
 
         let mut bitset: Tested = 0b00000010.into();
-        bitset >>= 1.try_into()?;
+        bitset >>= Index::try_from(1)?;
 
         assert_eq!(bitset, 0b00000001.into());
         Ok(())
     }
 
+    #[test]
+    fn usize_shifts() {
+        let bitset: Tested = 0b00000011.into();
+
+        assert_eq!(bitset << 7, Tested::new(0b10000000));
+        assert_eq!(Tested::new(0b10000000) >> 7, 0b00000001.into());
+
+        let mut shifted = bitset;
+        shifted <<= 1;
+        shifted >>= 1;
+        assert_eq!(shifted, bitset);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn usize_shift_overflow() {
+        let _ = Tested::ALL << (u32::MAX as usize + 1);
+    }
+
     #[test]
     fn not() {
         let a: Tested = 0b11110000.into();
@@ -543,6 +679,22 @@ mod tests {
         assert_eq!(a, 0b00111100.into());
     }
 
+    #[test]
+    fn sub() -> TestResult {
+        let a: Tested = 0b11110000.into();
+        let b: Tested = 0b11001100.into();
+
+        assert_eq!(a - b, 0b00110000.into());
+        assert_eq!(a - Index::<Tested>::try_from(4)?, 0b11100000.into());
+
+        let mut a: Tested = 0b11110000.into();
+        a -= b;
+        a -= Index::<Tested>::try_from(5)?;
+
+        assert_eq!(a, 0b00010000.into());
+        Ok(())
+    }
+
     #[test]
     fn complement() {
         let a: Tested = 0b11110000.into();
@@ -629,7 +781,6 @@ mod tests {
         assert_eq!(iter.next().as_deref(), Some(&One));
         assert_eq!(iter.next().as_deref(), Some(&One));
         assert_eq!(iter.next(), None);
-        drop(iter);
 
         for mut bit in bitset.bits_mut() {
             *bit = !*bit;
@@ -666,6 +817,9 @@ mod tests {
             .collect();
 
         assert_eq!(bitset, 0b10101010.into());
+
+        let bitset: Tested = [false, true, true].into_iter().collect();
+        assert_eq!(bitset, 0b00000110.into());
     }
 
     #[test]
@@ -741,6 +895,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn fast_expand() -> TestResult {
         let bitset1 = Bitset8::from(0b00011011);
         let bitset2: Bitset16 = bitset1.expand_optimized();
@@ -787,6 +942,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn fast_combine() -> TestResult {
         let bitset1 = Bitset8::from(0b00011011);
         let bitset2 = Bitset8::from(0b11101000);
@@ -798,6 +954,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn fast_split() -> TestResult {
         let bitset1 = Bitset16::from(0b1110100000011011);
         let (bitset2, bitset3): (Bitset8, Bitset8) = bitset1.split_optimized();
@@ -806,4 +963,21 @@ mod tests {
         assert_eq!(bitset3, Bitset8::from(0b11101000));
         Ok(())
     }
+
+    #[test]
+    fn indexed_bits() -> TestResult {
+        let bitset: Tested = 0b11110000.into();
+        let mut iter = bitset.indexed_bits();
+
+        assert_eq!(iter.next(), Some((0.try_into()?, Zero)));
+        assert_eq!(iter.next(), Some((1.try_into()?, Zero)));
+        assert_eq!(iter.next(), Some((2.try_into()?, Zero)));
+        assert_eq!(iter.next(), Some((3.try_into()?, Zero)));
+        assert_eq!(iter.next(), Some((4.try_into()?, One)));
+        assert_eq!(iter.next(), Some((5.try_into()?, One)));
+        assert_eq!(iter.next(), Some((6.try_into()?, One)));
+        assert_eq!(iter.next(), Some((7.try_into()?, One)));
+        assert_eq!(iter.next(), None);
+        Ok(())
+    }
 }
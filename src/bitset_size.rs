@@ -0,0 +1,642 @@
+//! Module containing [`BitsetSize`].
+
+use crate::{
+    bit::Bit,
+    bitset::{Bitset, LeftAligned},
+    error::{ConvError, ConvTarget},
+    prelude::{Bitset128, Bitset16, Bitset32, Bitset64, Bitset8, Byteset, Index},
+    safety_markers::Size,
+};
+use std::{
+    fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
+    ops::{
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+        ShrAssign, Sub, SubAssign,
+    },
+};
+
+type Inner = usize;
+type BIndex = Index<BitsetSize>;
+const BYTES: usize = std::mem::size_of::<Inner>();
+const BITS: usize = Inner::BITS as usize;
+
+/// [`Bitset`] of the pointer width: bit size 32 or 64, depending on the target.
+///
+/// Matches the `usize` bitmasks, used by OS APIs, like CPU affinity masks.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(transparent)]
+pub struct BitsetSize(pub(crate) Inner);
+
+impl BitsetSize {
+    /// Constructs a new value of `BitsetSize`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, BitsetSize};
+    ///
+    /// let bitset = BitsetSize::new(19);
+    ///
+    /// assert_eq!(bitset, BitsetSize::from_repr(19));
+    /// assert_eq!(BitsetSize::BYTE_SIZE, std::mem::size_of::<usize>());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn new(inner: Inner) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the inner [`usize`] representation of `BitsetSize`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::BitsetSize;
+    ///
+    /// let bitset = BitsetSize::new(19);
+    /// let inner: usize = bitset.into_inner();
+    ///
+    /// assert_eq!(inner, 19);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn into_inner(&self) -> Inner {
+        self.0
+    }
+
+    /// Returns `BitsetSize` with the byte order of its inner representation reversed.
+    #[inline(always)]
+    pub const fn swap_bytes(&self) -> Self {
+        Self(self.0.swap_bytes())
+    }
+
+    /// Converts `BitsetSize` from big endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_be(value: Self) -> Self {
+        Self(usize::from_be(value.0))
+    }
+
+    /// Converts `BitsetSize` from little endian to the target's endianness.
+    #[inline(always)]
+    pub const fn from_le(value: Self) -> Self {
+        Self(usize::from_le(value.0))
+    }
+
+    /// Converts `BitsetSize` to big endian from the target's endianness.
+    #[inline(always)]
+    pub const fn to_be(&self) -> Self {
+        Self(self.0.to_be())
+    }
+
+    /// Converts `BitsetSize` to little endian from the target's endianness.
+    #[inline(always)]
+    pub const fn to_le(&self) -> Self {
+        Self(self.0.to_le())
+    }
+
+    /// Constructs a new value of `BitsetSize` from its representation as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; BYTES]) -> Self {
+        Self(usize::from_be_bytes(bytes))
+    }
+
+    /// Constructs a new value of `BitsetSize` from its representation as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; BYTES]) -> Self {
+        Self(usize::from_le_bytes(bytes))
+    }
+
+    /// Returns the memory representation of `BitsetSize` as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn to_be_bytes(&self) -> [u8; BYTES] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the memory representation of `BitsetSize` as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn to_le_bytes(&self) -> [u8; BYTES] {
+        self.0.to_le_bytes()
+    }
+}
+
+unsafe impl LeftAligned for BitsetSize {
+    type _Repr = Inner;
+    type _Size = Size<BYTES>;
+    const _BYTE_SIZE: usize = BYTES;
+    const _ALL: Self = Self(Inner::MAX);
+    const _NONE: Self = Self(Inner::MIN);
+
+    #[inline(always)]
+    fn _from_repr(value: Self::Repr) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Inner> for BitsetSize {
+    #[inline(always)]
+    fn from(value: Inner) -> Self {
+        Self(value)
+    }
+}
+
+impl From<BitsetSize> for Inner {
+    #[inline(always)]
+    fn from(value: BitsetSize) -> Self {
+        value.0
+    }
+}
+
+impl From<BIndex> for BitsetSize {
+    #[inline(always)]
+    fn from(value: BIndex) -> Self {
+        Self(1) << value
+    }
+}
+
+impl From<Byteset<BYTES>> for BitsetSize {
+    #[inline(always)]
+    fn from(value: Byteset<BYTES>) -> Self {
+        Self::from_le_bytes(value.into_inner())
+    }
+}
+
+impl From<BitsetSize> for Byteset<BYTES> {
+    #[inline(always)]
+    fn from(value: BitsetSize) -> Self {
+        Self::new(value.to_le_bytes())
+    }
+}
+
+impl From<Bitset8> for BitsetSize {
+    #[inline(always)]
+    fn from(value: Bitset8) -> Self {
+        Self(value.into_inner() as Inner)
+    }
+}
+
+impl From<Bitset16> for BitsetSize {
+    #[inline(always)]
+    fn from(value: Bitset16) -> Self {
+        Self(value.into_inner() as Inner)
+    }
+}
+
+impl From<Bitset32> for BitsetSize {
+    #[inline(always)]
+    fn from(value: Bitset32) -> Self {
+        Self(value.into_inner() as Inner)
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl From<Bitset64> for BitsetSize {
+    #[inline(always)]
+    fn from(value: Bitset64) -> Self {
+        Self(value.into_inner() as Inner)
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+impl TryFrom<Bitset64> for BitsetSize {
+    type Error = ConvError;
+
+    #[inline(always)]
+    fn try_from(value: Bitset64) -> Result<Self, Self::Error> {
+        Inner::try_from(value.into_inner())
+            .map(Self::from)
+            .map_err(|_| ConvError::new(ConvTarget::Set(64), ConvTarget::Set(BITS)))
+    }
+}
+
+impl TryFrom<Bitset128> for BitsetSize {
+    type Error = ConvError;
+
+    #[inline(always)]
+    fn try_from(value: Bitset128) -> Result<Self, Self::Error> {
+        Inner::try_from(value.into_inner())
+            .map(Self::from)
+            .map_err(|_| ConvError::new(ConvTarget::Set(128), ConvTarget::Set(BITS)))
+    }
+}
+
+impl TryFrom<BitsetSize> for Bitset8 {
+    type Error = ConvError;
+
+    #[inline(always)]
+    fn try_from(value: BitsetSize) -> Result<Self, Self::Error> {
+        u8::try_from(value.0)
+            .map(Self::from)
+            .map_err(|_| ConvError::new(ConvTarget::Set(BITS), ConvTarget::Set(8)))
+    }
+}
+
+impl TryFrom<BitsetSize> for Bitset16 {
+    type Error = ConvError;
+
+    #[inline(always)]
+    fn try_from(value: BitsetSize) -> Result<Self, Self::Error> {
+        u16::try_from(value.0)
+            .map(Self::from)
+            .map_err(|_| ConvError::new(ConvTarget::Set(BITS), ConvTarget::Set(16)))
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl TryFrom<BitsetSize> for Bitset32 {
+    type Error = ConvError;
+
+    #[inline(always)]
+    fn try_from(value: BitsetSize) -> Result<Self, Self::Error> {
+        u32::try_from(value.0)
+            .map(Self::from)
+            .map_err(|_| ConvError::new(ConvTarget::Set(BITS), ConvTarget::Set(32)))
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+impl From<BitsetSize> for Bitset32 {
+    #[inline(always)]
+    fn from(value: BitsetSize) -> Self {
+        Self::new(value.0 as u32)
+    }
+}
+
+impl From<BitsetSize> for Bitset64 {
+    #[inline(always)]
+    fn from(value: BitsetSize) -> Self {
+        Self::new(value.0 as u64)
+    }
+}
+
+impl From<BitsetSize> for Bitset128 {
+    #[inline(always)]
+    fn from(value: BitsetSize) -> Self {
+        Self::new(value.0 as u128)
+    }
+}
+
+impl Not for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+impl BitAnd for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for BitsetSize {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl BitOr for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for BitsetSize {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitXor for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl BitXorAssign for BitsetSize {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0;
+    }
+}
+
+impl Sub for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 & !rhs.0)
+    }
+}
+
+impl SubAssign for BitsetSize {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 &= !rhs.0;
+    }
+}
+
+impl Shl<BIndex> for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shl(self, rhs: BIndex) -> Self::Output {
+        Self::from(self.0.shl(rhs.into_inner()))
+    }
+}
+
+impl ShlAssign<BIndex> for BitsetSize {
+    #[inline(always)]
+    fn shl_assign(&mut self, rhs: BIndex) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl Shr<BIndex> for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shr(self, rhs: BIndex) -> Self::Output {
+        Self::from(self.0.shr(rhs.into_inner()))
+    }
+}
+
+impl ShrAssign<BIndex> for BitsetSize {
+    #[inline(always)]
+    fn shr_assign(&mut self, rhs: BIndex) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl BitAnd<BIndex> for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: BIndex) -> Self::Output {
+        Self(self.0 & Self::from(rhs).0)
+    }
+}
+
+impl BitAndAssign<BIndex> for BitsetSize {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: BIndex) {
+        self.0 &= Self::from(rhs).0;
+    }
+}
+
+impl BitOr<BIndex> for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: BIndex) -> Self::Output {
+        Self(self.0 | Self::from(rhs).0)
+    }
+}
+
+impl BitOrAssign<BIndex> for BitsetSize {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: BIndex) {
+        self.0 |= Self::from(rhs).0;
+    }
+}
+
+impl BitXor<BIndex> for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: BIndex) -> Self::Output {
+        Self(self.0 ^ Self::from(rhs).0)
+    }
+}
+
+impl BitXorAssign<BIndex> for BitsetSize {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: BIndex) {
+        self.0 ^= Self::from(rhs).0;
+    }
+}
+
+impl Sub<BIndex> for BitsetSize {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: BIndex) -> Self::Output {
+        Self(self.0 & !Self::from(rhs).0)
+    }
+}
+
+impl SubAssign<BIndex> for BitsetSize {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: BIndex) {
+        self.0 &= !Self::from(rhs).0;
+    }
+}
+
+impl FromIterator<Bit> for BitsetSize {
+    fn from_iter<T: IntoIterator<Item = Bit>>(iter: T) -> Self {
+        iter.into_iter()
+            .take(BITS)
+            .enumerate()
+            .fold(Self::NONE, |acc, (i, bit)| {
+                Self(acc.0 | Inner::from(bool::from(bit)) << i)
+            })
+    }
+}
+
+impl FromIterator<bool> for BitsetSize {
+    #[inline(always)]
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        iter.into_iter().map(Bit::from).collect()
+    }
+}
+
+impl Debug for BitsetSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BitsetSize({:#0width$b})", self.0, width = BITS + 2)
+    }
+}
+
+impl Display for BitsetSize {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:0width$b}", self.0, width = BITS)
+    }
+}
+
+impl Binary for BitsetSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#0width$b}", self.0, width = BITS + 2)
+    }
+}
+
+impl Octal for BitsetSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#0width$o}", self.0, width = BITS.div_ceil(3) + 2)
+    }
+}
+
+impl UpperHex for BitsetSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#0width$X}", self.0, width = BITS / 4 + 2)
+    }
+}
+
+impl LowerHex for BitsetSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#0width$x}", self.0, width = BITS / 4 + 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::{bit::Bit::*, prelude::Bitset};
+
+    use super::*;
+    type Tested = BitsetSize;
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    #[test]
+    fn construction() -> TestResult {
+        let bitset = Tested::NONE
+            .clone()
+            .replace(0.try_into()?, One)
+            .set(1.try_into()?)
+            .unset(0.try_into()?)
+            .build();
+
+        assert_eq!(bitset, 0b00000010.into());
+        assert_eq!(Tested::BYTE_SIZE, BYTES);
+        Ok(())
+    }
+
+    #[test]
+    fn conversion_from_index() -> TestResult {
+        assert_eq!(Tested::from(Index::<Tested>::MIN).0, 1);
+        assert_eq!(Tested::from(Index::<Tested>::MAX).0, 1 << (BITS - 1));
+        assert!(Index::<Tested>::try_from(BITS).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn conversion_from_sized() -> TestResult {
+        assert_eq!(Tested::from(Bitset8::new(0b1010)).0, 0b1010);
+        assert_eq!(Tested::from(Bitset32::new(u32::MAX)).0, u32::MAX as usize);
+        assert_eq!(Tested::try_from(Bitset128::new(1 << 20))?.0, 1 << 20);
+        assert!(Tested::try_from(Bitset128::new(1 << 100)).is_err());
+        assert_eq!(Tested::from(Byteset::<BYTES>::ALL), Tested::ALL);
+        Ok(())
+    }
+
+    #[test]
+    fn conversion_to_sized() -> TestResult {
+        let bitset = Tested::new(0b1010);
+
+        assert_eq!(Bitset8::try_from(bitset)?, Bitset8::new(0b1010));
+        assert_eq!(Bitset16::try_from(bitset)?, Bitset16::new(0b1010));
+        assert!(Bitset8::try_from(Tested::new(1 << 8)).is_err());
+        assert!(Bitset16::try_from(Tested::ALL).is_err());
+        assert_eq!(Bitset64::from(Tested::ALL).count_ones(), BITS);
+        assert_eq!(Bitset128::from(Tested::ALL).count_ones(), BITS);
+        Ok(())
+    }
+
+    #[test]
+    fn shl() -> TestResult {
+        let bitset: Tested = 0b1.into();
+
+        assert_eq!(
+            bitset << Index::try_from(BITS - 1)?,
+            Tested::new(1 << (BITS - 1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn shr() -> TestResult {
+        let bitset = Tested::new(1 << (BITS - 1));
+
+        assert_eq!(bitset >> Index::try_from(BITS - 1)?, Tested::new(1));
+        Ok(())
+    }
+
+    #[test]
+    fn operators() -> TestResult {
+        let a: Tested = 0b1100.into();
+        let b: Tested = 0b1010.into();
+
+        assert_eq!(a & b, 0b1000.into());
+        assert_eq!(a | b, 0b1110.into());
+        assert_eq!(a ^ b, 0b0110.into());
+        assert_eq!(!Tested::NONE, Tested::ALL);
+        assert_eq!(a | Index::<Tested>::try_from(0)?, 0b1101.into());
+        Ok(())
+    }
+
+    #[test]
+    fn ones() -> TestResult {
+        let bitset = Tested::new(0b101);
+        let mut iter = bitset.ones();
+
+        assert_eq!(iter.next(), Some(0.try_into()?));
+        assert_eq!(iter.next(), Some(2.try_into()?));
+        assert_eq!(iter.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn formatting() {
+        let bitset = Tested::new(0xF);
+
+        assert_eq!(format!("{bitset}").len(), BITS);
+        assert_eq!(
+            format!("{bitset:x}"),
+            format!("0x{}f", "0".repeat(BITS / 4 - 1))
+        );
+        assert_eq!(format!("{bitset:?}"), format!("BitsetSize(0b{bitset})"));
+    }
+
+    #[test]
+    fn test_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Tested>();
+    }
+
+    #[test]
+    fn test_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Tested>();
+    }
+}
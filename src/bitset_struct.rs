@@ -0,0 +1,82 @@
+//! Module containing [`BitsetStruct`], trait for structs, fields of which are packed into bit ranges of a [`Bitset`].
+//!
+//! Enable feature `"derive"` to derive it with `#[derive(BitsetStruct)]`.
+
+use crate::{bitset::Bitset, error::ConvResult};
+
+#[cfg(feature = "derive")]
+pub use bitworks_derive::BitsetStruct;
+
+/// Trait for structs, fields of which are packed into consecutive bit ranges of a [`Bitset`],
+/// like fields of hardware registers and protocol headers.
+///
+/// # Deriving
+/// With feature `"derive"`, `#[derive(BitsetStruct)]` implements the trait for structs with named fields,
+/// packed into the `Bitset`, chosen with `#[bitset_struct(bitset = Bitset16)]`,
+/// starting from bit 0, in order of declaration.
+/// Width of each field is set with `#[bits(N)]`, up to 64 bits; `bool` fields default to 1 bit.
+/// Field types have to implement `Into<u64>` and `TryFrom<u64>`, or be `bool`.
+///
+/// Deriving also generates `get_<field>` and `set_<field>` associated functions,
+/// reading and writing a single field of the packed `Bitset`.
+/// It fails to compile, if the fields don't fit into the `Bitset`, or a field is wider than its type.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{bitset_struct::BitsetStruct, error::ConvResult, prelude::*};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Header {
+///     version: u8,
+///     urgent: bool,
+/// }
+///
+/// impl BitsetStruct for Header {
+///     type Bitset = Bitset8;
+///     const WIDTH: usize = 5;
+///
+///     fn from_bits(bits: &Bitset8) -> ConvResult<Self> {
+///         Ok(Self {
+///             version: bits.extract_bits(0, 4)?,
+///             urgent: bits.extract_bits::<u8>(4, 1)? != 0,
+///         })
+///     }
+///
+///     fn to_bits(&self) -> ConvResult<Bitset8> {
+///         let mut bits = Bitset8::NONE;
+///         bits.insert_bits(0, 4, self.version)?
+///             .insert_bits(4, 1, self.urgent)?;
+///         Ok(bits)
+///     }
+/// }
+///
+/// let header = Header { version: 6, urgent: true };
+///
+/// assert_eq!(header.to_bits()?, Bitset8::new(0b10110));
+/// assert_eq!(Header::from_bits(&Bitset8::new(0b10110))?, header);
+/// assert!(Header { version: 16, urgent: false }.to_bits().is_err());
+/// #   Ok(())
+/// # }
+/// ```
+pub trait BitsetStruct: Sized {
+    /// `Bitset`, fields are packed into.
+    type Bitset: Bitset;
+
+    /// Total width of the fields in bits.
+    const WIDTH: usize;
+
+    /// Unpacks the struct from `bits`.
+    ///
+    /// # Errors
+    /// Returns [`ConvError`][crate::error::ConvError], if bits of a field don't represent a valid value of its type.
+    fn from_bits(bits: &Self::Bitset) -> ConvResult<Self>;
+
+    /// Packs the struct into `Bitset`.
+    ///
+    /// # Errors
+    /// Returns [`ConvError`][crate::error::ConvError], if value of a field doesn't fit into its width.
+    fn to_bits(&self) -> ConvResult<Self::Bitset>;
+}
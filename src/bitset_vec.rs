@@ -0,0 +1,594 @@
+//! Module containing [`BitsetVec`], growable bitset of runtime size.
+
+use crate::{
+    bit::Bit,
+    bitset::Bitset,
+    iter::{find_first, find_last},
+};
+use std::{
+    fmt::{Binary, Debug, Display},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+// Length of a word in bits.
+const WORD_BITS: usize = 64;
+
+/// Growable bitset of runtime size, packing bits into words, like a packed [`Vec<bool>`](Vec).
+///
+/// Mirrors the [`Bitset`] API where possible, but as its size is only known at runtime,
+/// bits are indexed with [`usize`], and out of bounds accesses panic.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::prelude::*;
+///
+/// let mut bitset = BitsetVec::new();
+/// bitset.push(One);
+/// bitset.push(Zero);
+/// bitset.resize(100, One);
+///
+/// assert_eq!(bitset.len(), 100);
+/// assert_eq!(bitset.count_ones(), 99);
+/// assert_eq!(bitset.zeros().collect::<Vec<_>>(), [1]);
+/// assert_eq!(bitset.pop(), Some(One));
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BitsetVec {
+    // Bits past `len` are always not set.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitsetVec {
+    /// Constructs a new empty value of `BitsetVec`.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            words: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Constructs a new value of `BitsetVec` of `len` bits, all set to `value`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = BitsetVec::with_len(70, One);
+    ///
+    /// assert_eq!(bitset.count_ones(), 70);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn with_len(len: usize, value: Bit) -> Self {
+        let mut bitset = Self::new();
+        bitset.resize(len, value);
+        bitset
+    }
+
+    /// Constructs a new value of `BitsetVec` with the bits of `bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = BitsetVec::from_bitset(&Bitset8::new(0b00010100));
+    ///
+    /// assert_eq!(bitset.len(), 8);
+    /// assert_eq!(bitset.ones().collect::<Vec<_>>(), [2, 4]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn from_bitset<T: Bitset>(bitset: &T) -> Self {
+        let len = T::BYTE_SIZE * 8;
+        Self {
+            words: (0..len.div_ceil(WORD_BITS))
+                .map(|w| bitset._word(w))
+                .collect(),
+            len,
+        }
+    }
+
+    /// Returns count of bits in `BitsetVec`.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns [`true`], if `BitsetVec` has no bits, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns count of set bits.
+    #[inline(always)]
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns count of not set bits.
+    #[inline(always)]
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    /// Returns [`true`], if any of the bits are set, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+
+    /// Returns [`true`], if none of the bits are set, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn none(&self) -> bool {
+        !self.any()
+    }
+
+    /// Returns the [`Bit`] at `index`, or [`None`], if `index` is out of bounds.
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<Bit> {
+        (index < self.len)
+            .then(|| Bit::from(self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 != 0))
+    }
+
+    /// Returns the [`Bit`] at `index`.
+    ///
+    /// # Panics
+    /// Panics, if `index` is out of bounds.
+    #[inline(always)]
+    pub fn bit(&self, index: usize) -> Bit {
+        self.get(index).expect("index should be in bounds")
+    }
+
+    /// Sets bit at `index` to `value`. Returns a mutable reference to `self`.
+    ///
+    /// # Panics
+    /// Panics, if `index` is out of bounds.
+    pub fn replace(&mut self, index: usize, value: Bit) -> &mut Self {
+        assert!(index < self.len, "index should be in bounds");
+        let mask = 1 << (index % WORD_BITS);
+        match value {
+            Bit::One => self.words[index / WORD_BITS] |= mask,
+            Bit::Zero => self.words[index / WORD_BITS] &= !mask,
+        }
+        self
+    }
+
+    /// Sets bit at `index`. Returns a mutable reference to `self`.
+    ///
+    /// # Panics
+    /// Panics, if `index` is out of bounds.
+    #[inline(always)]
+    pub fn set(&mut self, index: usize) -> &mut Self {
+        self.replace(index, Bit::One)
+    }
+
+    /// Unsets bit at `index`. Returns a mutable reference to `self`.
+    ///
+    /// # Panics
+    /// Panics, if `index` is out of bounds.
+    #[inline(always)]
+    pub fn unset(&mut self, index: usize) -> &mut Self {
+        self.replace(index, Bit::Zero)
+    }
+
+    /// Flips bit at `index`. Returns a mutable reference to `self`.
+    ///
+    /// # Panics
+    /// Panics, if `index` is out of bounds.
+    #[inline(always)]
+    pub fn flip(&mut self, index: usize) -> &mut Self {
+        let bit = self.bit(index);
+        self.replace(index, !bit)
+    }
+
+    /// Appends `value` to the end of `BitsetVec`.
+    pub fn push(&mut self, value: Bit) {
+        if self.len.is_multiple_of(WORD_BITS) {
+            self.words.push(0);
+        }
+        self.len += 1;
+        self.replace(self.len - 1, value);
+    }
+
+    /// Removes the last bit of `BitsetVec` and returns it, or [`None`], if `BitsetVec` is empty.
+    pub fn pop(&mut self) -> Option<Bit> {
+        let bit = self.get(self.len.checked_sub(1)?)?;
+        self.truncate(self.len - 1);
+        Some(bit)
+    }
+
+    /// Resizes `BitsetVec` to `len` bits, filling new bits with `value`.
+    pub fn resize(&mut self, len: usize, value: Bit) {
+        if len <= self.len {
+            self.truncate(len);
+            return;
+        }
+        let old_len = self.len;
+        self.words.resize(len.div_ceil(WORD_BITS), 0);
+        self.len = len;
+        if value == Bit::One {
+            for index in old_len..len {
+                self.words[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+            }
+        }
+    }
+
+    /// Shortens `BitsetVec` to `len` bits. Does nothing, if `len` is not less than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        self.len = len;
+        self.words.truncate(len.div_ceil(WORD_BITS));
+        self.clear_tail();
+    }
+
+    /// Removes all bits of `BitsetVec`.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+
+    /// Returns iterator over [`Bit`]s of `BitsetVec`.
+    #[inline(always)]
+    pub fn bits(&self) -> impl DoubleEndedIterator<Item = Bit> + ExactSizeIterator + '_ {
+        (0..self.len).map(|i| self.bit(i))
+    }
+
+    /// Returns iterator over indeces of the set bits of `BitsetVec`.
+    ///
+    /// Skips over whole words of not set bits.
+    #[inline(always)]
+    pub fn ones(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        Positions::new(self, |w| w)
+    }
+
+    /// Returns iterator over indeces of the not set bits of `BitsetVec`.
+    ///
+    /// Skips over whole words of set bits.
+    #[inline(always)]
+    pub fn zeros(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        Positions::new(self, |w| !w)
+    }
+
+    /// Returns [`true`], if all set bits of `other` are also set in `self`, and [`false`] otherwise.
+    ///
+    /// # Panics
+    /// Panics, if lengths of `self` and `other` differ.
+    pub fn includes(&self, other: &Self) -> bool {
+        self.assert_same_len(other);
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(a, b)| a & b == *b)
+    }
+
+    /// Returns [`true`], if `self` shares any set bits with `other`, and [`false`] otherwise.
+    ///
+    /// # Panics
+    /// Panics, if lengths of `self` and `other` differ.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.assert_same_len(other);
+        self.words.iter().zip(&other.words).any(|(a, b)| a & b != 0)
+    }
+
+    /// Returns Set complement (`self′`) of `BitsetVec`.
+    pub fn complement(mut self) -> Self {
+        self.words.iter_mut().for_each(|w| *w = !*w);
+        self.clear_tail();
+        self
+    }
+
+    /// Returns Set union (`self ∪ other`) of two `BitsetVec`s.
+    ///
+    /// # Panics
+    /// Panics, if lengths of `self` and `other` differ.
+    #[inline(always)]
+    pub fn union(self, other: &Self) -> Self {
+        self.combine_words(other, |a, b| a | b)
+    }
+
+    /// Returns Set intersection (`self ∩ other`) of two `BitsetVec`s.
+    ///
+    /// # Panics
+    /// Panics, if lengths of `self` and `other` differ.
+    #[inline(always)]
+    pub fn intersection(self, other: &Self) -> Self {
+        self.combine_words(other, |a, b| a & b)
+    }
+
+    /// Returns Set difference (`self \ other`) of two `BitsetVec`s.
+    ///
+    /// # Panics
+    /// Panics, if lengths of `self` and `other` differ.
+    #[inline(always)]
+    pub fn difference(self, other: &Self) -> Self {
+        self.combine_words(other, |a, b| a & !b)
+    }
+
+    /// Returns Set symmetric difference (`self Δ other`) of two `BitsetVec`s.
+    ///
+    /// # Panics
+    /// Panics, if lengths of `self` and `other` differ.
+    #[inline(always)]
+    pub fn sym_difference(self, other: &Self) -> Self {
+        self.combine_words(other, |a, b| a ^ b)
+    }
+
+    // Panics, if lengths of `self` and `other` differ.
+    #[inline(always)]
+    fn assert_same_len(&self, other: &Self) {
+        assert_eq!(self.len, other.len, "lengths should be equal");
+    }
+
+    // Combines words of `self` and `other` with `op`.
+    fn combine_words(mut self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        self.assert_same_len(other);
+        self.words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(a, &b)| *a = op(*a, b));
+        self
+    }
+
+    // Unsets bits past `len` in the last word.
+    fn clear_tail(&mut self) {
+        if !self.len.is_multiple_of(WORD_BITS) {
+            if let Some(last) = self.words.last_mut() {
+                *last &= !(!0 << (self.len % WORD_BITS));
+            }
+        }
+    }
+}
+
+// Iterator over positions of the bits in `BitsetVec`, for which `map` of their word returns set bit.
+struct Positions<'a, F> {
+    bitset: &'a BitsetVec,
+    map: F,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, F> Positions<'a, F>
+where
+    F: Fn(u64) -> u64,
+{
+    fn new(bitset: &'a BitsetVec, map: F) -> Self {
+        Self {
+            bitset,
+            map,
+            front: 0,
+            back: bitset.len,
+        }
+    }
+}
+
+impl<'a, F> Iterator for Positions<'a, F>
+where
+    F: Fn(u64) -> u64,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match find_first(self.front, self.back, |w| (self.map)(self.bitset.words[w])) {
+            Some(i) => {
+                self.front = i + 1;
+                Some(i)
+            }
+            None => {
+                self.front = self.back;
+                None
+            }
+        }
+    }
+}
+
+impl<'a, F> DoubleEndedIterator for Positions<'a, F>
+where
+    F: Fn(u64) -> u64,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match find_last(self.front, self.back, |w| (self.map)(self.bitset.words[w])) {
+            Some(i) => {
+                self.back = i;
+                Some(i)
+            }
+            None => {
+                self.back = self.front;
+                None
+            }
+        }
+    }
+}
+
+impl FromIterator<Bit> for BitsetVec {
+    fn from_iter<I: IntoIterator<Item = Bit>>(iter: I) -> Self {
+        let mut bitset = Self::new();
+        bitset.extend(iter);
+        bitset
+    }
+}
+
+impl Extend<Bit> for BitsetVec {
+    fn extend<I: IntoIterator<Item = Bit>>(&mut self, iter: I) {
+        for bit in iter {
+            self.push(bit);
+        }
+    }
+}
+
+impl Not for BitsetVec {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl BitAnd for BitsetVec {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(&rhs)
+    }
+}
+
+impl BitAndAssign<&BitsetVec> for BitsetVec {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: &Self) {
+        *self = std::mem::take(self).intersection(rhs);
+    }
+}
+
+impl BitOr for BitsetVec {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(&rhs)
+    }
+}
+
+impl BitOrAssign<&BitsetVec> for BitsetVec {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: &Self) {
+        *self = std::mem::take(self).union(rhs);
+    }
+}
+
+impl BitXor for BitsetVec {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.sym_difference(&rhs)
+    }
+}
+
+impl BitXorAssign<&BitsetVec> for BitsetVec {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: &Self) {
+        *self = std::mem::take(self).sym_difference(rhs);
+    }
+}
+
+impl Debug for BitsetVec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BitsetVec({self:b})")
+    }
+}
+
+impl Display for BitsetVec {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Binary::fmt(self, f)
+    }
+}
+
+impl Binary for BitsetVec {
+    // Bits are printed from the highest index to the lowest, like integers.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s: String = self
+            .bits()
+            .rev()
+            .map(|b| char::from(b'0' + u8::from(bool::from(b))))
+            .collect();
+        f.pad_integral(true, "0b", &s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bit::Bit::*, prelude::Bitset16};
+
+    #[test]
+    fn push_pop_resize() {
+        let mut bitset = BitsetVec::new();
+        assert_eq!(bitset.pop(), None);
+
+        for i in 0..130 {
+            bitset.push(Bit::from(i % 3 == 0));
+        }
+        assert_eq!(bitset.len(), 130);
+        assert_eq!(bitset.count_ones(), 44);
+        assert_eq!(bitset.pop(), Some(One));
+        assert_eq!(bitset.pop(), Some(Zero));
+
+        bitset.resize(200, One);
+        assert_eq!(bitset.count_ones(), 43 + 72);
+        bitset.truncate(65);
+        assert_eq!(bitset.count_ones(), 22);
+        assert_eq!(bitset.ones().next_back(), Some(63));
+
+        bitset.clear();
+        assert!(bitset.is_empty());
+        assert!(bitset.none());
+    }
+
+    #[test]
+    fn access() {
+        let mut bitset = BitsetVec::with_len(70, Zero);
+        bitset.set(0).set(69).flip(3).flip(0);
+
+        assert_eq!(bitset.get(70), None);
+        assert_eq!(bitset.bit(69), One);
+        assert_eq!(bitset.ones().collect::<Vec<_>>(), [3, 69]);
+        assert_eq!(bitset.zeros().next_back(), Some(68));
+        assert_eq!(bitset.count_zeros(), 68);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        BitsetVec::with_len(3, Zero).set(3);
+    }
+
+    #[test]
+    fn operations() {
+        let a: BitsetVec = [One, One, Zero, Zero].into_iter().collect();
+        let b: BitsetVec = [One, Zero, One, Zero].into_iter().collect();
+
+        assert_eq!((a.clone() & b.clone()).ones().collect::<Vec<_>>(), [0]);
+        assert_eq!(
+            (a.clone() | b.clone()).ones().collect::<Vec<_>>(),
+            [0, 1, 2]
+        );
+        assert_eq!((a.clone() ^ b.clone()).ones().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(a.clone().difference(&b).ones().collect::<Vec<_>>(), [1]);
+        assert_eq!((!a.clone()).ones().collect::<Vec<_>>(), [2, 3]);
+        assert!(a.intersects(&b));
+        assert!(!a.includes(&b));
+
+        let mut c = a.clone();
+        c |= &b;
+        assert!(c.includes(&a) && c.includes(&b));
+    }
+
+    #[test]
+    fn conversions() {
+        let bitset = BitsetVec::from_bitset(&Bitset16::new(0b1000_0000_0000_0101));
+
+        assert_eq!(bitset.len(), 16);
+        assert_eq!(format!("{bitset}"), "1000000000000101");
+        assert_eq!(format!("{bitset:?}"), "BitsetVec(1000000000000101)");
+        assert_eq!(format!("{:#b}", BitsetVec::with_len(3, One)), "0b111");
+    }
+}
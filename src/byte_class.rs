@@ -0,0 +1,270 @@
+//! Module containing [`ByteClass`], set of [`u8`] values.
+
+use crate::{
+    bitset::Bitset,
+    prelude::{Byteset, Index},
+};
+use std::{
+    fmt::Debug,
+    ops::{BitAnd, BitOr, Not},
+};
+
+/// Set of [`u8`] values, backed by [`Byteset<32>`][Byteset], with a bit for each of the 256 values.
+///
+/// Classic "byte class" structure of lexers and matchers.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::byte_class::ByteClass;
+///
+/// const DIGITS: ByteClass = ByteClass::from_bytes(b"0123456789");
+///
+/// let mut class = DIGITS;
+/// class.insert(b'.');
+///
+/// assert!(class.contains(b'7'));
+/// assert!(class.contains(b'.'));
+/// assert!(!class.contains(b'a'));
+/// assert_eq!(class.len(), 11);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ByteClass(Byteset<32>);
+
+impl ByteClass {
+    /// Empty `ByteClass`.
+    pub const NONE: Self = Self(Byteset::new([0; 32]));
+    /// `ByteClass` containing all of the values.
+    pub const ALL: Self = Self(Byteset::new([u8::MAX; 32]));
+
+    /// Constructs a new empty value of `ByteClass`.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::NONE
+    }
+
+    /// Constructs a new value of `ByteClass`, containing all of the `bytes`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::byte_class::ByteClass;
+    ///
+    /// const HEX: ByteClass = ByteClass::from_bytes(b"0123456789abcdefABCDEF");
+    ///
+    /// assert!(HEX.contains(b'f'));
+    /// assert!(!HEX.contains(b'g'));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub const fn from_bytes(bytes: &[u8]) -> Self {
+        let mut inner = [0; 32];
+        let mut i = 0;
+        while i < bytes.len() {
+            inner[(bytes[i] / 8) as usize] |= 1 << (bytes[i] % 8);
+            i += 1;
+        }
+        Self(Byteset::new(inner))
+    }
+
+    /// Constructs a new value of `ByteClass`, containing all of the values in `start..=end`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::byte_class::ByteClass;
+    ///
+    /// const LOWERCASE: ByteClass = ByteClass::from_range(b'a', b'z');
+    ///
+    /// assert_eq!(LOWERCASE.len(), 26);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub const fn from_range(start: u8, end: u8) -> Self {
+        let mut inner = [0; 32];
+        let mut byte = start as usize;
+        while byte <= end as usize {
+            inner[byte / 8] |= 1 << (byte % 8);
+            byte += 1;
+        }
+        Self(Byteset::new(inner))
+    }
+
+    /// Returns [`true`], if `ByteClass` contains `byte`, and [`false`] otherwise.
+    #[inline(always)]
+    pub const fn contains(&self, byte: u8) -> bool {
+        self.0.into_inner()[(byte / 8) as usize] & (1 << (byte % 8)) != 0
+    }
+
+    /// Inserts `byte` into `ByteClass`.
+    /// Returns [`true`], if `byte` was not contained before, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn insert(&mut self, byte: u8) -> bool {
+        let contained = self.contains(byte);
+        self.0.set(Index::from_usize(byte as usize));
+        !contained
+    }
+
+    /// Removes `byte` from `ByteClass`.
+    /// Returns [`true`], if `byte` was contained before, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn remove(&mut self, byte: u8) -> bool {
+        let contained = self.contains(byte);
+        self.0.unset(Index::from_usize(byte as usize));
+        contained
+    }
+
+    /// Returns count of values in `ByteClass`.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.as_byteset().count_ones()
+    }
+
+    /// Returns [`true`], if `ByteClass` is empty, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.as_byteset().is_empty()
+    }
+
+    /// Returns iterator over values of `ByteClass`, in ascending order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::byte_class::ByteClass;
+    ///
+    /// let class = ByteClass::from_bytes(b"cab");
+    ///
+    /// assert_eq!(class.iter().collect::<Vec<_>>(), b"abc");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
+        self.as_byteset().ones().map(|i| i.into_inner() as u8)
+    }
+
+    /// Returns `ByteClass` as [`Byteset<32>`][Byteset], with bit at [`Index`][crate::index::Index] `b` set for each value `b`.
+    #[inline(always)]
+    pub fn as_byteset(&self) -> &Byteset<32> {
+        &self.0
+    }
+}
+
+impl Default for ByteClass {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl From<Byteset<32>> for ByteClass {
+    fn from(value: Byteset<32>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ByteClass> for Byteset<32> {
+    fn from(value: ByteClass) -> Self {
+        value.0
+    }
+}
+
+impl FromIterator<u8> for ByteClass {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut class = Self::NONE;
+        class.extend(iter);
+        class
+    }
+}
+
+impl Extend<u8> for ByteClass {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for byte in iter {
+            self.insert(byte);
+        }
+    }
+}
+
+impl Not for ByteClass {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+impl BitAnd for ByteClass {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for ByteClass {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl Debug for ByteClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.iter().map(|b| b.escape_ascii().to_string()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_remove() {
+        let mut class = ByteClass::new();
+
+        assert!(class.insert(0));
+        assert!(class.insert(255));
+        assert!(!class.insert(255));
+        assert_eq!(class.iter().collect::<Vec<_>>(), [0, 255]);
+        assert_eq!(class.iter().next_back(), Some(255));
+
+        assert!(class.remove(0));
+        assert!(!class.remove(0));
+        assert_eq!(class.len(), 1);
+        assert!(!class.is_empty());
+    }
+
+    #[test]
+    fn operations() {
+        let letters = ByteClass::from_range(b'a', b'z') | ByteClass::from_range(b'A', b'Z');
+        let hex = ByteClass::from_bytes(b"0123456789abcdefABCDEF");
+
+        assert_eq!((letters & hex).len(), 12);
+        assert_eq!((!letters).len(), 256 - 52);
+        assert_eq!(ByteClass::ALL.len(), 256);
+        assert_eq!(ByteClass::from_range(0, 255), ByteClass::ALL);
+        assert_eq!(b"hello".iter().copied().collect::<ByteClass>().len(), 4);
+        assert_eq!(
+            Byteset::from(ByteClass::from_bytes(&[9])).into_inner()[1],
+            0b10
+        );
+        assert_eq!(
+            format!("{:?}", ByteClass::from_bytes(b"\na")),
+            r#"{"\\n", "a"}"#
+        );
+    }
+}
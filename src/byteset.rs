@@ -2,7 +2,7 @@
 
 use crate::{
     bit::Bit,
-    bitset::{Bitset, LeftAligned},
+    bitset::{shift_bytes_left, shift_bytes_right, Bitset, LeftAligned},
     prelude::Index,
     safety_markers::Size,
 };
@@ -10,16 +10,41 @@ use std::{
     fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
     ops::{
         BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
-        ShrAssign,
+        ShrAssign, Sub, SubAssign,
     },
 };
 
 type Inner<const N: usize> = [u8; N];
 type BIndex<const N: usize> = Index<Byteset<N>>;
 
+// Returns `bytes` in reverse order.
+const fn reversed<const N: usize>(bytes: Inner<N>) -> Inner<N> {
+    let mut result = [0; N];
+    let mut i = 0;
+    while i < N {
+        result[i] = bytes[N - 1 - i];
+        i += 1;
+    }
+    result
+}
+
 /// [`Bitset`] of variable `size`.
 /// `N` is size in bytes of the `Byteset`.
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Binary)
+)]
 #[repr(transparent)]
 pub struct Byteset<const N: usize>(pub(crate) Inner<N>);
 
@@ -64,6 +89,64 @@ impl<const N: usize> Byteset<N> {
     pub const fn into_inner(&self) -> Inner<N> {
         self.0
     }
+
+    /// Returns `Byteset` with the order of its bytes reversed.
+    #[inline(always)]
+    pub const fn swap_bytes(&self) -> Self {
+        Self(reversed(self.0))
+    }
+
+    /// Converts `Byteset` from big endian to `Byteset`'s little endian byte order, reversing its bytes.
+    /// Result doesn't depend on the target's endianness.
+    #[inline(always)]
+    pub const fn from_be(value: Self) -> Self {
+        value.swap_bytes()
+    }
+
+    /// Converts `Byteset` from little endian to `Byteset`'s little endian byte order, which is a no-op.
+    /// Result doesn't depend on the target's endianness.
+    #[inline(always)]
+    pub const fn from_le(value: Self) -> Self {
+        value
+    }
+
+    /// Converts `Byteset` to big endian from `Byteset`'s little endian byte order, reversing its bytes.
+    /// Result doesn't depend on the target's endianness.
+    #[inline(always)]
+    pub const fn to_be(&self) -> Self {
+        self.swap_bytes()
+    }
+
+    /// Converts `Byteset` to little endian from `Byteset`'s little endian byte order, which is a no-op.
+    /// Result doesn't depend on the target's endianness.
+    #[inline(always)]
+    pub const fn to_le(&self) -> Self {
+        Self(self.0)
+    }
+
+    /// Constructs a new value of `Byteset` from its representation as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: Inner<N>) -> Self {
+        Self(reversed(bytes))
+    }
+
+    /// Constructs a new value of `Byteset` from its representation as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: Inner<N>) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the memory representation of `Byteset` as a byte array in big endian byte order.
+    #[inline(always)]
+    pub const fn to_be_bytes(&self) -> Inner<N> {
+        reversed(self.0)
+    }
+
+    /// Returns the memory representation of `Byteset` as a byte array in little endian byte order.
+    #[inline(always)]
+    pub const fn to_le_bytes(&self) -> Inner<N> {
+        self.0
+    }
 }
 
 unsafe impl<const N: usize> LeftAligned for Byteset<N> {
@@ -79,6 +162,13 @@ unsafe impl<const N: usize> LeftAligned for Byteset<N> {
     }
 }
 
+impl<const N: usize> Default for Byteset<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
 impl<const N: usize> From<Inner<N>> for Byteset<N> {
     #[inline(always)]
     fn from(value: Inner<N>) -> Self {
@@ -169,6 +259,23 @@ impl<const N: usize> BitXorAssign for Byteset<N> {
     }
 }
 
+impl<const N: usize> Sub for Byteset<N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign for Byteset<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (i, chunk) in self.0.iter_mut().enumerate() {
+            *chunk &= !rhs.0[i];
+        }
+    }
+}
+
 impl<const N: usize> Shl<BIndex<N>> for Byteset<N> {
     type Output = Self;
 
@@ -179,27 +286,7 @@ impl<const N: usize> Shl<BIndex<N>> for Byteset<N> {
 
 impl<const N: usize> ShlAssign<BIndex<N>> for Byteset<N> {
     fn shl_assign(&mut self, rhs: BIndex<N>) {
-        let byte_shift = rhs.byte_index();
-        let bit_shift = rhs.bit_index();
-
-        let ptr = self as *mut _ as *mut u8;
-
-        if byte_shift > 0 {
-            unsafe {
-                std::ptr::copy(ptr.add(byte_shift), ptr, Self::BYTE_SIZE - byte_shift);
-                std::ptr::write_bytes(ptr.add(Self::BYTE_SIZE - byte_shift), 0, byte_shift);
-            }
-        }
-
-        if bit_shift > 0 {
-            let bytes: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(ptr, Self::BYTE_SIZE) };
-            let mut carry = 0;
-            for byte in bytes.iter_mut().rev() {
-                let shifted = *byte << bit_shift | carry;
-                carry = *byte >> (8 - bit_shift);
-                *byte = shifted;
-            }
-        }
+        shift_bytes_left(&mut self.0, rhs.into_inner());
     }
 }
 
@@ -213,27 +300,7 @@ impl<const N: usize> Shr<BIndex<N>> for Byteset<N> {
 
 impl<const N: usize> ShrAssign<BIndex<N>> for Byteset<N> {
     fn shr_assign(&mut self, rhs: BIndex<N>) {
-        let byte_shift = rhs.byte_index();
-        let bit_shift = rhs.bit_index();
-
-        let ptr = self as *mut _ as *mut u8;
-
-        if byte_shift > 0 {
-            unsafe {
-                std::ptr::copy(ptr, ptr.add(byte_shift), Self::BYTE_SIZE - byte_shift);
-                std::ptr::write_bytes(ptr, 0, byte_shift);
-            }
-        }
-
-        if bit_shift > 0 {
-            let bytes: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(ptr, Self::BYTE_SIZE) };
-            let mut carry = 0;
-            for byte in bytes.iter_mut() {
-                let shifted = *byte >> bit_shift | carry;
-                carry = *byte << (8 - bit_shift);
-                *byte = shifted;
-            }
-        }
+        shift_bytes_right(&mut self.0, rhs.into_inner());
     }
 }
 
@@ -285,22 +352,41 @@ impl<const N: usize> BitXorAssign<BIndex<N>> for Byteset<N> {
     }
 }
 
+impl<const N: usize> Sub<BIndex<N>> for Byteset<N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: BIndex<N>) -> Self::Output {
+        self - Self::from(rhs)
+    }
+}
+
+impl<const N: usize> SubAssign<BIndex<N>> for Byteset<N> {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: BIndex<N>) {
+        *self -= Self::from(rhs);
+    }
+}
+
 impl<const N: usize> FromIterator<Bit> for Byteset<N> {
     fn from_iter<T: IntoIterator<Item = Bit>>(iter: T) -> Self {
         iter.into_iter()
             .take(N * 8)
             .enumerate()
-            .filter_map(|(i, bit)| if bool::from(bit) { Some(i) } else { None })
-            .filter_map(|i| BIndex::<N>::try_from(i).ok())
-            .fold(Self::NONE, |mut acc, i| {
-                let byte_index = i.byte_index();
-                let bitmask = i.bitmask();
-                acc.0[byte_index] |= bitmask;
+            .fold(Self::NONE, |mut acc, (i, bit)| {
+                acc.0[i / 8] |= u8::from(bool::from(bit)) << (i % 8);
                 acc
             })
     }
 }
 
+impl<const N: usize> FromIterator<bool> for Byteset<N> {
+    #[inline(always)]
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        iter.into_iter().map(Bit::from).collect()
+    }
+}
+
 impl<const N: usize> Debug for Byteset<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut s = self
@@ -329,7 +415,7 @@ impl<const N: usize> Display for Byteset<N> {
                 }
                 acc
             });
-        if N > 4 && N % 4 > 0 {
+        if N > 4 && !N.is_multiple_of(4) {
             let padding = (4 - N % 4) * 10 + 1;
             s.push_str(&format!("{:>padding$}", "]"));
         } else {
@@ -379,6 +465,24 @@ impl<const N: usize> LowerHex for Byteset<N> {
     }
 }
 
+// SAFETY: Byteset is #[repr(transparent)] over [u8; N], which is Pod for any N.
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Zeroable for Byteset<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for Byteset<N> {}
+
+#[cfg(feature = "rkyv")]
+impl<const N: usize> ArchivedByteset<N> {
+    /// Returns bytes of the archived [`Byteset`] without deserialization.
+    ///
+    /// Byte `i` contains bits at [`Index`]es `8 * i..8 * i + 8`.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &Inner<N> {
+        &self.0
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<const N: usize> serde::Serialize for Byteset<N> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -424,12 +528,29 @@ impl<'de, const N: usize> serde::Deserialize<'de> for Byteset<N> {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl<const N: usize> schemars::JsonSchema for Byteset<N> {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("Byteset{N}").into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "array",
+            "items": generator.subschema_for::<u8>(),
+            "minItems": N,
+            "maxItems": N,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
 
     use crate::{
         bit::Bit::*,
+        error::{ConvError, ConvTarget},
         prelude::Bitset,
     };
 
@@ -570,32 +691,38 @@ mod tests {
         // 1 bit shift, easy case
         let bitset: Tested1 = [0b00000001].into();
 
-        assert_eq!(bitset << 1.try_into()?, [0b00000010].into());
+        assert_eq!(bitset << Index::try_from(1)?, [0b00000010].into());
 
         let mut bitset: Tested1 = [0b00000001].into();
-        bitset <<= 1.try_into()?;
+        bitset <<= Index::try_from(1)?;
 
         assert_eq!(bitset, [0b00000010].into());
 
         // 7 bit shift, crossing the boundary case
         let bitset: Tested2 = [0b00011011, 0b11101000].into();
 
-        assert_eq!(bitset << 7.try_into()?, [0b11110100, 0b00000000].into());
+        assert_eq!(
+            bitset << Index::try_from(7)?,
+            [0b10000000, 0b00001101].into()
+        );
 
         let mut bitset: Tested2 = [0b00011011, 0b11101000].into();
-        bitset <<= 7.try_into()?;
+        bitset <<= Index::try_from(7)?;
 
-        assert_eq!(bitset, [0b11110100, 0b00000000].into());
+        assert_eq!(bitset, [0b10000000, 0b00001101].into());
 
         // 9 bit shift, bigger, than chunk length
         let bitset: Tested2 = [0b00011011, 0b11101000].into();
 
-        assert_eq!(bitset << 9.try_into()?, [0b11010000, 0b00000000].into());
+        assert_eq!(
+            bitset << Index::try_from(9)?,
+            [0b00000000, 0b00110110].into()
+        );
 
         let mut bitset: Tested2 = [0b00011011, 0b11101000].into();
-        bitset <<= 9.try_into()?;
+        bitset <<= Index::try_from(9)?;
 
-        assert_eq!(bitset, [0b11010000, 0b00000000].into());
+        assert_eq!(bitset, [0b00000000, 0b00110110].into());
         Ok(())
     }
 
@@ -604,32 +731,38 @@ mod tests {
         // 1 bit shift, easy case
         let bitset: Tested1 = [0b00000010].into();
 
-        assert_eq!(bitset >> 1.try_into()?, [0b00000001].into());
+        assert_eq!(bitset >> Index::try_from(1)?, [0b00000001].into());
 
         let mut bitset: Tested1 = [0b00000010].into();
-        bitset >>= 1.try_into()?;
+        bitset >>= Index::try_from(1)?;
 
         assert_eq!(bitset, [0b00000001].into());
 
         // 7 bit shift, crossing the boundary case
-        let bitset: Tested2 = [0b11110100, 0b00000000].into();
+        let bitset: Tested2 = [0b00011011, 0b11101000].into();
 
-        assert_eq!(bitset >> 7.try_into()?, [0b00000001, 0b11101000].into());
+        assert_eq!(
+            bitset >> Index::try_from(7)?,
+            [0b11010000, 0b00000001].into()
+        );
 
-        let mut bitset: Tested2 = [0b11110100, 0b00000000].into();
-        bitset >>= 7.try_into()?;
+        let mut bitset: Tested2 = [0b00011011, 0b11101000].into();
+        bitset >>= Index::try_from(7)?;
 
-        assert_eq!(bitset, [0b00000001, 0b11101000].into());
+        assert_eq!(bitset, [0b11010000, 0b00000001].into());
 
         // 9 bit shift, bigger, than chunk length
-        let bitset: Tested2 = [0b11010000, 0b00000000].into();
+        let bitset: Tested2 = [0b00011011, 0b11101000].into();
 
-        assert_eq!(bitset >> 9.try_into()?, [0b00000000, 0b01101000].into());
+        assert_eq!(
+            bitset >> Index::try_from(9)?,
+            [0b01110100, 0b00000000].into()
+        );
 
-        let mut bitset: Tested2 = [0b11010000, 0b00000000].into();
-        bitset >>= 9.try_into()?;
+        let mut bitset: Tested2 = [0b00011011, 0b11101000].into();
+        bitset >>= Index::try_from(9)?;
 
-        assert_eq!(bitset, [0b00000000, 0b01101000].into());
+        assert_eq!(bitset, [0b01110100, 0b00000000].into());
         Ok(())
     }
 
@@ -682,6 +815,21 @@ mod tests {
         assert_eq!(a, [0b00111100].into());
     }
 
+    #[test]
+    fn sub() {
+        let a: Tested1 = [0b11110000].into();
+        let b: Tested1 = [0b11001100].into();
+
+        assert_eq!(a - b, [0b00110000].into());
+        assert_eq!(a - Index::from_usize(4), [0b11100000].into());
+
+        let mut a: TestedOdd = [0b11110000, 0xFF, 0x0F].into();
+        a -= TestedOdd::new([0b11001100, 0xF0, 0xFF]);
+        a -= Index::from_usize(12);
+
+        assert_eq!(a, [0b00110000, 0x0F & !0b10000, 0].into());
+    }
+
     #[test]
     fn complement() {
         let a: Tested1 = [0b11110000].into();
@@ -768,7 +916,6 @@ mod tests {
         assert_eq!(iter.next().as_deref(), Some(&One));
         assert_eq!(iter.next().as_deref(), Some(&One));
         assert_eq!(iter.next(), None);
-        drop(iter);
 
         for mut bit in bitset.bits_mut() {
             *bit = !*bit;
@@ -805,6 +952,158 @@ mod tests {
             .collect();
 
         assert_eq!(bitset, [0b10101010].into());
+
+        let bitset: TestedOdd = [true, false, true].into_iter().collect();
+        assert_eq!(bitset, [0b00000101, 0, 0].into());
+    }
+
+    #[test]
+    fn conversions() -> TestResult {
+        use crate::prelude::{Bitset128, Bitset16, Bitset512, Bitset8, BitsetSize};
+
+        let bitset = Tested2::new([0x34, 0x12]);
+        assert_eq!(Bitset16::from(bitset), Bitset16::new(0x1234));
+        assert_eq!(Tested2::from(Bitset16::new(0x1234)), bitset);
+
+        let bitset = Tested16::new(std::array::from_fn(|i| i as u8));
+        assert_eq!(Tested16::from(Bitset128::from(bitset)), bitset);
+        let bitset = Byteset::<64>::new(std::array::from_fn(|i| i as u8));
+        assert_eq!(Byteset::<64>::from(Bitset512::from(bitset)), bitset);
+        let bitset = BitsetSize::new(usize::MAX - 1);
+        assert_eq!(BitsetSize::from(Byteset::from(bitset)), bitset);
+
+        let bitset = TestedOdd::new([0xFF, 0x01, 0]);
+        assert_eq!(bitset.try_convert::<Bitset16>()?, Bitset16::new(0x01FF));
+        assert_eq!(
+            bitset.try_convert::<Tested16>()?,
+            Tested16::from([0xFF, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            bitset.try_convert::<Bitset8>(),
+            Err(ConvError::new(ConvTarget::Set(9), ConvTarget::Set(8)))
+        );
+        assert_eq!(
+            Bitset512::new([0, 0, 0, 1 << 63, 0, 0, 0, 0]).try_convert::<TestedOdd>(),
+            Err(ConvError::new(ConvTarget::Set(256), ConvTarget::Set(24)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn byte_views() -> TestResult {
+        use crate::{bitset::LeftAligned, prelude::Bitset256};
+
+        let mut bitset = TestedOdd::new([1, 2, 3]);
+        assert_eq!(bitset.as_bytes(), &[1, 2, 3]);
+        bitset.as_bytes_mut()[1] = 0xFF;
+        assert_eq!(bitset, TestedOdd::new([1, 0xFF, 3]));
+        assert_eq!(TestedOdd::from_bytes(&[1, 0xFF, 3])?, bitset);
+        assert_eq!(
+            TestedOdd::from_bytes(&[1, 2, 3, 4]),
+            Err(ConvError::new(ConvTarget::Set(32), ConvTarget::Set(24)))
+        );
+
+        let mut buffer = [0u8; 34];
+        let bitset = Bitset256::new([1, 1 << 127]);
+        buffer[2..].copy_from_slice(bitset.as_bytes());
+        assert_eq!((buffer[2], buffer[33]), (1, 0x80));
+        assert_eq!(Bitset256::from_bytes(&buffer[2..])?, bitset);
+        Ok(())
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_casts() {
+        use crate::prelude::Bitset16;
+
+        let bitsets = [TestedOdd::new([1, 2, 3]), TestedOdd::new([4, 5, 6])];
+        let bytes: &[u8] = bytemuck::cast_slice(&bitsets);
+        assert_eq!(bytes, &[1, 2, 3, 4, 5, 6]);
+
+        let bitsets: &[Bitset16] = bytemuck::cast_slice(&[1u16, 0x8000]);
+        assert_eq!(bitsets, &[Bitset16::new(1), Bitset16::new(0x8000)]);
+        assert_eq!(<TestedOdd as bytemuck::Zeroable>::zeroed(), TestedOdd::NONE);
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy_packet() {
+        use crate::prelude::Bitset16;
+        use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+        #[derive(AsBytes, FromBytes, FromZeroes)]
+        #[repr(C)]
+        struct Header {
+            flags: Bitset16,
+            mask: Tested2,
+        }
+
+        let header = Header::read_from(&[0x01, 0x80, 0xFF, 0x00][..]).unwrap();
+        assert_eq!(header.flags, Bitset16::new(0x8001));
+        assert_eq!(header.mask, Tested2::new([0xFF, 0x00]));
+        assert_eq!(header.as_bytes(), &[0x01, 0x80, 0xFF, 0x00]);
+        assert_eq!(TestedOdd::new_zeroed(), TestedOdd::NONE);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() -> arbitrary::Result<()> {
+        use crate::prelude::{Bitset16, Index8};
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut u = Unstructured::new(&[1, 2, 3, 0x01, 0x80, 0xFF, 0xFF]);
+        assert_eq!(TestedOdd::arbitrary(&mut u)?, TestedOdd::new([1, 2, 3]));
+        assert_eq!(Bitset16::arbitrary(&mut u)?, Bitset16::new(0x8001));
+        for _ in 0..4 {
+            assert!(Index8::arbitrary(&mut u)?.into_inner() < 8);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn rkyv_archive() -> TestResult {
+        use crate::{bitset256::ArchivedBitset256, prelude::Bitset256};
+        use rkyv::rancor::Error;
+
+        let bitset = Byteset::<64>::new(std::array::from_fn(|i| i as u8));
+        let bytes = rkyv::to_bytes::<Error>(&bitset)?;
+        let archived = rkyv::access::<ArchivedByteset<64>, Error>(&bytes)?;
+        assert_eq!(archived.as_bytes(), &bitset.into_inner());
+        assert_eq!(rkyv::deserialize::<_, Error>(archived)?, bitset);
+
+        let bitset = Bitset256::new([1, 1 << 127]);
+        let bytes = rkyv::to_bytes::<Error>(&bitset)?;
+        let archived = rkyv::access::<ArchivedBitset256, Error>(&bytes)?;
+        assert_eq!(rkyv::deserialize::<Bitset256, Error>(archived)?, bitset);
+        Ok(())
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        use crate::prelude::{Bitset16, Bitset512};
+
+        let schema = schemars::schema_for!(TestedOdd);
+        assert_eq!(schema.get("type").unwrap(), "array");
+        assert_eq!(schema.get("maxItems").unwrap(), 3);
+        assert_eq!(schema.get("items").unwrap()["maximum"], 255);
+
+        let schema = schemars::schema_for!(Bitset16);
+        assert_eq!(schema.get("type").unwrap(), "integer");
+        assert_eq!(schema.get("maximum").unwrap(), 65535);
+
+        let schema = schemars::schema_for!(Bitset512);
+        assert_eq!(schema.get("minItems").unwrap(), 8);
+    }
+
+    #[test]
+    fn default_and_copy() {
+        let a = TestedOdd::default();
+        assert_eq!(a, TestedOdd::NONE);
+
+        let b = a;
+        assert_eq!(a | TestedOdd::new([1, 2, 3]), b | TestedOdd::new([1, 2, 3]));
     }
 
     #[test]
@@ -842,6 +1141,23 @@ mod tests {
         assert_eq!(bitset, [0b01010101].into());
     }
 
+    #[test]
+    fn from_iter_bits() {
+        // Bits cross the byte boundary, extra bits are ignored.
+        let bits = [One, Zero, Zero, Zero, Zero, Zero, Zero, One, One, Zero, One];
+        let bitset: Tested1 = bits.into_iter().collect();
+
+        assert_eq!(bitset, [0b10000001].into());
+
+        let bitset: TestedOdd = bits.into_iter().collect();
+
+        assert_eq!(bitset, [0b10000001, 0b00000101, 0].into());
+
+        let bitset: TestedOdd = std::iter::repeat_n(true, 30).collect();
+
+        assert_eq!(bitset, TestedOdd::ALL);
+    }
+
     #[test]
     fn test_send() {
         fn assert_send<T: Send>() {}
@@ -895,6 +1211,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn fast_expand() -> TestResult {
         let bitset1 = Tested1::from([0b00011011]);
         let bitset2: Tested2 = bitset1.expand_optimized();
@@ -972,6 +1289,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn fast_combine() -> TestResult {
         let bitset1 = Tested1::from([0b00011011]);
         let bitset2 = Tested1::from([0b11101000]);
@@ -993,6 +1311,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn fast_split() -> TestResult {
         let bitset1 = Tested2::from([0b00011011, 0b11101000]);
         let (bitset2, bitset3): (Tested1, Tested1) = bitset1.split_optimized();
@@ -1033,4 +1352,1131 @@ mod tests {
         let byteset = Byteset::<8>::from([170; 8]);
         println!("{byteset}");
     }
+
+    #[test]
+    fn indexed_bits() -> TestResult {
+        let bitset: Tested2 = [0b10000000, 0b00000001].into();
+        let mut iter = bitset.indexed_bits().skip(6);
+
+        assert_eq!(iter.next(), Some((6.try_into()?, Zero)));
+        assert_eq!(iter.next(), Some((7.try_into()?, One)));
+        assert_eq!(iter.next(), Some((8.try_into()?, One)));
+        assert_eq!(iter.next(), Some((9.try_into()?, Zero)));
+        assert_eq!(iter.len(), 6);
+
+        let mut iter = bitset.indexed_bits();
+        assert_eq!(iter.next_back(), Some((15.try_into()?, Zero)));
+        assert_eq!(iter.len(), 15);
+        let set: Vec<usize> = iter
+            .filter_map(|(i, bit)| bool::from(bit).then_some(i.into_inner()))
+            .collect();
+        assert_eq!(set, [7, 8]);
+        Ok(())
+    }
+
+    #[test]
+    fn ones_zeros_word_boundaries() {
+        let bitset = Tested16::new(std::array::from_fn(|b| match b % 3 {
+            0 => 0x81,
+            1 => 0,
+            _ => 0xFF,
+        }));
+        let expected: Vec<usize> = (0..128).filter(|&i| bitset._bits(i, 1) == 1).collect();
+
+        let ones: Vec<usize> = bitset.ones().map(usize::from).collect();
+        assert_eq!(ones, expected);
+
+        for split in 0..expected.len() {
+            let mut iter = bitset.ones();
+            let mut front: Vec<usize> = iter.by_ref().take(split).map(usize::from).collect();
+            let back: Vec<usize> = iter.rev().map(usize::from).collect();
+            front.extend(back.into_iter().rev());
+            assert_eq!(front, expected);
+        }
+
+        for n in 0..expected.len() {
+            let mut iter = bitset.ones();
+            assert_eq!(iter.nth(n).map(usize::from), Some(expected[n]));
+            assert_eq!(iter.next().map(usize::from), expected.get(n + 1).copied());
+        }
+
+        let mut iter = bitset.zeros();
+        assert_eq!(iter.next_back().map(usize::from), Some(126));
+        let zeros: Vec<usize> = iter.map(usize::from).collect();
+        let expected: Vec<usize> = (0..126).filter(|&i| bitset._bits(i, 1) == 0).collect();
+        assert_eq!(zeros, expected);
+    }
+
+    #[test]
+    fn ones_sparse() -> TestResult {
+        let mut bitset = Tested16::NONE;
+        bitset.set(3.try_into()?).set(64.try_into()?).set(127.try_into()?);
+
+        let ones: Vec<usize> = bitset.ones().map(usize::from).collect();
+        assert_eq!(ones, [3, 64, 127]);
+
+        let ones: Vec<usize> = bitset.ones().rev().map(usize::from).collect();
+        assert_eq!(ones, [127, 64, 3]);
+
+        let mut iter = bitset.ones();
+        assert_eq!(iter.next(), Some(3.try_into()?));
+        assert_eq!(iter.next_back(), Some(127.try_into()?));
+        assert_eq!(iter.next(), Some(64.try_into()?));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn zeros_dense() -> TestResult {
+        let mut bitset = Tested16::ALL;
+        bitset.unset(0.try_into()?).unset(63.try_into()?).unset(100.try_into()?);
+
+        let zeros: Vec<usize> = bitset.zeros().map(usize::from).collect();
+        assert_eq!(zeros, [0, 63, 100]);
+
+        let zeros: Vec<usize> = bitset.zeros().rev().map(usize::from).collect();
+        assert_eq!(zeros, [100, 63, 0]);
+
+        let bitset = TestedOdd::ALL;
+        assert_eq!(bitset.zeros().next(), None);
+        assert_eq!(bitset.ones().count(), 24);
+        Ok(())
+    }
+
+    #[test]
+    fn interleave() {
+        let x = Tested1::new([0b00000101]);
+        let y = Tested1::new([0b10000011]);
+        let morton: Tested2 = x.interleave(y);
+
+        assert_eq!(morton, Tested2::from_indices([0, 1, 3, 4, 15]));
+        assert_eq!(morton.deinterleave(), (x, y));
+
+        let x = Tested8::from_indices([0, 31, 32, 63]);
+        let y = Tested8::from_indices([1, 40]);
+        let morton: Tested16 = x.interleave(y);
+
+        assert_eq!(morton, Tested16::from_indices([0, 3, 62, 64, 81, 126]));
+        assert_eq!(morton.deinterleave(), (x, y));
+    }
+
+    #[test]
+    fn polynomials() {
+        let a = Tested8::from_indices([0, 63]);
+        let b = Tested8::from_indices([1, 63]);
+        let product: Tested16 = a.clmul(b);
+
+        assert_eq!(product, Tested16::from_indices([1, 63, 64, 126]));
+
+        let a = Tested4::from_indices([0, 5, 9, 15]);
+        let b = Tested4::from_indices([2, 11, 13]);
+        let remainder = Tested4::from_indices([1, 12]);
+        let product: Tested8 = a.clmul(b);
+        let (low, high): (Tested4, Tested4) = product.split();
+
+        assert_eq!(high, Tested4::NONE);
+        assert_eq!(low.poly_div_rem(&b), (a, Tested4::NONE));
+        assert_eq!(
+            low.sym_difference(remainder).poly_div_rem(&b),
+            (a, remainder)
+        );
+        assert_eq!(a.poly_rem(&Tested4::from_indices([0])), Tested4::NONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero polynomial")]
+    fn poly_div_by_zero() {
+        Tested4::ALL.poly_div_rem(&Tested4::NONE);
+    }
+
+    #[test]
+    fn deposit_extract_masked() {
+        let mask = TestedOdd::from_indices([1, 4, 7, 8, 15, 23]);
+        let bitset = TestedOdd::from_indices([0, 2, 3, 5]);
+
+        let deposited = bitset.deposit_masked(&mask);
+        assert_eq!(deposited, TestedOdd::from_indices([1, 7, 8, 23]));
+        assert_eq!(deposited.extract_masked(&mask), bitset);
+        assert_eq!(
+            TestedOdd::ALL.extract_masked(&mask),
+            TestedOdd::from_indices(0..6)
+        );
+
+        let mask = Tested16::from_indices((0..128).step_by(3));
+        let bitset = Tested16::from_indices([0, 20, 21, 22, 41, 42]);
+        let deposited = bitset.deposit_masked(&mask);
+        assert_eq!(deposited, Tested16::from_indices([0, 60, 63, 66, 123, 126]));
+        assert_eq!(deposited.extract_masked(&mask), bitset);
+        assert_eq!(
+            Tested16::ALL.extract_masked(&Tested16::NONE),
+            Tested16::NONE
+        );
+    }
+
+    #[test]
+    fn hamming_distance() {
+        let a = TestedOdd::from_indices([0, 5, 12, 23]);
+        let b = TestedOdd::from_indices([5, 13, 23]);
+
+        assert_eq!(a.hamming_distance(&b), 3);
+        assert_eq!(b.hamming_distance(&a), 3);
+        assert_eq!(a.hamming_distance(&a), 0);
+        assert_eq!(Tested16::NONE.hamming_distance(&Tested16::ALL), 128);
+        assert_eq!(a.jaccard(&b), 2.0 / 5.0);
+    }
+
+    #[test]
+    fn parity() {
+        assert!(!Tested16::NONE.parity());
+        assert!(!Tested16::ALL.parity());
+        assert!(TestedOdd::from_indices([23]).parity());
+        assert!(!TestedOdd::from_indices([0, 23]).parity());
+
+        for bitset in Tested2::all_values().step_by(97) {
+            assert_eq!(bitset.parity(), bitset.count_ones() % 2 == 1);
+        }
+        let bitset = Tested16::from_indices([0, 63, 64, 65, 127]);
+        assert_eq!(bitset.parity(), bitset.count_ones() % 2 == 1);
+    }
+
+    #[test]
+    fn matches_masked() {
+        let pattern = Tested16::from_indices([0, 64, 127]);
+        let care_mask = Tested16::from_indices([0, 1, 64, 65, 127]);
+
+        let value = Tested16::from_indices([0, 2, 64, 100, 127]);
+        assert!(value.matches_masked(&pattern, &care_mask));
+        assert_eq!(value & care_mask, pattern & care_mask);
+
+        let value = Tested16::from_indices([0, 64, 65, 127]);
+        assert!(!value.matches_masked(&pattern, &care_mask));
+        let value = Tested16::from_indices([0, 64]);
+        assert!(!value.matches_masked(&pattern, &care_mask));
+
+        assert!(Tested16::ALL.matches_masked(&Tested16::NONE, &Tested16::NONE));
+        assert!(!TestedOdd::ALL.matches_masked(&TestedOdd::NONE, &TestedOdd::new([0, 0, 0x80])));
+    }
+
+    #[test]
+    fn merge() {
+        let a = TestedOdd::new([0b11110000, 0xFF, 0x00]);
+        let b = TestedOdd::new([0b10101010, 0x00, 0xFF]);
+        let mask = TestedOdd::new([0b00111100, 0x0F, 0xF0]);
+        let expected = TestedOdd::new([0b11101000, 0xF0, 0xF0]);
+
+        assert_eq!(a.merge(b, mask), expected);
+        assert_eq!(a.merge(b, mask), (a & !mask) | (b & mask));
+        assert_eq!(a.merge(b, TestedOdd::NONE), a);
+        assert_eq!(a.merge(b, TestedOdd::ALL), b);
+
+        let mut c = a;
+        c.merge_in(b, mask);
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn all_values() {
+        let values: Vec<Tested1> = Tested1::all_values().collect();
+        assert_eq!(values.len(), 256);
+        assert!(values
+            .iter()
+            .enumerate()
+            .all(|(i, value)| value.into_inner() == [i as u8]));
+
+        let mut values = Tested2::all_values();
+        assert_eq!(values.nth(0x1234), Some(Tested2::new([0x34, 0x12])));
+        assert_eq!(values.last(), Some(Tested2::ALL));
+    }
+
+    #[test]
+    fn counter_arithmetic() {
+        let as_u128 = |bitset: Tested16| u128::from_le_bytes(bitset.into_inner());
+        let values = [
+            0,
+            1,
+            u64::MAX as u128,
+            1 << 64,
+            u128::MAX,
+            u128::MAX - 1,
+            0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+        ];
+        for a in values {
+            for b in values {
+                let (x, y) = (
+                    Tested16::new(a.to_le_bytes()),
+                    Tested16::new(b.to_le_bytes()),
+                );
+                let (sum, overflow) = x.overflowing_add(y);
+                assert_eq!((as_u128(sum), overflow), a.overflowing_add(b));
+                let (difference, overflow) = x.overflowing_sub(y);
+                assert_eq!((as_u128(difference), overflow), a.overflowing_sub(b));
+                assert_eq!(as_u128(x.wrapping_add(y)), a.wrapping_add(b));
+                assert_eq!(as_u128(x.wrapping_sub(y)), a.wrapping_sub(b));
+            }
+        }
+
+        let max = TestedOdd::ALL;
+        let one = TestedOdd::new([1, 0, 0]);
+        assert_eq!(max.overflowing_add(one), (TestedOdd::NONE, true));
+        assert_eq!(TestedOdd::NONE.overflowing_sub(one), (max, true));
+        assert_eq!(
+            TestedOdd::new([0xFF, 0xFF, 0]).wrapping_add(one),
+            TestedOdd::new([0, 0, 1])
+        );
+    }
+
+    #[test]
+    fn usize_shifts() -> TestResult {
+        let bitset = Tested16::new(std::array::from_fn(|b| (b as u8).wrapping_mul(37) | 1));
+
+        for amount in [0, 1, 7, 8, 13, 64, 100, 127] {
+            let index = Index::try_from(amount)?;
+            assert_eq!(bitset << amount, bitset << index);
+            assert_eq!(bitset >> amount, bitset >> index);
+            assert_eq!(bitset.checked_shl(amount), Some(bitset << index));
+            assert_eq!(bitset.checked_shr(amount), Some(bitset >> index));
+            assert_eq!(bitset.wrapping_shl(amount + 128), bitset << index);
+            assert_eq!(bitset.wrapping_shr(amount + 256), bitset >> index);
+            assert_eq!(bitset.saturating_shl(amount), bitset << index);
+            assert_eq!(bitset.saturating_shr(amount), bitset >> index);
+
+            let mut shifted = bitset;
+            shifted <<= amount;
+            assert_eq!(shifted, bitset << index);
+            let mut shifted = bitset;
+            shifted >>= amount;
+            assert_eq!(shifted, bitset >> index);
+        }
+
+        assert_eq!(bitset.checked_shl(128), None);
+        assert_eq!(bitset.checked_shr(usize::MAX), None);
+        assert_eq!(bitset.saturating_shl(128), Tested16::NONE);
+        assert_eq!(bitset.saturating_shr(usize::MAX), Tested16::NONE);
+
+        let bitset = TestedOdd::new([0b00000001, 0, 0b10000000]);
+        assert_eq!(bitset << 1, TestedOdd::new([0b00000010, 0, 0]));
+        assert_eq!(bitset >> 23, TestedOdd::new([0b00000001, 0, 0]));
+        assert_eq!(bitset.wrapping_shl(25), TestedOdd::new([0b00000010, 0, 0]));
+
+        let empty = Byteset::<0>::new([]);
+        assert_eq!(empty.wrapping_shl(3), empty);
+        assert_eq!(empty.wrapping_shr(3), empty);
+        Ok(())
+    }
+
+    #[test]
+    fn byte_order() {
+        let bitset = TestedOdd::new([1, 2, 3]);
+
+        assert_eq!(bitset.to_le_bytes(), [1, 2, 3]);
+        assert_eq!(bitset.to_be_bytes(), [3, 2, 1]);
+        assert_eq!(TestedOdd::from_le_bytes(bitset.to_le_bytes()), bitset);
+        assert_eq!(TestedOdd::from_be_bytes(bitset.to_be_bytes()), bitset);
+        assert_eq!(TestedOdd::from_be(bitset.to_be()), bitset);
+        assert_eq!(TestedOdd::from_le(bitset.to_le()), bitset);
+        assert_eq!(bitset.swap_bytes(), TestedOdd::new([3, 2, 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn usize_shift_overflow() {
+        let _ = Tested2::ALL << 16;
+    }
+
+    #[test]
+    fn folding() {
+        use crate::iter::BitsetIteratorExt;
+
+        let sets = [
+            TestedOdd::new([0b00000111, 0, 0x80]),
+            TestedOdd::new([0b00000110, 0xFF, 0x80]),
+            TestedOdd::new([0b00001100, 0x0F, 0x81]),
+        ];
+
+        assert_eq!(
+            TestedOdd::union_of(sets),
+            TestedOdd::new([0b00001111, 0xFF, 0x81])
+        );
+        assert_eq!(
+            TestedOdd::intersection_of(sets),
+            TestedOdd::new([0b00000100, 0, 0x80])
+        );
+        assert_eq!(sets.iter().copied().union(), TestedOdd::union_of(sets));
+        assert_eq!(
+            sets.into_iter().intersection(),
+            TestedOdd::intersection_of(sets)
+        );
+        assert_eq!(std::iter::empty::<TestedOdd>().union(), TestedOdd::NONE);
+        assert_eq!(
+            std::iter::empty::<TestedOdd>().intersection(),
+            TestedOdd::ALL
+        );
+    }
+
+    #[test]
+    fn from_indices() -> TestResult {
+        let expected = TestedOdd::new([0b00001001, 0, 0b10000000]);
+
+        assert_eq!(TestedOdd::from_indices([0, 3, 23, 24, 1000]), expected);
+        assert_eq!(TestedOdd::try_from_indices([23, 3, 0, 3])?, expected);
+        assert!(TestedOdd::try_from_indices([0, 24]).is_err());
+
+        let bitset: TestedOdd = [0, 3, 23, usize::MAX].into_iter().collect();
+        assert_eq!(bitset, expected);
+
+        let bitset: TestedOdd = expected.ones().collect();
+        assert_eq!(bitset, expected);
+
+        assert_eq!(Tested16::from_indices(0..128), Tested16::ALL);
+        assert_eq!(Tested16::from_indices(None), Tested16::NONE);
+        Ok(())
+    }
+
+    #[test]
+    fn extend() -> TestResult {
+        let mut bitset = TestedOdd::new([0b00000001, 0, 0]);
+
+        bitset.extend([Index::try_from(9)?, Index::try_from(23)?]);
+        assert_eq!(bitset, TestedOdd::new([0b00000001, 0b00000010, 0b10000000]));
+
+        bitset.extend([Zero, Zero, One]);
+        assert_eq!(bitset, TestedOdd::new([0b00000101, 0b00000010, 0b10000000]));
+
+        bitset.extend(std::iter::repeat_n(true, 30));
+        assert_eq!(bitset, TestedOdd::ALL);
+
+        let mut bitset = Tested2::NONE;
+        bitset.extend(Tested2::ALL.ones().filter(|i| i.into_inner() % 5 == 0));
+        assert_eq!(bitset, Tested2::new([0b00100001, 0b10000100]));
+        Ok(())
+    }
+
+    #[test]
+    fn index_ops() -> TestResult {
+        let bitset = TestedOdd::new([0b00000101, 0, 0b10000000]);
+
+        assert!(bitset[0]);
+        assert!(!bitset[1]);
+        assert!(bitset[2]);
+        assert!(bitset[23]);
+        assert!(bitset[Index::try_from(23)?]);
+        assert!(!bitset[Index::try_from(8)?]);
+        assert_eq!((0..24).filter(|&i| bitset[i]).count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_ops_out_of_range() {
+        let _ = Tested1::NONE[8];
+    }
+
+    #[test]
+    fn into_iterator() {
+        let mut bitset = TestedOdd::new([0b00000101, 0, 0b10000000]);
+
+        let mut ones = Vec::new();
+        for (i, bit) in (&bitset).into_iter().enumerate() {
+            if *bit == One {
+                ones.push(i);
+            }
+        }
+        assert_eq!(ones, [0, 2, 23]);
+
+        for mut bit in &mut bitset {
+            *bit = !*bit;
+        }
+        assert_eq!(bitset, TestedOdd::new([0b11111010, 0xFF, 0b01111111]));
+
+        let bits: Vec<Bit> = bitset.into_iter().take(3).collect();
+        assert_eq!(bits, [Zero, One, Zero]);
+    }
+
+    #[test]
+    fn exact_size_and_fused() -> TestResult {
+        let mut bitset = TestedOdd::new([0b00000101, 0, 0b10000000]);
+
+        let mut bits = bitset.bits();
+        assert_eq!(bits.len(), 24);
+        assert_eq!(bits.nth(2), Some(One));
+        assert_eq!(bits.next_back(), Some(One));
+        assert_eq!(bits.len(), 20);
+        assert_eq!(bits.nth(100), None);
+        assert_eq!(bits.next(), None);
+        assert_eq!(bits.next_back(), None);
+
+        let mut bits_ref = bitset.bits_ref();
+        assert_eq!(bits_ref.len(), 24);
+        assert_eq!(bits_ref.nth(2).as_deref(), Some(&One));
+        assert_eq!(bits_ref.nth_back(0).as_deref(), Some(&One));
+        assert_eq!(bits_ref.nth_back(19).as_deref(), Some(&Zero));
+        assert_eq!(bits_ref.len(), 0);
+        assert_eq!(bits_ref.next(), None);
+        assert_eq!(bits_ref.next_back(), None);
+        assert_eq!(bitset.bits_ref().rev().nth(23).as_deref(), Some(&One));
+
+        let mut bits_mut = bitset.bits_mut();
+        assert_eq!(bits_mut.len(), 24);
+        if let Some(mut bit) = bits_mut.nth(1) {
+            *bit = One;
+        }
+        if let Some(mut bit) = bits_mut.next_back() {
+            *bit = Zero;
+        }
+        assert_eq!(bits_mut.len(), 21);
+        assert_eq!(bits_mut.count(), 21);
+        assert_eq!(bitset, TestedOdd::new([0b00000111, 0, 0]));
+
+        let mut ones = bitset.ones();
+        assert_eq!(ones.len(), 3);
+        assert_eq!(ones.next_back().map(usize::from), Some(2));
+        assert_eq!(ones.len(), 2);
+        assert_eq!(ones.nth(1).map(usize::from), Some(1));
+        assert_eq!(ones.next(), None);
+        assert_eq!(ones.next(), None);
+
+        let mut zeros = bitset.zeros();
+        assert_eq!(zeros.len(), 21);
+        assert_eq!(zeros.nth(1).map(usize::from), Some(4));
+        assert_eq!(zeros.next_back().map(usize::from), Some(23));
+        assert_eq!(zeros.len(), 18);
+        assert_eq!(zeros.by_ref().count(), 18);
+        assert_eq!(zeros.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn iterator_specializations() -> TestResult {
+        let mut bitset = Tested16::NONE;
+        bitset.set(3.try_into()?).set(64.try_into()?).set(100.try_into()?).set(127.try_into()?);
+
+        assert_eq!(bitset.ones().count(), 4);
+        assert_eq!(bitset.ones().size_hint(), (4, Some(4)));
+        assert_eq!(bitset.ones().last(), Some(127.try_into()?));
+        assert_eq!(bitset.ones().nth(2), Some(100.try_into()?));
+        assert_eq!(bitset.ones().nth(4), None);
+        assert_eq!(bitset.ones().step_by(2).count(), 2);
+
+        let mut iter = bitset.ones();
+        assert_eq!(iter.nth(1), Some(64.try_into()?));
+        assert_eq!(iter.count(), 2);
+
+        assert_eq!(bitset.zeros().count(), 124);
+        assert_eq!(bitset.zeros().nth(3), Some(4.try_into()?));
+        assert_eq!(bitset.zeros().last(), Some(126.try_into()?));
+
+        assert_eq!(bitset.bits().count(), 128);
+        assert_eq!(bitset.bits().nth(64), Some(One));
+        assert_eq!(bitset.bits().nth(128), None);
+        assert_eq!(bitset.bits().last(), Some(One));
+        Ok(())
+    }
+
+    #[test]
+    fn count_ones_chunked() -> TestResult {
+        let mut bitset = Byteset::<19>::NONE;
+        bitset
+            .set(0.try_into()?)
+            .set(70.try_into()?)
+            .set(151.try_into()?);
+
+        assert_eq!(bitset.count_ones(), 3);
+        assert_eq!(bitset.count_zeros(), 149);
+        assert_eq!(Byteset::<19>::ALL.count_ones(), 152);
+        Ok(())
+    }
+
+    #[test]
+    fn is_empty() -> TestResult {
+        assert!(Tested16::NONE.is_empty());
+        assert!(!Tested16::NONE.any());
+
+        let bitset = Tested16::NONE.clone().set(127.try_into()?).build();
+        assert!(!bitset.is_empty());
+        assert!(bitset.any());
+
+        let bitset = TestedOdd::NONE.clone().set(20.try_into()?).build();
+        assert!(bitset.any());
+        Ok(())
+    }
+
+    #[test]
+    fn swap_bits_words() {
+        let mut bitset = Byteset::<17>::from_indices([0, 70, 135]);
+        for (i, j) in [(0, 5), (5, 5), (70, 127), (127, 135), (64, 135)] {
+            let expected = if bitset.bit(Index::from_usize(i)) == bitset.bit(Index::from_usize(j)) {
+                bitset
+            } else {
+                *bitset
+                    .clone()
+                    .flip(Index::from_usize(i))
+                    .flip(Index::from_usize(j))
+            };
+            bitset.swap_bits(Index::from_usize(i), Index::from_usize(j));
+            assert_eq!(bitset, expected);
+        }
+        assert_eq!(bitset, Byteset::<17>::from_indices([5, 64, 127]));
+    }
+
+    #[test]
+    fn swap_bits() -> TestResult {
+        let bitset = Tested2::from([0b00000001, 0b00000000])
+            .swap_bits(0.try_into()?, 15.try_into()?)
+            .swap_bits(1.try_into()?, 2.try_into()?)
+            .build();
+
+        assert_eq!(bitset, Tested2::from([0b00000000, 0b10000000]));
+        Ok(())
+    }
+
+    #[test]
+    fn swap_ranges() {
+        let mut bitset = Tested16::NONE;
+        for i in (3..40).chain(100..110) {
+            bitset.set(i.try_into().unwrap());
+        }
+        bitset.swap_ranges(0..50, 70..120);
+
+        let ones: Vec<usize> = bitset.ones().map(usize::from).collect();
+        let expected: Vec<usize> = (30..40).chain(73..110).collect();
+        assert_eq!(ones, expected);
+
+        let mut bitset = TestedOdd::from([0b10101010, 0b11001100, 0b11110000]);
+        bitset.swap_ranges(1..9, 13..21);
+        let mut other = TestedOdd::from([0b10101010, 0b11001100, 0b11110000]);
+        for k in 0..8 {
+            other.swap_bits((1 + k).try_into().unwrap(), (13 + k).try_into().unwrap());
+        }
+        assert_eq!(bitset, other);
+    }
+
+    #[test]
+    fn copy_bits_from() {
+        let mut other = Tested16::NONE;
+        for i in (5..70).chain(100..101) {
+            other.set(i.try_into().unwrap());
+        }
+
+        let mut bitset = TestedOdd::ALL;
+        bitset.copy_bits_from(&other, 60..80, 3);
+
+        let zeros: Vec<usize> = bitset.zeros().map(usize::from).collect();
+        assert_eq!(zeros, (13..23).collect::<Vec<_>>());
+
+        let mut bitset = Tested16::NONE;
+        bitset.copy_bits_from(&other, 0..128, 0);
+        assert_eq!(bitset, other);
+
+        let mut bitset = Tested16::NONE;
+        bitset.copy_bits_from(&crate::prelude::Bitset64::from(u64::MAX), 0..64, 33);
+        assert_eq!(
+            bitset.ones().map(usize::from).collect::<Vec<_>>(),
+            (33..97).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn shifts_match_integers() -> TestResult {
+        let bitset: Tested16 = 0x0123456789ABCDEF_FEDCBA9876543210u128.to_le_bytes().into();
+        let integer = crate::prelude::Bitset128::from(0x0123456789ABCDEF_FEDCBA9876543210);
+
+        for amount in [1, 7, 8, 13, 64, 100, 127] {
+            let expected = (integer << Index::try_from(amount)?)
+                .into_inner()
+                .to_le_bytes();
+            assert_eq!((bitset << Index::try_from(amount)?).into_inner(), expected);
+
+            let expected = (integer >> Index::try_from(amount)?)
+                .into_inner()
+                .to_le_bytes();
+            assert_eq!((bitset >> Index::try_from(amount)?).into_inner(), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rotate() {
+        let inner = 0x0123456789ABCDEF_FEDCBA9876543210u128;
+        let bitset: Tested16 = inner.to_le_bytes().into();
+
+        for amount in [0, 1, 7, 8, 13, 64, 100, 127, 128, 300] {
+            let expected = inner.rotate_left(amount as u32).to_le_bytes();
+            assert_eq!(bitset.rotate_left(amount).into_inner(), expected);
+
+            let expected = inner.rotate_right(amount as u32).to_le_bytes();
+            assert_eq!(bitset.rotate_right(amount).into_inner(), expected);
+        }
+
+        let bitset = TestedOdd::from([0b00000001, 0b00000000, 0b10000000]);
+        assert_eq!(
+            bitset.rotate_left(1),
+            TestedOdd::from([0b00000011, 0b00000000, 0b00000000])
+        );
+    }
+
+    #[test]
+    fn rotate_by_index() {
+        // Rotates by setting each bit, like the default implementation of the trait.
+        fn reference<const N: usize>(bitset: &Byteset<N>, amount: usize) -> Byteset<N> {
+            Byteset::from_indices(bitset.ones().map(|i| (i.into_inner() + amount) % (N * 8)))
+        }
+
+        let odd = TestedOdd::from_indices([0, 9, 23]);
+        let wide = Byteset::<17>::from_indices([0, 63, 64, 127, 128, 135]);
+        for amount in 0..24 {
+            let index = Index::<TestedOdd>::from_usize(amount);
+            assert_eq!(odd.rotate_left_by_index(index), reference(&odd, amount));
+            assert_eq!(
+                odd.rotate_right_by_index(index),
+                reference(&odd, 24 - amount)
+            );
+        }
+        for amount in [0, 1, 63, 64, 65, 129, 135] {
+            let index = Index::<Byteset<17>>::from_usize(amount);
+            assert_eq!(wide.rotate_left_by_index(index), reference(&wide, amount));
+        }
+
+        assert_eq!(Byteset::<0>::NONE.rotate_left(3), Byteset::<0>::NONE);
+        assert_eq!(Byteset::<0>::NONE.rotate_right(3), Byteset::<0>::NONE);
+    }
+
+    #[test]
+    fn reverse_paths() {
+        // Reverses by setting each bit, like the default implementation of the trait.
+        fn reference<const N: usize>(bitset: &Byteset<N>) -> Byteset<N> {
+            Byteset::from_indices(bitset.ones().map(|i| N * 8 - 1 - i.into_inner()))
+        }
+
+        let odd = TestedOdd::from_indices([0, 9, 22]);
+        let full = Tested16::from_indices([0, 1, 63, 64, 100]);
+        let wide = Byteset::<17>::from_indices([0, 63, 64, 127, 128, 135]);
+
+        assert_eq!(odd.reverse(), reference(&odd));
+        assert_eq!(full.reverse(), reference(&full));
+        assert_eq!(wide.reverse(), reference(&wide));
+        assert_eq!(
+            Tested1::new([0b11000010]).reverse(),
+            Tested1::new([0b01000011])
+        );
+        assert_eq!(Byteset::<0>::NONE.reverse(), Byteset::<0>::NONE);
+    }
+
+    #[test]
+    fn reverse() {
+        let inner = 0x0123456789ABCDEF_FEDCBA9876543210u128;
+        let bitset: Tested16 = inner.to_le_bytes().into();
+
+        assert_eq!(
+            bitset.reverse().into_inner(),
+            inner.reverse_bits().to_le_bytes()
+        );
+
+        let bitset = TestedOdd::from([0b00000001, 0b00000010, 0b11000000]);
+        assert_eq!(
+            bitset.reverse(),
+            TestedOdd::from([0b00000011, 0b01000000, 0b10000000])
+        );
+    }
+
+    #[test]
+    fn display_adapter() {
+        let bitset = Byteset::<17>::from([0b00000001; 17]);
+        let s = bitset
+            .display()
+            .group_bits(8)
+            .groups_per_line(8)
+            .offsets(true)
+            .msb_first(false)
+            .to_string();
+        let lines: Vec<_> = s.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "  0: 10000000 10000000 10000000 10000000 10000000 10000000 10000000 10000000"
+        );
+        assert_eq!(lines[2], "128: 10000000");
+
+        let s = TestedOdd::from([0b11111111, 0, 0b00000001])
+            .display()
+            .group_bits(5)
+            .groups_per_line(2)
+            .msb_first(false)
+            .to_string();
+
+        assert_eq!(s, "11111 11100\n00000 01000\n0000");
+        assert_eq!(
+            TestedOdd::from([0b11111111, 0, 0b00000001])
+                .display()
+                .to_string(),
+            <TestedOdd as Bitset>::display(&TestedOdd::from([0b11111111, 0, 0b00000001]))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn ranges() {
+        let ranges = [0..1, 7..70, 100..101, 120..128];
+        let bitset = Tested16::from_ranges(ranges.clone());
+
+        assert_eq!(bitset.count_ones(), 1 + 63 + 1 + 8);
+        assert_eq!(bitset.to_ranges().collect::<Vec<_>>(), ranges);
+        assert_eq!(
+            bitset.to_ranges().rev().collect::<Vec<_>>(),
+            ranges.iter().rev().cloned().collect::<Vec<_>>()
+        );
+
+        let bitset = Tested16::from_ranges([5..20, 10..30, 40..40]);
+        let mut iter = bitset.to_ranges();
+        assert_eq!(iter.next(), Some(5..30));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(Tested16::NONE.to_ranges().next(), None);
+        assert_eq!(Tested16::ALL.to_ranges().next_back(), Some(0..128));
+
+        // Ranges past the end are clamped, same as with insert_range.
+        assert_eq!(
+            Tested2::from_ranges([8..20, 30..40]),
+            Tested2::NONE.clone().insert_range(8..20).build()
+        );
+    }
+
+    #[test]
+    fn insert_remove_range() {
+        use std::ops::Bound;
+
+        let mut bitset = TestedOdd::NONE;
+        bitset.insert_range(3..13);
+        assert_eq!(bitset.to_ranges().next(), Some(3..13));
+
+        bitset.insert_range((Bound::Excluded(15), Bound::Included(17)));
+        assert_eq!(bitset.to_ranges().collect::<Vec<_>>(), [3..13, 16..18]);
+
+        bitset.insert_range(20..1000);
+        assert_eq!(
+            bitset.to_ranges().collect::<Vec<_>>(),
+            [3..13, 16..18, 20..24]
+        );
+
+        bitset.remove_range(5..=16).remove_range(..);
+        assert!(bitset.is_empty());
+
+        let mut bitset = Tested16::NONE;
+        bitset
+            .insert_range(..)
+            .remove_range(1..127)
+            .remove_range(200..300);
+        assert_eq!(bitset.to_ranges().collect::<Vec<_>>(), [0..1, 127..128]);
+
+        // Decreasing ranges are empty
+        #[allow(clippy::reversed_empty_ranges)]
+        bitset.insert_range(10..5);
+        assert_eq!(bitset.count_ones(), 2);
+    }
+
+    #[test]
+    fn get_range() -> Result<(), crate::error::ConvError> {
+        let bitset = Tested16::NONE.clone().insert_range(10..110).build();
+
+        assert_eq!(bitset.get_range::<u128, _>(..)?, ((1 << 100) - 1) << 10);
+        assert_eq!(bitset.get_range::<u128, _>(100..)?, 0b1111111111);
+        assert_eq!(bitset.get_range::<u16, _>(5..=15)?, 0b11111100000);
+        assert_eq!(bitset.get_range::<u8, _>(60..60)?, 0);
+        assert!(bitset.get_range::<u8, _>(60..69).is_err());
+        assert!(bitset.get_range::<u8, _>(120..129).is_err());
+
+        let bitset = Byteset::<17>::ALL;
+        assert!(bitset.get_range::<u128, _>(..).is_err());
+        assert_eq!(bitset.get_range::<u128, _>(8..)?, u128::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn flip_range() {
+        let mut bitset = TestedOdd::NONE;
+        bitset.flip_range(3..13).flip_range(10..20);
+        assert_eq!(bitset.to_ranges().collect::<Vec<_>>(), [3..10, 13..20]);
+
+        bitset.flip_range(..).flip_range(30..);
+        assert_eq!(
+            bitset.to_ranges().collect::<Vec<_>>(),
+            [0..3, 10..13, 20..24]
+        );
+
+        let mut bitset = Tested16::NONE;
+        bitset.flip_range(1..127).flip_range(60..70);
+        assert_eq!(bitset.to_ranges().collect::<Vec<_>>(), [1..60, 70..127]);
+    }
+
+    #[test]
+    fn similarity() {
+        let a = Tested16::NONE.clone().insert_range(0..64).build();
+        let b = Tested16::NONE.clone().insert_range(32..).build();
+
+        assert_eq!(a.jaccard(&b), 32.0 / 128.0);
+        assert_eq!(a.jaccard(&a), 1.0);
+        assert_eq!(Tested16::NONE.jaccard(&Tested16::NONE), 1.0);
+        assert_eq!(a.jaccard(&Tested16::NONE), 0.0);
+
+        assert_eq!(a.cosine_similarity(&b), 32.0 / (64.0f64 * 96.0).sqrt());
+        assert_eq!(a.cosine_similarity(&a), 1.0);
+        assert_eq!(a.cosine_similarity(&Tested16::NONE), 0.0);
+
+        let a = TestedOdd::from([0b11110000, 0, 0b00000001]);
+        let b = TestedOdd::from([0b00110000, 0, 0b10000001]);
+        assert_eq!(a.jaccard(&b), 3.0 / 6.0);
+    }
+
+    #[test]
+    fn combination_counts() {
+        let a = TestedOdd::from([0b11110000, 0b10101010, 0b00000001]);
+        let b = TestedOdd::from([0b00111100, 0b11111111, 0b10000001]);
+
+        assert_eq!(a.intersection_count(&b), a.intersection(b).count_ones());
+        assert_eq!(a.union_count(&b), a.union(b).count_ones());
+        assert_eq!(a.difference_count(&b), a.difference(b).count_ones());
+        assert_eq!(a.sym_difference_count(&b), a.sym_difference(b).count_ones());
+
+        let a = Tested16::from_ranges([0..20, 60..100]);
+        let b = Tested16::from_ranges([10..70, 127..128]);
+
+        assert_eq!(a.intersection_count(&b), 20);
+        assert_eq!(a.union_count(&b), 101);
+        assert_eq!(a.difference_count(&b), 40);
+        assert_eq!(a.sym_difference_count(&b), 81);
+    }
+
+    #[test]
+    fn leading_trailing() {
+        let bitset = TestedOdd::from_ranges([0..5, 10..11, 17..20]);
+        assert_eq!(bitset.trailing_zeros(), 0);
+        assert_eq!(bitset.trailing_ones(), 5);
+        assert_eq!(bitset.leading_zeros(), 4);
+        assert_eq!(bitset.leading_ones(), 0);
+        assert_eq!(bitset.lowest_one().map(|i| i.into_inner()), Some(0));
+        assert_eq!(bitset.highest_one().map(|i| i.into_inner()), Some(19));
+
+        let bitset = Tested16::from_ranges([70..71, 100..128]);
+        assert_eq!(bitset.trailing_zeros(), 70);
+        assert_eq!(bitset.leading_ones(), 28);
+        assert_eq!(bitset.lowest_one().map(|i| i.into_inner()), Some(70));
+        assert_eq!(bitset.highest_one().map(|i| i.into_inner()), Some(127));
+
+        assert_eq!(TestedOdd::NONE.leading_zeros(), 24);
+        assert_eq!(TestedOdd::ALL.leading_ones(), 24);
+        assert_eq!(TestedOdd::ALL.trailing_ones(), 24);
+        assert_eq!(Tested16::NONE.highest_one(), None);
+
+        let bitset = TestedOdd::from_ranges([0..5, 10..11, 17..24]);
+        assert_eq!(bitset.lowest_zero().map(|i| i.into_inner()), Some(5));
+        assert_eq!(bitset.highest_zero().map(|i| i.into_inner()), Some(16));
+        assert_eq!(
+            TestedOdd::NONE.highest_zero().map(|i| i.into_inner()),
+            Some(23)
+        );
+        assert_eq!(Tested16::ALL.lowest_zero(), None);
+    }
+
+    #[test]
+    fn rank_select() {
+        let bitset = Tested16::from_ranges([3..4, 60..70, 127..128]);
+
+        assert_eq!(bitset.rank(Index::from_usize(3)), 0);
+        assert_eq!(bitset.rank(Index::from_usize(64)), 5);
+        assert_eq!(bitset.rank(Index::from_usize(127)), 11);
+        assert_eq!(bitset.select(5).map(|i| i.into_inner()), Some(64));
+        assert_eq!(bitset.select(11).map(|i| i.into_inner()), Some(127));
+        assert_eq!(bitset.select(12), None);
+
+        for n in 0..bitset.count_ones() {
+            assert_eq!(bitset.rank(bitset.select(n).unwrap()), n);
+        }
+    }
+
+    #[test]
+    fn next_prev() {
+        let bitset = Tested16::from_ranges([3..4, 60..70, 127..128]);
+        let at = |i| Index::<Tested16>::from_usize(i);
+        let found = |i: Option<Index<Tested16>>| i.map(|i| i.into_inner());
+
+        assert_eq!(found(bitset.next_one(at(3))), Some(60));
+        assert_eq!(found(bitset.next_one(at(69))), Some(127));
+        assert_eq!(found(bitset.next_one(at(127))), None);
+        assert_eq!(found(bitset.prev_one(at(127))), Some(69));
+        assert_eq!(found(bitset.prev_one(at(60))), Some(3));
+        assert_eq!(found(bitset.prev_one(at(3))), None);
+
+        assert_eq!(found(bitset.next_zero(at(59))), Some(70));
+        assert_eq!(found(bitset.next_zero(at(126))), None);
+        assert_eq!(found(bitset.prev_zero(at(70))), Some(59));
+        assert_eq!(found(bitset.prev_zero(at(4))), Some(2));
+        assert_eq!(found(Tested16::ALL.prev_zero(at(127))), None);
+    }
+
+    #[test]
+    fn subsets() {
+        let mask = Tested16::from_ranges([0..1, 63..65, 127..128]);
+        let subsets: Vec<_> = mask.subsets().collect();
+
+        assert_eq!(subsets.len(), 16);
+        assert_eq!(subsets[0], Tested16::NONE);
+        assert_eq!(
+            subsets[6],
+            Tested16::NONE.clone().insert_range(63..65).build()
+        );
+        assert_eq!(subsets[15], mask);
+        assert!(subsets.iter().all(|s| mask.includes(s)));
+
+        assert_eq!(
+            Tested16::NONE.subsets().collect::<Vec<_>>(),
+            [Tested16::NONE]
+        );
+        assert_eq!(Tested2::ALL.subsets().count(), 1 << 16);
+    }
+
+    #[test]
+    fn combinations() {
+        let mask = Tested16::from_ranges([0..2, 63..65, 127..128]);
+        let pairs: Vec<_> = mask.combinations(2).collect();
+
+        assert_eq!(pairs.len(), 10);
+        assert!(pairs
+            .iter()
+            .all(|s| s.count_ones() == 2 && mask.includes(s)));
+        assert!(pairs
+            .windows(2)
+            .all(|w| w[0].highest_one() <= w[1].highest_one()));
+        assert_eq!(pairs[0], Tested16::from_ranges([0..1, 1..2]));
+        assert_eq!(pairs[9], Tested16::from_ranges([64..65, 127..128]));
+
+        assert_eq!(mask.combinations(0).collect::<Vec<_>>(), [Tested16::NONE]);
+        let mut iter = mask.combinations(5);
+        assert_eq!(iter.next().as_ref(), Some(&mask));
+        assert_eq!(iter.next(), None);
+        assert_eq!(mask.combinations(6).next(), None);
+
+        let mut iter = Tested2::ALL.combinations(8);
+        assert_eq!(iter.size_hint(), (12870, Some(12870)));
+        iter.next();
+        assert_eq!(iter.clone().count(), 12869);
+        assert_eq!(
+            iter.count(),
+            Tested2::ALL.combinations(8).skip(1).fold(0, |n, _| n + 1)
+        );
+
+        assert_eq!(
+            Tested16::ALL.combinations(64).size_hint(),
+            (usize::MAX, None)
+        );
+    }
+
+    #[test]
+    fn set_relations() {
+        let a = Tested16::from_ranges([0..10, 100..120]);
+        let b = Tested16::from_ranges([2..5, 110..111]);
+        let c = Tested16::from_ranges([10..100, 120..128]);
+
+        assert!(b.is_subset(&a) && a.is_superset(&b));
+        assert!(!a.is_subset(&b) && !b.is_superset(&a));
+        assert!(a.is_subset(&a) && a.is_superset(&a));
+        assert!(Tested16::NONE.is_subset(&b));
+        assert!(a.is_disjoint(&c) && c.is_disjoint(&b));
+        assert!(!a.is_disjoint(&b));
+        assert!(Tested16::NONE.is_disjoint(&Tested16::NONE));
+    }
+
+    #[test]
+    fn pop_lowest_highest() {
+        let mut bitset = Tested16::from_ranges([3..4, 64..65, 127..128]);
+
+        assert_eq!(bitset.pop_lowest().map(|i| i.into_inner()), Some(3));
+        assert_eq!(bitset.pop_highest().map(|i| i.into_inner()), Some(127));
+        assert_eq!(bitset.pop_highest().map(|i| i.into_inner()), Some(64));
+        assert_eq!(bitset.pop_lowest(), None);
+        assert_eq!(bitset.pop_highest(), None);
+        assert!(bitset.is_empty());
+    }
+
+    #[test]
+    fn drain_ones() {
+        let mut bitset = Tested16::from_ranges([3..4, 64..66, 127..128]);
+        let drained: Vec<_> = bitset.drain_ones().map(|i| i.into_inner()).collect();
+        assert_eq!(drained, [3, 64, 65, 127]);
+        assert!(bitset.is_empty());
+
+        let mut bitset = Tested16::ALL;
+        let mut iter = bitset.drain_ones();
+        assert_eq!(iter.len(), 128);
+        assert_eq!(iter.next_back().map(|i| i.into_inner()), Some(127));
+        assert_eq!(iter.len(), 127);
+        drop(iter);
+        assert!(bitset.is_empty());
+
+        let mut bitset = Tested16::from_indices([3]);
+        let mut iter = bitset.drain_ones();
+        assert_eq!(iter.next().map(|i| i.into_inner()), Some(3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn prefix() {
+        let a = Tested16::from_ranges([0..70, 100..101]);
+        let b = Tested16::NONE.clone().insert_range(0..70).build();
+
+        assert_eq!(a.first_difference(&b).map(|i| i.into_inner()), Some(100));
+        assert_eq!(a.common_prefix_len(&b), 100);
+        assert_eq!(a.common_prefix_len(&a), 128);
+
+        let c = TestedOdd::NONE.clone().insert_range(0..23).build();
+        let d = TestedOdd::NONE.clone().insert_range(..24).build();
+        assert_eq!(c.common_prefix_len(&d), 23);
+        assert_eq!(c.first_difference(&c), None);
+    }
+
+    #[test]
+    fn merge3() {
+        let base = Tested16::from_ranges([0..10, 64..80]);
+        let ours = Tested16::from_ranges([0..5, 64..80, 100..110]);
+        let theirs = Tested16::from_ranges([0..10, 64..70, 105..120]);
+        let (merged, concurrent) = Tested16::merge3(&base, &ours, &theirs);
+
+        assert_eq!(
+            merged.to_ranges().collect::<Vec<_>>(),
+            [0..5, 64..70, 100..120]
+        );
+        assert_eq!(concurrent.to_ranges().next(), Some(105..110));
+        assert_eq!(concurrent.count_ones(), 5);
+
+        let (merged, concurrent) = Tested16::merge3(&base, &base, &base);
+        assert_eq!(merged, base);
+        assert!(concurrent.is_empty());
+    }
+
+    #[test]
+    fn multiword_sizes() -> TestResult {
+        use crate::prelude::{Bitset128, Bitset64};
+
+        let low = Bitset128::new(u128::MAX);
+        let high = Bitset64::new(1 << 63);
+        let bitset: Byteset<24> = low.combine(high);
+        assert_eq!(bitset.count_ones(), 129);
+        assert_eq!(bitset.to_ranges().last(), Some(191..192));
+
+        let (a, b): (Bitset64, Bitset128) = bitset.split();
+        assert_eq!(
+            (a.into_inner(), b.into_inner()),
+            (u64::MAX, 1 << 127 | u64::MAX as u128)
+        );
+
+        let wide: Byteset<40> = bitset.combine(Bitset128::new(1));
+        assert_eq!(wide.count_ones(), 130);
+        let (narrow, rest): (Byteset<24>, Bitset128) = wide.split();
+        assert_eq!((&narrow, rest), (&bitset, Bitset128::new(1)));
+
+        let expanded: Byteset<128> = narrow.expand();
+        assert_eq!(expanded.to_ranges().collect::<Vec<_>>(), [0..128, 191..192]);
+        Ok(())
+    }
 }
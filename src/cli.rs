@@ -0,0 +1,230 @@
+//! Module containing [`BitsetValueParser`], `clap` value parser for [`Bitset`]s.
+
+use crate::{bitset::Bitset, index::Index, parse};
+use clap::{
+    builder::TypedValueParser,
+    error::{Error, ErrorKind},
+    Arg, Command,
+};
+use std::ffi::OsStr;
+
+/// [`TypedValueParser`] turning command line arguments into [`Bitset`]s.
+///
+/// Accepts integer literals: binary (`0b1010`), octal (`0o12`), hexadecimal (`0xA`) or decimal (`10`),
+/// where bit `i` of the integer is bit of the `Bitset` at [`Index`] `i`.
+/// Decimal integers are limited to 128 bits.
+///
+/// If flag names were provided with [`BitsetValueParser::flag`], also accepts comma-separated lists
+/// of them, like `read,write`, setting the bit of each flag.
+/// Errors on unknown flags list all valid flag names.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{cli::BitsetValueParser, prelude::*};
+/// use clap::{Arg, Command};
+///
+/// let command = Command::new("app").arg(
+///     Arg::new("perms").long("perms").value_parser(
+///         BitsetValueParser::<Bitset8>::new()
+///             .flag("read", 0.try_into()?)
+///             .flag("write", 1.try_into()?)
+///             .flag("exec", 2.try_into()?),
+///     ),
+/// );
+///
+/// let matches = command.clone().try_get_matches_from(["app", "--perms", "read,exec"])?;
+/// assert_eq!(matches.get_one::<Bitset8>("perms"), Some(&Bitset8::new(0b101)));
+///
+/// let matches = command.clone().try_get_matches_from(["app", "--perms", "0b11"])?;
+/// assert_eq!(matches.get_one::<Bitset8>("perms"), Some(&Bitset8::new(0b11)));
+///
+/// assert!(command.try_get_matches_from(["app", "--perms", "delete"]).is_err());
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitsetValueParser<T: Bitset> {
+    flags: Vec<(&'static str, Index<T>)>,
+}
+
+impl<T> BitsetValueParser<T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `BitsetValueParser`, accepting only integer literals.
+    pub fn new() -> Self {
+        Self { flags: Vec::new() }
+    }
+
+    /// Adds flag with `name`, which sets bit at `index`, to accepted values.
+    pub fn flag(mut self, name: &'static str, index: Index<T>) -> Self {
+        self.flags.push((name, index));
+        self
+    }
+
+    // Parses `value` into Bitset, or returns description of the error.
+    fn parse_str(&self, value: &str) -> Result<T, String> {
+        let value = value.trim();
+        let (digits, radix) = match value.get(..2) {
+            Some("0b" | "0B") => (&value[2..], 2),
+            Some("0o" | "0O") => (&value[2..], 8),
+            Some("0x" | "0X") => (&value[2..], 16),
+            _ => (value, 10),
+        };
+
+        if radix != 10 {
+            return parse::parse_radix(digits, radix, 2).map_err(|e| e.to_string());
+        }
+        if value.starts_with(|c: char| c.is_ascii_digit()) {
+            let int = u128::from_str_radix(&digits.replace('_', ""), radix)
+                .map_err(|e| format!("invalid integer: {e}"))?;
+            let bit_len = T::BYTE_SIZE * 8;
+            if bit_len < 128 && int >> bit_len != 0 {
+                return Err(format!("integer doesn't fit into {bit_len} bits"));
+            }
+
+            let mut bitset = T::NONE.clone();
+            for w in 0..bit_len.min(128).div_ceil(64) {
+                let width = (bit_len - w * 64).min(64);
+                bitset._set_bits(w * 64, width, (int >> (w * 64)) as u64);
+            }
+            return Ok(bitset);
+        }
+
+        let mut bitset = T::NONE.clone();
+        for name in value.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            match self.flags.iter().find(|(flag, _)| *flag == name) {
+                Some((_, index)) => {
+                    bitset.set(*index);
+                }
+                None if self.flags.is_empty() => {
+                    return Err("expected an integer".to_owned());
+                }
+                None => {
+                    let names: Vec<_> = self.flags.iter().map(|(flag, _)| *flag).collect();
+                    return Err(format!(
+                        "unknown flag '{name}', expected an integer or one of: {}",
+                        names.join(", ")
+                    ));
+                }
+            }
+        }
+        Ok(bitset)
+    }
+}
+
+impl<T> Default for BitsetValueParser<T>
+where
+    T: Bitset,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TypedValueParser for BitsetValueParser<T>
+where
+    T: Bitset + Send + Sync + 'static,
+{
+    type Value = T;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let arg = arg.map_or_else(|| "...".to_owned(), |a| a.to_string());
+        let value = value.to_str().ok_or_else(|| {
+            Error::raw(
+                ErrorKind::InvalidUtf8,
+                format!("invalid UTF-8 in '{arg}'\n"),
+            )
+            .with_cmd(cmd)
+        })?;
+
+        self.parse_str(value).map_err(|message| {
+            Error::raw(
+                ErrorKind::InvalidValue,
+                format!("invalid value '{value}' for '{arg}': {message}\n"),
+            )
+            .with_cmd(cmd)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset128, Bitset8, Byteset};
+    use std::error::Error;
+
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    fn parser() -> Result<BitsetValueParser<Bitset8>, Box<dyn Error>> {
+        Ok(BitsetValueParser::new()
+            .flag("read", 0.try_into()?)
+            .flag("write", 1.try_into()?)
+            .flag("exec", 2.try_into()?))
+    }
+
+    #[test]
+    fn integers() -> TestResult {
+        let parser = parser()?;
+
+        assert_eq!(parser.parse_str("0b1010")?, Bitset8::new(0b1010));
+        assert_eq!(parser.parse_str("0o12")?, Bitset8::new(0b1010));
+        assert_eq!(parser.parse_str("0xA")?, Bitset8::new(0b1010));
+        assert_eq!(parser.parse_str("10")?, Bitset8::new(0b1010));
+        assert_eq!(parser.parse_str("0b1111_0000")?, Bitset8::new(0b11110000));
+        assert!(parser.parse_str("256").is_err());
+        assert!(parser.parse_str("0b2").is_err());
+
+        let parser = BitsetValueParser::<Bitset128>::new();
+        assert_eq!(
+            parser.parse_str(&u128::MAX.to_string())?,
+            Bitset128::new(u128::MAX)
+        );
+
+        let parser = BitsetValueParser::<Byteset<3>>::new();
+        assert_eq!(parser.parse_str("0x010203")?, Byteset::new([3, 2, 1]));
+        assert!(parser.parse_str("0x01020304").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn flags() -> TestResult {
+        let parser = parser()?;
+
+        assert_eq!(parser.parse_str("read, exec")?, Bitset8::new(0b101));
+        assert_eq!(parser.parse_str("")?, Bitset8::new(0));
+        assert_eq!(
+            parser.parse_str("read,delete"),
+            Err(
+                "unknown flag 'delete', expected an integer or one of: read, write, exec"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            BitsetValueParser::<Bitset8>::new().parse_str("read"),
+            Err("expected an integer".to_owned())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn command() -> TestResult {
+        let command =
+            Command::new("app").arg(Arg::new("perms").long("perms").value_parser(parser()?));
+
+        let err = command
+            .try_get_matches_from(["app", "--perms", "read,delete"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert!(err.to_string().contains("one of: read, write, exec"));
+        Ok(())
+    }
+}
@@ -0,0 +1,373 @@
+//! Module containing [`BitCursor`] and [`BitCursorMut`], cursors for incremental walking over [`Bitset`] bits,
+//! e.g. for decoding and encoding bit-packed headers in place.
+
+use crate::{bit::Bit, bitset::Bitset, error::ConvResult, index::Index, iter::find_first};
+
+/// Cursor over the bits of the [`Bitset`], with position between `0` and the size of the `Bitset` in bits.
+///
+/// Position equal to the size of the `Bitset` is the end, past all of the bits.
+///
+/// Returned by [`Bitset::cursor`].
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::prelude::*;
+///
+/// let bitset = Bitset8::new(0b00111000);
+/// let mut cursor = bitset.cursor();
+///
+/// // Walk to the start of the run of set bits, and then to its end.
+/// let start = cursor.next_one().ok_or("no set bits")?;
+/// let end = cursor.next_zero().ok_or("run reaches the end")?;
+///
+/// assert_eq!((start.into_inner(), end.into_inner()), (3, 6));
+/// assert_eq!(cursor.position(), 7);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitCursor<'a, T: Bitset> {
+    bitset: &'a T,
+    position: usize,
+}
+
+impl<'a, T> BitCursor<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `BitCursor` over `bitset`, at position `0`.
+    pub fn new(bitset: &'a T) -> Self {
+        Self {
+            bitset,
+            position: 0,
+        }
+    }
+
+    /// Returns current position of the cursor.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns [`true`], if the cursor is past all of the bits, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_at_end(&self) -> bool {
+        self.position >= T::BYTE_SIZE * 8
+    }
+
+    /// Returns count of bits from the current position to the end.
+    #[inline(always)]
+    pub fn remaining_bits(&self) -> usize {
+        (T::BYTE_SIZE * 8).saturating_sub(self.position)
+    }
+
+    /// Moves the cursor to `index`. Returns a mutable reference to `self`.
+    #[inline(always)]
+    pub fn seek(&mut self, index: Index<T>) -> &mut Self {
+        self.position = index.into_inner();
+        self
+    }
+
+    /// Returns the bit at the current position as [`bool`] and moves the cursor to the next one,
+    /// or returns [`None`], if the cursor is at the end.
+    #[inline(always)]
+    pub fn read_bool(&mut self) -> Option<bool> {
+        self.advance().map(bool::from)
+    }
+
+    /// Returns `width` (up to 64) bits from the current position, converted into `V`,
+    /// and moves the cursor past them.
+    ///
+    /// # Errors
+    /// Returns [`ConvError`][crate::error::ConvError] and doesn't move the cursor,
+    /// if there are less than `width` bits remaining, or if the value doesn't fit into `V`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// // Version in bits 0..4, flag in bit 4 and length in bits 5..16.
+    /// let header = Byteset::<2>::new([0b00111010, 0b00000001]);
+    /// let mut cursor = header.cursor();
+    ///
+    /// assert_eq!(cursor.read::<u8>(4)?, 0b1010);
+    /// assert_eq!(cursor.read_bool(), Some(true));
+    /// assert_eq!(cursor.read::<u16>(11)?, 0b1001);
+    /// assert_eq!(cursor.remaining_bits(), 0);
+    /// assert!(cursor.read::<u8>(1).is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn read<V>(&mut self, width: usize) -> ConvResult<V>
+    where
+        V: TryFrom<u64>,
+    {
+        let value = self.bitset.extract_bits(self.position, width)?;
+        self.position += width;
+        Ok(value)
+    }
+
+    /// Returns the bit at the current position, or [`None`], if the cursor is at the end.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b00000010);
+    /// let mut cursor = bitset.cursor();
+    ///
+    /// assert_eq!(cursor.peek(), Some(Zero));
+    /// assert_eq!(cursor.seek(1.try_into()?).peek(), Some(One));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn peek(&self) -> Option<Bit> {
+        (!self.is_at_end()).then(|| self.bitset.bit(Index::<T>::from_usize(self.position)))
+    }
+
+    /// Returns the bit at the current position and moves the cursor to the next one,
+    /// or returns [`None`], if the cursor is at the end.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b00000010);
+    /// let mut cursor = bitset.cursor();
+    ///
+    /// assert_eq!(cursor.advance(), Some(Zero));
+    /// assert_eq!(cursor.advance(), Some(One));
+    /// assert_eq!(cursor.position(), 2);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn advance(&mut self) -> Option<Bit> {
+        let bit = self.peek()?;
+        self.position += 1;
+        Some(bit)
+    }
+
+    /// Finds the first set bit at or after the current position, and moves the cursor past it.
+    /// Returns [`Index`] of the found bit, or [`None`] and moves the cursor to the end, if there is no such bit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b00100100);
+    /// let mut cursor = bitset.cursor();
+    ///
+    /// assert_eq!(cursor.next_one(), Some(2.try_into()?));
+    /// assert_eq!(cursor.next_one(), Some(5.try_into()?));
+    /// assert_eq!(cursor.next_one(), None);
+    /// assert!(cursor.is_at_end());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn next_one(&mut self) -> Option<Index<T>> {
+        let bitset = self.bitset;
+        self.find(|w| bitset._word(w))
+    }
+
+    /// Finds the first not set bit at or after the current position, and moves the cursor past it.
+    /// Returns [`Index`] of the found bit, or [`None`] and moves the cursor to the end, if there is no such bit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b11011111);
+    /// let mut cursor = bitset.cursor();
+    ///
+    /// assert_eq!(cursor.next_zero(), Some(5.try_into()?));
+    /// assert_eq!(cursor.next_zero(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn next_zero(&mut self) -> Option<Index<T>> {
+        let bitset = self.bitset;
+        self.find(|w| !bitset._word(w))
+    }
+
+    // Finds the first bit at or after the position, for which `word` returns set bit, and moves past it.
+    fn find(&mut self, word: impl Fn(usize) -> u64) -> Option<Index<T>> {
+        let end = T::BYTE_SIZE * 8;
+        match find_first(self.position.min(end), end, word) {
+            Some(i) => {
+                self.position = i + 1;
+                Some(Index::<T>::from_usize(i))
+            }
+            None => {
+                self.position = end;
+                None
+            }
+        }
+    }
+}
+
+/// Cursor for writing the bits of the [`Bitset`] in order,
+/// with position between `0` and the size of the `Bitset` in bits.
+///
+/// Returned by [`Bitset::cursor_mut`].
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::prelude::*;
+///
+/// let mut header = Byteset::<2>::NONE;
+/// header
+///     .cursor_mut()
+///     .write(4, 0b1010u8)?
+///     .write_bool(true)?
+///     .write(11, 0b1001u16)?;
+///
+/// assert_eq!(header, Byteset::new([0b00111010, 0b00000001]));
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BitCursorMut<'a, T: Bitset> {
+    bitset: &'a mut T,
+    position: usize,
+}
+
+impl<'a, T> BitCursorMut<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `BitCursorMut` over `bitset`, at position `0`.
+    pub fn new(bitset: &'a mut T) -> Self {
+        Self {
+            bitset,
+            position: 0,
+        }
+    }
+
+    /// Returns current position of the cursor.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns [`true`], if the cursor is past all of the bits, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_at_end(&self) -> bool {
+        self.position >= T::BYTE_SIZE * 8
+    }
+
+    /// Returns count of bits from the current position to the end.
+    #[inline(always)]
+    pub fn remaining_bits(&self) -> usize {
+        (T::BYTE_SIZE * 8).saturating_sub(self.position)
+    }
+
+    /// Moves the cursor to `index`. Returns a mutable reference to `self`.
+    #[inline(always)]
+    pub fn seek(&mut self, index: Index<T>) -> &mut Self {
+        self.position = index.into_inner();
+        self
+    }
+
+    /// Writes `value` into the bit at the current position and moves the cursor to the next one.
+    /// Returns a mutable reference to `self`.
+    ///
+    /// # Errors
+    /// Returns [`ConvError`][crate::error::ConvError], if the cursor is at the end.
+    #[inline(always)]
+    pub fn write_bool(&mut self, value: bool) -> ConvResult<&mut Self> {
+        self.write(1, value)
+    }
+
+    /// Writes `value` into `width` (up to 64) bits from the current position and moves the cursor past them.
+    /// Returns a mutable reference to `self`.
+    ///
+    /// # Errors
+    /// Returns [`ConvError`][crate::error::ConvError] and doesn't change anything,
+    /// if there are less than `width` bits remaining, or if `value` doesn't fit into `width` bits.
+    pub fn write<V>(&mut self, width: usize, value: V) -> ConvResult<&mut Self>
+    where
+        V: Into<u64>,
+    {
+        self.bitset.insert_bits(self.position, width, value)?;
+        self.position += width;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::Byteset;
+
+    #[test]
+    fn walk() {
+        let bitset = Byteset::<3>::from_ranges([0..2, 10..20]);
+        let mut cursor = bitset.cursor();
+
+        assert_eq!(cursor.advance(), Some(Bit::One));
+        assert_eq!(cursor.next_zero().map(|i| i.into_inner()), Some(2));
+        assert_eq!(cursor.next_one().map(|i| i.into_inner()), Some(10));
+        assert_eq!(cursor.next_zero().map(|i| i.into_inner()), Some(20));
+        assert_eq!(cursor.next_one(), None);
+        assert!(cursor.is_at_end());
+        assert_eq!(cursor.peek(), None);
+        assert_eq!(cursor.advance(), None);
+
+        cursor.seek(Index::from_usize(15));
+        assert_eq!(cursor.position(), 15);
+        assert_eq!(cursor.peek(), Some(Bit::One));
+        assert_eq!(cursor.next_zero().map(|i| i.into_inner()), Some(20));
+        assert_eq!(cursor.next_zero().map(|i| i.into_inner()), Some(21));
+    }
+
+    #[test]
+    fn read_write() -> ConvResult<()> {
+        let mut bitset = Byteset::<12>::NONE;
+        let mut writer = bitset.cursor_mut();
+
+        writer
+            .write(3, 0b101u8)?
+            .write(64, u64::MAX)?
+            .write_bool(true)?;
+        assert_eq!(writer.position(), 68);
+        assert!(writer.write(29, 0u32).is_err());
+        writer.seek(Index::from_usize(90)).write(6, 0b110011u8)?;
+        assert!(writer.is_at_end());
+        assert!(writer.write_bool(false).is_err());
+
+        let mut reader = bitset.cursor();
+        assert_eq!(reader.read::<u8>(3)?, 0b101);
+        assert_eq!(reader.read::<u64>(64)?, u64::MAX);
+        assert_eq!(reader.read_bool(), Some(true));
+        assert!(reader.read::<u32>(29).is_err());
+        assert_eq!(reader.position(), 68);
+        assert_eq!(reader.remaining_bits(), 28);
+        reader.seek(Index::from_usize(90));
+        assert_eq!(reader.read::<u8>(6)?, 0b110011);
+        assert_eq!(reader.read_bool(), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,363 @@
+//! Module containing runtime CPU feature dispatch for bulk operations over bytes.
+//!
+//! Every operation has a portable implementation, which is also compiled with additional
+//! target features enabled (`popcnt` for counting, `avx2` for bitwise operations, `bmi2` for selection, deposit and extraction,
+//! `pclmulqdq` for carry-less multiplication).
+//! The best available version is chosen at runtime, so published binaries get the fast paths
+//! without requiring `-C target-cpu=native`. Detected features are cached after the first call.
+//!
+//! All of the dispatching functions are internal to the crate.
+
+// Returns, whether the CPU supports target `feature`.
+// Detection runs once per call site, its result is cached for the following calls.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+macro_rules! detected {
+    ($feature:tt) => {{
+        static DETECTED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+        *DETECTED.get_or_init(|| std::is_x86_feature_detected!($feature))
+    }};
+}
+
+// Generates a crate-visible dispatching function and its portable and feature-enabled versions
+// for a bitwise operation over two byte slices of equal length.
+macro_rules! binary_op {
+    ($name:ident, $portable:ident, $avx2:ident, |$dst:ident, $src:ident| $op:expr) => {
+        #[inline(always)]
+        fn $portable(dst: &mut [u8], src: &[u8]) {
+            for ($dst, &$src) in dst.iter_mut().zip(src) {
+                $op;
+            }
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[target_feature(enable = "avx2")]
+        unsafe fn $avx2(dst: &mut [u8], src: &[u8]) {
+            $portable(dst, src)
+        }
+
+        #[inline]
+        pub(crate) fn $name(dst: &mut [u8], src: &[u8]) {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            if dst.len() >= 32 && detected!("avx2") {
+                // SAFETY: `avx2` support was detected at runtime.
+                return unsafe { $avx2(dst, src) };
+            }
+            $portable(dst, src)
+        }
+    };
+}
+
+binary_op!(and, and_portable, and_avx2, |d, s| *d &= s);
+binary_op!(or, or_portable, or_avx2, |d, s| *d |= s);
+binary_op!(xor, xor_portable, xor_avx2, |d, s| *d ^= s);
+binary_op!(and_not, and_not_portable, and_not_avx2, |d, s| *d &= !s);
+
+#[inline(always)]
+fn count_ones_portable(bytes: &[u8]) -> usize {
+    let chunks = bytes.chunks_exact(8);
+    let rest = chunks
+        .remainder()
+        .iter()
+        .fold(0, |acc, &byte| acc + byte.count_ones());
+
+    chunks.fold(rest, |acc, chunk| {
+        acc + u64::from_ne_bytes(chunk.try_into().unwrap()).count_ones()
+    }) as usize
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "popcnt")]
+unsafe fn count_ones_popcnt(bytes: &[u8]) -> usize {
+    count_ones_portable(bytes)
+}
+
+/// Returns the number of set bits in `bytes`.
+#[inline]
+pub(crate) fn count_ones(bytes: &[u8]) -> usize {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if bytes.len() >= 8 && detected!("popcnt") {
+        // SAFETY: `popcnt` support was detected at runtime.
+        return unsafe { count_ones_popcnt(bytes) };
+    }
+    count_ones_portable(bytes)
+}
+
+// Generates a crate-visible dispatching function and its portable and feature-enabled versions
+// for counting set bits of a bitwise operation over two byte slices of equal length.
+macro_rules! count_op {
+    ($name:ident, $portable:ident, $popcnt:ident, |$a:ident, $b:ident| $op:expr) => {
+        #[inline(always)]
+        fn $portable(a: &[u8], b: &[u8]) -> usize {
+            let (a_chunks, b_chunks) = (a.chunks_exact(8), b.chunks_exact(8));
+            let rest = a_chunks
+                .remainder()
+                .iter()
+                .zip(b_chunks.remainder())
+                .fold(0, |acc, (&$a, &$b)| acc + ($op).count_ones());
+
+            a_chunks.zip(b_chunks).fold(rest, |acc, (a, b)| {
+                let $a = u64::from_ne_bytes(a.try_into().unwrap());
+                let $b = u64::from_ne_bytes(b.try_into().unwrap());
+                acc + ($op).count_ones()
+            }) as usize
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[target_feature(enable = "popcnt")]
+        unsafe fn $popcnt(a: &[u8], b: &[u8]) -> usize {
+            $portable(a, b)
+        }
+
+        #[inline]
+        pub(crate) fn $name(a: &[u8], b: &[u8]) -> usize {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            if a.len() >= 8 && detected!("popcnt") {
+                // SAFETY: `popcnt` support was detected at runtime.
+                return unsafe { $popcnt(a, b) };
+            }
+            $portable(a, b)
+        }
+    };
+}
+
+count_op!(count_and, count_and_portable, count_and_popcnt, |a, b| a
+    & b);
+count_op!(count_or, count_or_portable, count_or_popcnt, |a, b| a | b);
+count_op!(count_xor, count_xor_portable, count_xor_popcnt, |a, b| a
+    ^ b);
+count_op!(
+    count_and_not,
+    count_and_not_portable,
+    count_and_not_popcnt,
+    |a, b| a & !b
+);
+
+#[inline(always)]
+fn select_portable(mut word: u64, n: u32) -> u32 {
+    for _ in 0..n {
+        word &= word - 1;
+    }
+    word.trailing_zeros()
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+unsafe fn select_bmi2(word: u64, n: u32) -> u32 {
+    std::arch::x86_64::_pdep_u64(1 << n, word).trailing_zeros()
+}
+
+/// Returns position of the `n`-th (starting from 0) set bit of `word`, which has more than `n` set bits.
+#[inline]
+pub(crate) fn select(word: u64, n: u32) -> u32 {
+    debug_assert!(n < word.count_ones());
+    #[cfg(target_arch = "x86_64")]
+    if detected!("bmi2") {
+        // SAFETY: `bmi2` support was detected at runtime.
+        return unsafe { select_bmi2(word, n) };
+    }
+    select_portable(word, n)
+}
+
+#[inline(always)]
+fn deposit_portable(word: u64, mut mask: u64) -> u64 {
+    let (mut result, mut bit) = (0, 1);
+    while mask != 0 {
+        if word & bit != 0 {
+            result |= mask & mask.wrapping_neg();
+        }
+        mask &= mask - 1;
+        bit <<= 1;
+    }
+    result
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+unsafe fn deposit_bmi2(word: u64, mask: u64) -> u64 {
+    std::arch::x86_64::_pdep_u64(word, mask)
+}
+
+/// Deposits low bits of `word` to positions of set bits of `mask`, from the least significant one.
+#[inline]
+pub(crate) fn deposit(word: u64, mask: u64) -> u64 {
+    #[cfg(target_arch = "x86_64")]
+    if detected!("bmi2") {
+        // SAFETY: `bmi2` support was detected at runtime.
+        return unsafe { deposit_bmi2(word, mask) };
+    }
+    deposit_portable(word, mask)
+}
+
+#[inline(always)]
+fn extract_portable(word: u64, mut mask: u64) -> u64 {
+    let (mut result, mut bit) = (0, 1);
+    while mask != 0 {
+        if word & mask & mask.wrapping_neg() != 0 {
+            result |= bit;
+        }
+        mask &= mask - 1;
+        bit <<= 1;
+    }
+    result
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+unsafe fn extract_bmi2(word: u64, mask: u64) -> u64 {
+    std::arch::x86_64::_pext_u64(word, mask)
+}
+
+/// Extracts bits of `word` at positions of set bits of `mask`, packing them into low bits of the result.
+#[inline]
+pub(crate) fn extract(word: u64, mask: u64) -> u64 {
+    #[cfg(target_arch = "x86_64")]
+    if detected!("bmi2") {
+        // SAFETY: `bmi2` support was detected at runtime.
+        return unsafe { extract_bmi2(word, mask) };
+    }
+    extract_portable(word, mask)
+}
+
+#[inline(always)]
+fn clmul_portable(a: u64, b: u64) -> (u64, u64) {
+    let (mut low, mut high) = (0, 0);
+    for i in 0..64 {
+        if b >> i & 1 == 1 {
+            low ^= a << i;
+            if i > 0 {
+                high ^= a >> (64 - i);
+            }
+        }
+    }
+    (low, high)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn clmul_pclmulqdq(a: u64, b: u64) -> (u64, u64) {
+    use std::arch::x86_64::{
+        _mm_clmulepi64_si128, _mm_cvtsi128_si64, _mm_set_epi64x, _mm_unpackhi_epi64,
+    };
+
+    let product = _mm_clmulepi64_si128(_mm_set_epi64x(0, a as i64), _mm_set_epi64x(0, b as i64), 0);
+    (
+        _mm_cvtsi128_si64(product) as u64,
+        _mm_cvtsi128_si64(_mm_unpackhi_epi64(product, product)) as u64,
+    )
+}
+
+/// Returns low and high words of carry-less product of `a` and `b`.
+#[inline]
+pub(crate) fn clmul(a: u64, b: u64) -> (u64, u64) {
+    #[cfg(target_arch = "x86_64")]
+    if detected!("pclmulqdq") {
+        // SAFETY: `pclmulqdq` support was detected at runtime.
+        return unsafe { clmul_pclmulqdq(a, b) };
+    }
+    clmul_portable(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(len: usize, seed: u8) -> Vec<u8> {
+        (0..len)
+            .map(|i| (i as u8).wrapping_mul(37).wrapping_add(seed))
+            .collect()
+    }
+
+    #[test]
+    fn count_ones_matches_portable() {
+        for len in [0, 1, 7, 8, 9, 64, 100] {
+            let bytes = sample(len, 5);
+            let expected: usize = bytes.iter().map(|b| b.count_ones() as usize).sum();
+
+            assert_eq!(count_ones(&bytes), expected);
+        }
+    }
+
+    #[test]
+    fn binary_ops_match_portable() {
+        for len in [1, 31, 32, 33, 128] {
+            let a = sample(len, 3);
+            let b = sample(len, 11);
+
+            let mut dst = a.clone();
+            and(&mut dst, &b);
+            assert!(dst.iter().zip(&a).zip(&b).all(|((&d, &a), &b)| d == a & b));
+
+            let mut dst = a.clone();
+            or(&mut dst, &b);
+            assert!(dst.iter().zip(&a).zip(&b).all(|((&d, &a), &b)| d == a | b));
+
+            let mut dst = a.clone();
+            xor(&mut dst, &b);
+            assert!(dst.iter().zip(&a).zip(&b).all(|((&d, &a), &b)| d == a ^ b));
+
+            let mut dst = a.clone();
+            and_not(&mut dst, &b);
+            assert!(dst.iter().zip(&a).zip(&b).all(|((&d, &a), &b)| d == a & !b));
+        }
+    }
+
+    #[test]
+    fn count_ops_match_portable() {
+        for len in [0, 1, 7, 8, 9, 64, 100] {
+            let a = sample(len, 3);
+            let b = sample(len, 11);
+            let count = |op: fn(u8, u8) -> u8| -> usize {
+                a.iter()
+                    .zip(&b)
+                    .map(|(&a, &b)| op(a, b).count_ones() as usize)
+                    .sum()
+            };
+
+            assert_eq!(count_and(&a, &b), count(|a, b| a & b));
+            assert_eq!(count_or(&a, &b), count(|a, b| a | b));
+            assert_eq!(count_xor(&a, &b), count(|a, b| a ^ b));
+            assert_eq!(count_and_not(&a, &b), count(|a, b| a & !b));
+        }
+    }
+
+    #[test]
+    fn select_matches_portable() {
+        for word in [1, 0x8000000000000000, 0xF0F0F0F0F0F0F0F0, u64::MAX] {
+            for n in 0..word.count_ones() {
+                assert_eq!(select(word, n), select_portable(word, n));
+            }
+        }
+        assert_eq!(select(0b10110, 2), 4);
+    }
+
+    #[test]
+    fn deposit_extract_match_portable() {
+        for mask in [
+            0,
+            1,
+            0x8000000000000000,
+            0xF0F0F0F0F0F0F0F0,
+            0x0123456789ABCDEF,
+            u64::MAX,
+        ] {
+            for word in [0, 0b1011, 0xDEADBEEF, u64::MAX] {
+                assert_eq!(deposit(word, mask), deposit_portable(word, mask));
+                assert_eq!(extract(word, mask), extract_portable(word, mask));
+                assert_eq!(deposit(extract(word, mask), mask), word & mask);
+            }
+        }
+        assert_eq!(deposit(0b101, 0b11010), 0b10010);
+        assert_eq!(extract(0b10010, 0b11010), 0b101);
+    }
+
+    #[test]
+    fn clmul_matches_portable() {
+        for a in [0, 1, 0b1011, 0xDEADBEEF, 0x8000000000000001, u64::MAX] {
+            for b in [0, 1, 0b11, 0x0123456789ABCDEF, u64::MAX] {
+                assert_eq!(clmul(a, b), clmul_portable(a, b));
+            }
+        }
+        assert_eq!(clmul(0b11, 0b11), (0b101, 0));
+        assert_eq!(clmul(1 << 63, 1 << 63), (0, 1 << 62));
+    }
+}
@@ -0,0 +1,301 @@
+//! Module containing [`BitsetDisplay`], configurable formatting adapter for [`Bitset`]s.
+
+use crate::bitset::{bit_len, Bitset};
+use std::fmt::Display;
+
+// Layout of the formatted bits.
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    group_bits: usize,
+    groups_per_line: usize,
+    offsets: bool,
+    msb_first: bool,
+    separator: char,
+}
+
+impl Layout {
+    // Writes bits of `bitset` into `f`, split into groups and lines.
+    // Groups and lines are aligned to Index 0, regardless of the order.
+    fn write<T>(&self, f: &mut std::fmt::Formatter<'_>, bitset: &T) -> std::fmt::Result
+    where
+        T: Bitset,
+    {
+        let bit_len = bit_len::<T>();
+        let line_bits = self.group_bits.saturating_mul(self.groups_per_line);
+        let mut lines: Vec<_> = (0..bit_len)
+            .step_by(line_bits)
+            .map(|start| start..(start.saturating_add(line_bits)).min(bit_len))
+            .collect();
+        if self.msb_first {
+            lines.reverse();
+        }
+
+        let first_bit = |line: &std::ops::Range<usize>| match self.msb_first {
+            true => line.end - 1,
+            false => line.start,
+        };
+        let offset_width = lines
+            .iter()
+            .map(|line| first_bit(line).to_string().len())
+            .max()
+            .unwrap_or(1);
+
+        for (n, line) in lines.iter().enumerate() {
+            if n > 0 {
+                writeln!(f)?;
+            }
+            if self.offsets {
+                write!(f, "{:>offset_width$}: ", first_bit(line))?;
+            }
+            let mut write_bit = |i: usize, group_start: bool| {
+                if group_start {
+                    write!(f, "{}", self.separator)?;
+                }
+                write!(f, "{}", bitset._bits(i, 1))
+            };
+            if self.msb_first {
+                for i in line.clone().rev() {
+                    write_bit(i, i + 1 < line.end && (i + 1) % self.group_bits == 0)?;
+                }
+            } else {
+                for i in line.clone() {
+                    write_bit(i, i > line.start && i % self.group_bits == 0)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adapter for configurable [`Display`] of the [`Bitset`], returned by [`Bitset::display`].
+///
+/// Bits are split into groups of `group_bits` bits, separated with `separator`,
+/// with `groups_per_line` groups on each line. Groups and lines are aligned to [`Index`][crate::index::Index] `0`.
+/// Each line is optionally prefixed with the index of its first bit.
+///
+/// By default, bits are printed on a single line, from the most significant one, without grouping,
+/// same as with the `Display` of the `Bitset`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::prelude::{Bitset, Bitset16};
+///
+/// let bitset = Bitset16::new(0b1111000010100101);
+///
+/// assert_eq!(bitset.display().to_string(), "1111000010100101");
+/// assert_eq!(
+///     bitset.display().group_bits(4).separator('_').to_string(),
+///     "1111_0000_1010_0101"
+/// );
+/// assert_eq!(
+///     bitset.display().group_bits(4).msb_first(false).to_string(),
+///     "1010 0101 0000 1111"
+/// );
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BitsetDisplay<'a, T: Bitset> {
+    bitset: &'a T,
+    layout: Layout,
+}
+
+impl<'a, T> BitsetDisplay<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `BitsetDisplay` with default settings.
+    pub fn new(bitset: &'a T) -> Self {
+        Self {
+            bitset,
+            layout: Layout {
+                group_bits: bit_len::<T>().max(1),
+                groups_per_line: usize::MAX,
+                offsets: false,
+                msb_first: true,
+                separator: ' ',
+            },
+        }
+    }
+
+    /// Sets the number of bits in each group.
+    ///
+    /// # Panics
+    /// Panics, if `group_bits` is 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b00001111);
+    ///
+    /// assert_eq!(bitset.display().group_bits(3).to_string(), "00 001 111");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn group_bits(mut self, group_bits: usize) -> Self {
+        assert!(group_bits > 0, "group_bits must be positive");
+        self.layout.group_bits = group_bits;
+        self
+    }
+
+    /// Sets the number of groups on each line.
+    ///
+    /// # Panics
+    /// Panics, if `groups_per_line` is 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let bitset = Bitset16::new(0b1111000000001010);
+    ///
+    /// assert_eq!(
+    ///     bitset.display().group_bits(4).groups_per_line(2).to_string(),
+    ///     "1111 0000\n0000 1010"
+    /// );
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn groups_per_line(mut self, groups_per_line: usize) -> Self {
+        assert!(groups_per_line > 0, "groups_per_line must be positive");
+        self.layout.groups_per_line = groups_per_line;
+        self
+    }
+
+    /// Sets whether each line is prefixed with the index of its first bit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset16};
+    ///
+    /// let bitset = Bitset16::new(0b1111000000001010);
+    ///
+    /// assert_eq!(
+    ///     bitset.display().group_bits(8).groups_per_line(1).offsets(true).to_string(),
+    ///     "15: 11110000\n 7: 00001010"
+    /// );
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn offsets(mut self, offsets: bool) -> Self {
+        self.layout.offsets = offsets;
+        self
+    }
+
+    /// Sets whether bits are printed from the most significant one, or from the least significant one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b00000011);
+    ///
+    /// assert_eq!(bitset.display().msb_first(false).to_string(), "11000000");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn msb_first(mut self, msb_first: bool) -> Self {
+        self.layout.msb_first = msb_first;
+        self
+    }
+
+    /// Sets the character, separating groups.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::{Bitset, Bitset8};
+    ///
+    /// let bitset = Bitset8::new(0b11110000);
+    ///
+    /// assert_eq!(bitset.display().group_bits(4).separator('_').to_string(), "1111_0000");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn separator(mut self, separator: char) -> Self {
+        self.layout.separator = separator;
+        self
+    }
+}
+
+impl<'a, T> Display for BitsetDisplay<'a, T>
+where
+    T: Bitset,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.layout.write(f, self.bitset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset256, Bitset32, Byteset};
+
+    #[test]
+    fn layouts() {
+        let bitset = Bitset32::new(0x8000_00F1);
+        assert_eq!(bitset.display().to_string(), bitset.to_string());
+
+        let s = bitset
+            .display()
+            .group_bits(8)
+            .groups_per_line(2)
+            .offsets(true)
+            .to_string();
+        assert_eq!(s, "31: 10000000 00000000\n15: 00000000 11110001");
+
+        let s = bitset
+            .display()
+            .group_bits(12)
+            .separator('|')
+            .msb_first(false)
+            .to_string();
+        assert_eq!(s, "100011110000|000000000000|00000001");
+
+        let s = bitset.display().group_bits(12).to_string();
+        assert_eq!(s, "10000000|000000000000|000011110001".replace('|', " "));
+
+        let bitset = Byteset::<3>::new([0b11111111, 0, 0b00000001]);
+        let s = bitset
+            .display()
+            .group_bits(5)
+            .groups_per_line(2)
+            .to_string();
+        assert_eq!(s, "0000\n00010 00000\n00111 11111");
+        assert_eq!(
+            bitset.display().group_bits(8).to_string(),
+            "00000001 00000000 11111111"
+        );
+
+        let bitset = Bitset256::new([1, 1 << 127]);
+        let s = bitset
+            .display()
+            .group_bits(64)
+            .groups_per_line(1)
+            .offsets(true)
+            .to_string();
+        let lines: Vec<_> = s.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], format!("255: 1{}", "0".repeat(63)));
+        assert_eq!(lines[3], format!(" 63: {}1", "0".repeat(63)));
+    }
+}
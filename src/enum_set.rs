@@ -0,0 +1,476 @@
+//! Module containing [`EnumSet`], set of [`FlagsEnum`] variants.
+
+use crate::{bitset::Bitset, flags_enum::FlagsEnum};
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{BitAnd, BitOr, BitXor, Not, Sub},
+};
+
+/// Set of variants of [`FlagsEnum`] `T`, stored in bits of its [`Bitset`].
+///
+/// With feature `"serde"`, serializes as a list of variant names.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{enum_set::EnumSet, flags_enum::FlagsEnum, prelude::*};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Day {
+///     Monday,
+///     Tuesday,
+///     Wednesday,
+/// }
+///
+/// impl FlagsEnum for Day {
+///     type Bitset = Bitset8;
+///     const VARIANTS: &'static [Self] = &[Day::Monday, Day::Tuesday, Day::Wednesday];
+///
+///     fn index(self) -> Index8 {
+///         Index8::from_usize(self as usize)
+///     }
+///
+///     fn name(self) -> &'static str {
+///         match self {
+///             Day::Monday => "Monday",
+///             Day::Tuesday => "Tuesday",
+///             Day::Wednesday => "Wednesday",
+///         }
+///     }
+/// }
+///
+/// let mut days = EnumSet::new();
+/// days.insert(Day::Monday);
+/// days.insert(Day::Wednesday);
+///
+/// assert!(days.contains(Day::Monday));
+/// assert_eq!(days.iter().collect::<Vec<_>>(), [Day::Monday, Day::Wednesday]);
+/// assert_eq!(days.complement().iter().collect::<Vec<_>>(), [Day::Tuesday]);
+/// #   Ok(())
+/// # }
+/// ```
+pub struct EnumSet<T: FlagsEnum> {
+    bitset: T::Bitset,
+}
+
+impl<T> EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    /// Constructs a new empty value of `EnumSet`.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            bitset: T::Bitset::NONE.clone(),
+        }
+    }
+
+    /// Constructs a new value of `EnumSet`, containing all of the variants.
+    pub fn all() -> Self {
+        T::VARIANTS.iter().copied().collect()
+    }
+
+    /// Constructs a new value of `EnumSet` from `bitset`, dropping bits, that aren't variants.
+    pub fn from_bitset_truncate(bitset: T::Bitset) -> Self {
+        Self {
+            bitset: bitset.intersection(Self::all().bitset),
+        }
+    }
+
+    /// Returns the underlying `Bitset`.
+    #[inline(always)]
+    pub fn bitset(&self) -> &T::Bitset {
+        &self.bitset
+    }
+
+    /// Returns the underlying `Bitset`.
+    #[inline(always)]
+    pub fn into_bitset(self) -> T::Bitset {
+        self.bitset
+    }
+
+    /// Inserts `value` into `EnumSet`.
+    /// Returns [`true`], if `value` was not contained before, and [`false`] otherwise.
+    pub fn insert(&mut self, value: T) -> bool {
+        let contained = self.contains(value);
+        self.bitset.set(value.index());
+        !contained
+    }
+
+    /// Removes `value` from `EnumSet`.
+    /// Returns [`true`], if `value` was contained before, and [`false`] otherwise.
+    pub fn remove(&mut self, value: T) -> bool {
+        let contained = self.contains(value);
+        self.bitset.unset(value.index());
+        contained
+    }
+
+    /// Returns [`true`], if `EnumSet` contains `value`, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn contains(&self, value: T) -> bool {
+        self.bitset.bit(value.index()).into()
+    }
+
+    /// Returns count of variants in `EnumSet`.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.bitset.count_ones()
+    }
+
+    /// Returns [`true`], if `EnumSet` is empty, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.bitset.is_empty()
+    }
+
+    /// Returns iterator over variants in `EnumSet`, in ascending order of their [`indeces`][crate::index::Index].
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + '_ {
+        self.bitset.ones().filter_map(T::try_from_index)
+    }
+
+    /// Returns Set complement of `EnumSet`, limited to the variants of `T`.
+    #[inline(always)]
+    pub fn complement(&self) -> Self {
+        Self::all() - self.clone()
+    }
+
+    /// Returns [`true`], if all variants of `other` are in `self`, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn includes(&self, other: &Self) -> bool {
+        self.bitset.includes(&other.bitset)
+    }
+
+    /// Returns [`true`], if `self` shares any variants with `other`, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.bitset.intersects(&other.bitset)
+    }
+}
+
+impl<T> Default for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bitset: self.bitset.clone(),
+        }
+    }
+}
+
+impl<T> Copy for EnumSet<T>
+where
+    T: FlagsEnum,
+    T::Bitset: Copy,
+{
+}
+
+impl<T> PartialEq for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.bitset == other.bitset
+    }
+}
+
+impl<T> Eq for EnumSet<T> where T: FlagsEnum {}
+
+impl<T> Hash for EnumSet<T>
+where
+    T: FlagsEnum,
+    T::Bitset: Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bitset.hash(state);
+    }
+}
+
+impl<T> Debug for EnumSet<T>
+where
+    T: FlagsEnum + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T> From<T> for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    fn from(value: T) -> Self {
+        Self {
+            bitset: value.bitset(),
+        }
+    }
+}
+
+impl<T> FromIterator<T> for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T> Extend<T> for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> BitOr for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            bitset: self.bitset.union(rhs.bitset),
+        }
+    }
+}
+
+impl<T> BitAnd for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            bitset: self.bitset.intersection(rhs.bitset),
+        }
+    }
+}
+
+impl<T> BitXor for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self {
+            bitset: self.bitset.sym_difference(rhs.bitset),
+        }
+    }
+}
+
+impl<T> Sub for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            bitset: self.bitset.difference(rhs.bitset),
+        }
+    }
+}
+
+impl<T> Not for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter().map(T::name))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for EnumSet<T>
+where
+    T: FlagsEnum,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct EnumSetVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for EnumSetVisitor<T>
+        where
+            T: FlagsEnum,
+        {
+            type Value = EnumSet<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a list of variant names")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut set = EnumSet::new();
+                while let Some(name) = seq.next_element::<std::borrow::Cow<'de, str>>()? {
+                    let value = T::VARIANTS
+                        .iter()
+                        .copied()
+                        .find(|v| v.name() == name)
+                        .ok_or_else(|| {
+                            // Names of variants aren't available as a static list for `unknown_variant`.
+                            let expected = T::VARIANTS
+                                .iter()
+                                .map(|v| format!("`{}`", v.name()))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            serde::de::Error::custom(format!(
+                                "unknown variant `{name}`, expected one of {expected}"
+                            ))
+                        })?;
+                    set.insert(value);
+                }
+                Ok(set)
+            }
+        }
+
+        deserializer.deserialize_seq(EnumSetVisitor(std::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset8, Index8};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Suit {
+        Clubs,
+        Diamonds,
+        Hearts = 5,
+        Spades,
+    }
+
+    impl FlagsEnum for Suit {
+        type Bitset = Bitset8;
+        const VARIANTS: &'static [Self] =
+            &[Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+        fn index(self) -> Index8 {
+            Index8::from_usize(self as usize)
+        }
+
+        fn name(self) -> &'static str {
+            match self {
+                Suit::Clubs => "Clubs",
+                Suit::Diamonds => "Diamonds",
+                Suit::Hearts => "Hearts",
+                Suit::Spades => "Spades",
+            }
+        }
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut set = EnumSet::new();
+
+        assert!(set.insert(Suit::Hearts));
+        assert!(!set.insert(Suit::Hearts));
+        assert!(set.insert(Suit::Clubs));
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(Suit::Clubs));
+        assert!(!set.remove(Suit::Clubs));
+        assert_eq!(set, EnumSet::from(Suit::Hearts));
+        assert_eq!(set.bitset().into_inner(), 0b100000);
+        assert_eq!(format!("{set:?}"), "{Hearts}");
+    }
+
+    #[test]
+    fn operations() {
+        let red: EnumSet<_> = [Suit::Diamonds, Suit::Hearts].into_iter().collect();
+        let black = !red;
+
+        assert_eq!(
+            black.iter().collect::<Vec<_>>(),
+            [Suit::Clubs, Suit::Spades]
+        );
+        assert_eq!(red | black, EnumSet::all());
+        assert!((red & black).is_empty());
+        assert_eq!(red ^ EnumSet::all(), black);
+        assert_eq!(EnumSet::all() - black, red);
+        assert!(EnumSet::all().includes(&red));
+        assert!(!red.intersects(&black));
+        assert_eq!(
+            EnumSet::<Suit>::from_bitset_truncate(Bitset8::new(0b11111111)),
+            EnumSet::all()
+        );
+        assert_eq!(
+            EnumSet::<Suit>::all().iter().next_back(),
+            Some(Suit::Spades)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), serde_json::Error> {
+        let set: EnumSet<Suit> = [Suit::Spades, Suit::Clubs].into_iter().collect();
+        let json = serde_json::to_string(&set)?;
+
+        assert_eq!(json, r#"["Clubs","Spades"]"#);
+        assert_eq!(serde_json::from_str::<EnumSet<Suit>>(&json)?, set);
+        assert_eq!(
+            serde_json::from_str::<EnumSet<Suit>>(r#"["Spades","Clubs","Spades"]"#)?,
+            set
+        );
+        assert_eq!(serde_json::from_str::<EnumSet<Suit>>("[]")?, EnumSet::new());
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_errors() {
+        let err = serde_json::from_str::<EnumSet<Suit>>(r#"["Clubs","Jokers"]"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown variant `Jokers`, expected one of `Clubs`, `Diamonds`, `Hearts`, `Spades` at line 1 column 18"
+        );
+
+        assert!(serde_json::from_str::<EnumSet<Suit>>(r#""Clubs""#).is_err());
+        assert!(serde_json::from_str::<EnumSet<Suit>>("[1]").is_err());
+    }
+}
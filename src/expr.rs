@@ -0,0 +1,411 @@
+//! Module containing [`Expr`], boolean expressions over named [`Bitset`] columns,
+//! and [`ParseExprError`].
+
+use crate::bitset::Bitset;
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{BitAnd, BitOr, BitXor, Not},
+    str::FromStr,
+};
+
+/// Boolean expression over columns keyed by `K`.
+///
+/// Evaluated by [`BitmapIndex::query`][crate::bitmap_index::BitmapIndex::query] in a single pass
+/// over the columns, 64 rows at a time, without constructing intermediate `Bitset`s.
+///
+/// Can be constructed with [`Expr::column`] and operators `&`, `|`, `^` and `!`,
+/// or parsed from a string, like `"a & (b | !c)"`.
+/// Operator precedence follows Rust: `!` binds tightest, then `&`, then `^`, then `|`.
+///
+/// Parsed expressions are at most [`Expr::MAX_PARSE_DEPTH`] levels deep, counting `!`, parentheses
+/// and every operator of a chain, so evaluating untrusted input can't overflow the stack.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expr<K> {
+    /// Column with the key.
+    Column(K),
+    /// Negation of the expression.
+    Not(Box<Expr<K>>),
+    /// Conjunction of the expressions.
+    And(Box<Expr<K>>, Box<Expr<K>>),
+    /// Disjunction of the expressions.
+    Or(Box<Expr<K>>, Box<Expr<K>>),
+    /// Exclusive disjunction of the expressions.
+    Xor(Box<Expr<K>>, Box<Expr<K>>),
+}
+
+impl<K> Expr<K> {
+    /// Maximal depth of expressions parsed from strings.
+    pub const MAX_PARSE_DEPTH: usize = 256;
+
+    /// Constructs a new value of `Expr`, referring to the column with `key`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::expr::Expr;
+    ///
+    /// let expr = Expr::column("a") & !Expr::column("b");
+    ///
+    /// assert_eq!(expr, "a & !b".parse::<Expr<String>>()?.map(&|k| k.as_str()));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn column(key: K) -> Self {
+        Self::Column(key)
+    }
+
+    /// Returns the same expression with every key converted by `f`.
+    pub fn map<'a, R>(&'a self, f: &impl Fn(&'a K) -> R) -> Expr<R> {
+        match self {
+            Self::Column(key) => Expr::Column(f(key)),
+            Self::Not(e) => Expr::Not(Box::new(e.map(f))),
+            Self::And(a, b) => Expr::And(Box::new(a.map(f)), Box::new(b.map(f))),
+            Self::Or(a, b) => Expr::Or(Box::new(a.map(f)), Box::new(b.map(f))),
+            Self::Xor(a, b) => Expr::Xor(Box::new(a.map(f)), Box::new(b.map(f))),
+        }
+    }
+
+    // Evaluates the expression over a single word of every column, returned by `column`.
+    pub(crate) fn eval_word(&self, column: &impl Fn(&K) -> u64) -> u64 {
+        match self {
+            Self::Column(key) => column(key),
+            Self::Not(e) => !e.eval_word(column),
+            Self::And(a, b) => a.eval_word(column) & b.eval_word(column),
+            Self::Or(a, b) => a.eval_word(column) | b.eval_word(column),
+            Self::Xor(a, b) => a.eval_word(column) ^ b.eval_word(column),
+        }
+    }
+
+    /// Evaluates the expression over `Bitset`s, returned by `column` for every key,
+    /// in a single pass over them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{expr::Expr, prelude::*};
+    ///
+    /// let a = Bitset8::new(0b00001111);
+    /// let b = Bitset8::new(0b00111100);
+    /// let expr: Expr<String> = "a ^ b".parse()?;
+    ///
+    /// let result = expr.eval(|key| if key == "a" { &a } else { &b });
+    ///
+    /// assert_eq!(result.into_inner(), 0b00110011);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn eval<'b, T>(&self, column: impl Fn(&K) -> &'b T) -> T
+    where
+        T: Bitset + 'b,
+    {
+        let bit_len = T::BYTE_SIZE * 8;
+        let mut result = T::NONE.clone();
+        for w in 0..bit_len.div_ceil(64) {
+            let word = self.eval_word(&|key| column(key)._word(w));
+            result._set_bits(w * 64, (bit_len - w * 64).min(64), word);
+        }
+        result
+    }
+}
+
+impl<K> Not for Expr<K> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::Not(Box::new(self))
+    }
+}
+
+impl<K> BitAnd for Expr<K> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::And(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl<K> BitOr for Expr<K> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self::Or(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl<K> BitXor for Expr<K> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self::Xor(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl<K> Display for Expr<K>
+where
+    K: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Column(key) => write!(f, "{key}"),
+            Self::Not(e) => write!(f, "!{e}"),
+            Self::And(a, b) => write!(f, "({a} & {b})"),
+            Self::Or(a, b) => write!(f, "({a} | {b})"),
+            Self::Xor(a, b) => write!(f, "({a} ^ {b})"),
+        }
+    }
+}
+
+/// Error of parsing [`Expr`] from a string. Implements [`Error`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParseExprError {
+    position: usize,
+    expected: &'static str,
+}
+
+impl ParseExprError {
+    /// Returns the byte position in the parsed string, at which the error occured.
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Error for ParseExprError {}
+
+impl Display for ParseExprError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse expression: expected {} at position {}",
+            self.expected, self.position
+        )
+    }
+}
+
+// Recursive descent parser of expressions.
+// Every rule returns the parsed expression with its height, which is limited to `Expr::MAX_PARSE_DEPTH`,
+// same as nesting of `!` and `(`, which the parser recurses into.
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+    nesting: usize,
+}
+
+type Parsed = Result<(Expr<String>, usize), ParseExprError>;
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.s[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.s[self.pos..].starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, expected: &'static str) -> ParseExprError {
+        ParseExprError {
+            position: self.pos,
+            expected,
+        }
+    }
+
+    // Returns `depth`, or an error, if it's past the limit.
+    fn limit(&self, depth: usize) -> Result<usize, ParseExprError> {
+        if depth > Expr::<String>::MAX_PARSE_DEPTH {
+            return Err(self.error("expression at most 256 levels deep"));
+        }
+        Ok(depth)
+    }
+
+    // Parses `rule`, with `nesting` increased by one.
+    fn nested(&mut self, rule: fn(&mut Self) -> Parsed) -> Parsed {
+        self.nesting = self.limit(self.nesting + 1)?;
+        let parsed = rule(self);
+        self.nesting -= 1;
+        parsed
+    }
+
+    // or := xor ('|' xor)*
+    fn or(&mut self) -> Parsed {
+        let (mut expr, mut height) = self.xor()?;
+        while self.eat('|') {
+            let (rhs, rhs_height) = self.xor()?;
+            expr = expr | rhs;
+            height = self.limit(height.max(rhs_height) + 1)?;
+        }
+        Ok((expr, height))
+    }
+
+    // xor := and ('^' and)*
+    fn xor(&mut self) -> Parsed {
+        let (mut expr, mut height) = self.and()?;
+        while self.eat('^') {
+            let (rhs, rhs_height) = self.and()?;
+            expr = expr ^ rhs;
+            height = self.limit(height.max(rhs_height) + 1)?;
+        }
+        Ok((expr, height))
+    }
+
+    // and := unary ('&' unary)*
+    fn and(&mut self) -> Parsed {
+        let (mut expr, mut height) = self.unary()?;
+        while self.eat('&') {
+            let (rhs, rhs_height) = self.unary()?;
+            expr = expr & rhs;
+            height = self.limit(height.max(rhs_height) + 1)?;
+        }
+        Ok((expr, height))
+    }
+
+    // unary := '!' unary | '(' or ')' | column
+    fn unary(&mut self) -> Parsed {
+        if self.eat('!') {
+            let (expr, height) = self.nested(Self::unary)?;
+            return Ok((!expr, self.limit(height + 1)?));
+        }
+        if self.eat('(') {
+            let parsed = self.nested(Self::or)?;
+            if !self.eat(')') {
+                return Err(self.error("`)`"));
+            }
+            return Ok(parsed);
+        }
+
+        self.skip_whitespace();
+        let rest = &self.s[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || "_-.:".contains(c)))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("column name, `!` or `(`"));
+        }
+        self.pos += len;
+        Ok((Expr::Column(rest[..len].to_owned()), 1))
+    }
+}
+
+impl FromStr for Expr<String> {
+    type Err = ParseExprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            s,
+            pos: 0,
+            nesting: 0,
+        };
+        let (expr, _) = parser.or()?;
+        parser.skip_whitespace();
+        if parser.pos != s.len() {
+            return Err(parser.error("operator or end of expression"));
+        }
+        Ok(expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::Bitset8;
+
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    fn col(key: &str) -> Expr<String> {
+        Expr::column(key.to_owned())
+    }
+
+    #[test]
+    fn parse() -> TestResult {
+        let expr: Expr<String> = "a & (b | !c)".parse()?;
+        assert_eq!(expr, col("a") & (col("b") | !col("c")));
+
+        let expr: Expr<String> = "a | b ^ c & !d".parse()?;
+        assert_eq!(expr, col("a") | (col("b") ^ (col("c") & !col("d"))));
+
+        let expr: Expr<String> = " !!x.y_z-1 ".parse()?;
+        assert_eq!(expr, !!col("x.y_z-1"));
+
+        assert_eq!(expr.to_string(), "!!x.y_z-1");
+        assert_eq!(
+            "(a & b) | c".parse::<Expr<String>>()?.to_string(),
+            "((a & b) | c)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "a & (b | c".parse::<Expr<String>>().unwrap_err();
+        assert_eq!(err.position(), 10);
+
+        let err = "a & | b".parse::<Expr<String>>().unwrap_err();
+        assert_eq!(err.position(), 4);
+
+        let err = "a b".parse::<Expr<String>>().unwrap_err();
+        assert_eq!(err.position(), 2);
+        assert_eq!(
+            err.to_string(),
+            "failed to parse expression: expected operator or end of expression at position 2"
+        );
+
+        assert!("".parse::<Expr<String>>().is_err());
+    }
+
+    #[test]
+    fn parse_depth_limit() {
+        let depth = Expr::<String>::MAX_PARSE_DEPTH;
+
+        let negations = "!".repeat(depth - 1) + "a";
+        assert!(negations.parse::<Expr<String>>().is_ok());
+        let err = ("!".repeat(depth) + "a")
+            .parse::<Expr<String>>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to parse expression: expected expression at most 256 levels deep at position 257"
+        );
+
+        let parens = "(".repeat(depth) + "a" + &")".repeat(depth);
+        assert!(parens.parse::<Expr<String>>().is_ok());
+        let parens = "(".repeat(depth + 1) + "a" + &")".repeat(depth + 1);
+        assert!(parens.parse::<Expr<String>>().is_err());
+
+        let chain = vec!["a"; depth].join(" & ");
+        assert!(chain.parse::<Expr<String>>().is_ok());
+        assert!((chain + " & a").parse::<Expr<String>>().is_err());
+
+        let huge = "!(".repeat(1_000_000);
+        assert!(huge.parse::<Expr<String>>().is_err());
+    }
+
+    #[test]
+    fn eval() -> TestResult {
+        let expr: Expr<String> = "a & (b | !c)".parse()?;
+        let columns = [
+            Bitset8::new(0b11110000),
+            Bitset8::new(0b11001100),
+            Bitset8::new(0b10101010),
+        ];
+        let column = |key: &String| match key.as_str() {
+            "a" => &columns[0],
+            "b" => &columns[1],
+            _ => &columns[2],
+        };
+
+        assert_eq!(expr.eval(column).into_inner(), 0b11010000);
+        Ok(())
+    }
+}
@@ -0,0 +1,423 @@
+//! Module containing [`flags!`][crate::flags!] macro, declaring named flags over fixed size [`Bitset`][crate::bitset::Bitset]s.
+
+/// Declares a newtype over one of the fixed size [`Bitset`][crate::bitset::Bitset]s, with named flags.
+///
+/// Each flag is declared as a constant with the [`Index`][crate::index::Index] of its bit.
+/// The newtype gets:
+/// - a constant for each flag, and `NAMED` list of all of them with their names;
+/// - `empty`, `all`, `from_bitset`, `from_bitset_truncate`, `bitset` and `from_name` constructors and accessors;
+/// - `from_bits`, `from_bits_truncate`, `from_bits_retain`, `bits` and `unknown_bits`, named like in `bitflags` 2.x;
+/// - `contains`, `intersects`, `insert`, `remove`, `toggle`, `complement`, `is_empty`, `is_all`
+///   and `iter_names` methods;
+/// - `|`, `&`, `^`, `-` and `!` operators with their assigning variants,
+///   where `!` is limited to the named flags;
+/// - [`Debug`] printing names of the flags, [`FromIterator`] and [`Extend`] over flags,
+///   and conversions from and to the `Bitset`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{flags, prelude::*};
+///
+/// flags! {
+///     /// Access rights.
+///     pub struct Access: Bitset8 {
+///         const READ = 0;
+///         const WRITE = 1;
+///         const EXECUTE = 2;
+///     }
+/// }
+///
+/// let access = Access::READ | Access::WRITE;
+///
+/// assert!(access.contains(Access::READ));
+/// assert_eq!(!access, Access::EXECUTE);
+/// assert_eq!(format!("{access:?}"), "Access(READ | WRITE)");
+/// assert_eq!(Access::from_name("EXECUTE"), Some(Access::EXECUTE));
+/// assert_eq!(access.iter_names().map(|(name, _)| name).collect::<Vec<_>>(), ["READ", "WRITE"]);
+/// assert_eq!(access.bitset(), Bitset8::new(0b011));
+/// #   Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! flags {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident: $bitset:ty {
+            $(
+                $(#[$flag_meta:meta])*
+                const $flag:ident = $index:expr;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #[repr(transparent)]
+        $vis struct $name($bitset);
+
+        #[allow(dead_code)]
+        impl $name {
+            $(
+                $(#[$flag_meta])*
+                pub const $flag: Self = Self(<$bitset>::new(1 << $index));
+            )*
+
+            /// All of the named flags, with their names, in order of declaration.
+            pub const NAMED: &'static [(&'static str, Self)] = &[$((stringify!($flag), Self::$flag)),*];
+
+            /// Returns value with no flags set.
+            #[inline(always)]
+            pub fn empty() -> Self {
+                Self(<$bitset as $crate::bitset::Bitset>::NONE)
+            }
+
+            /// Returns value with all of the named flags set.
+            pub fn all() -> Self {
+                Self::NAMED.iter().fold(Self::empty(), |acc, (_, flag)| acc | *flag)
+            }
+
+            /// Constructs a new value from `bitset`, retaining bits of unnamed flags.
+            #[inline(always)]
+            pub const fn from_bitset(bitset: $bitset) -> Self {
+                Self(bitset)
+            }
+
+            /// Constructs a new value from `bitset`, dropping bits of unnamed flags.
+            #[inline(always)]
+            pub fn from_bitset_truncate(bitset: $bitset) -> Self {
+                Self(bitset & Self::all().0)
+            }
+
+            /// Returns the underlying `Bitset`.
+            #[inline(always)]
+            pub const fn bitset(&self) -> $bitset {
+                self.0
+            }
+
+            /// Constructs a new value from `bitset`, or returns [`None`], if it has bits of unnamed flags.
+            #[inline(always)]
+            pub fn from_bits(bitset: $bitset) -> ::core::option::Option<Self> {
+                let flags = Self(bitset);
+                $crate::bitset::Bitset::is_empty(&flags.unknown_bits()).then_some(flags)
+            }
+
+            /// Constructs a new value from `bitset`, dropping bits of unnamed flags.
+            /// Same as `from_bitset_truncate`.
+            #[inline(always)]
+            pub fn from_bits_truncate(bitset: $bitset) -> Self {
+                Self::from_bitset_truncate(bitset)
+            }
+
+            /// Constructs a new value from `bitset`, retaining bits of unnamed flags.
+            /// Same as `from_bitset`.
+            #[inline(always)]
+            pub const fn from_bits_retain(bitset: $bitset) -> Self {
+                Self::from_bitset(bitset)
+            }
+
+            /// Returns the underlying `Bitset`. Same as `bitset`.
+            #[inline(always)]
+            pub const fn bits(&self) -> $bitset {
+                self.0
+            }
+
+            /// Returns bits of `self`, which don't belong to any of the named flags.
+            #[inline(always)]
+            pub fn unknown_bits(&self) -> $bitset {
+                self.0 & !Self::all().0
+            }
+
+            /// Returns the named flags, which aren't set in `self`. Same as `!`.
+            #[inline(always)]
+            pub fn complement(self) -> Self {
+                !self
+            }
+
+            /// Returns the flag named `name`, or [`None`], if there is no such flag.
+            pub fn from_name(name: &str) -> Option<Self> {
+                Self::NAMED
+                    .iter()
+                    .find(|(flag_name, _)| *flag_name == name)
+                    .map(|(_, flag)| *flag)
+            }
+
+            /// Returns iterator over the named flags, set in `self`, with their names.
+            pub fn iter_names(&self) -> impl Iterator<Item = (&'static str, Self)> + '_ {
+                Self::NAMED
+                    .iter()
+                    .copied()
+                    .filter(|(_, flag)| self.contains(*flag))
+            }
+
+            /// Returns [`true`], if no flags are set, and [`false`] otherwise.
+            #[inline(always)]
+            pub fn is_empty(&self) -> bool {
+                $crate::bitset::Bitset::is_empty(&self.0)
+            }
+
+            /// Returns [`true`], if all of the named flags are set, and [`false`] otherwise.
+            #[inline(always)]
+            pub fn is_all(&self) -> bool {
+                self.contains(Self::all())
+            }
+
+            /// Returns [`true`], if all flags of `other` are set in `self`, and [`false`] otherwise.
+            #[inline(always)]
+            pub fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Returns [`true`], if any flags of `other` are set in `self`, and [`false`] otherwise.
+            #[inline(always)]
+            pub fn intersects(&self, other: Self) -> bool {
+                $crate::bitset::Bitset::intersects(&self.0, &other.0)
+            }
+
+            /// Sets flags of `other`. Returns a mutable reference to `self`.
+            #[inline(always)]
+            pub fn insert(&mut self, other: Self) -> &mut Self {
+                self.0 |= other.0;
+                self
+            }
+
+            /// Unsets flags of `other`. Returns a mutable reference to `self`.
+            #[inline(always)]
+            pub fn remove(&mut self, other: Self) -> &mut Self {
+                self.0 &= !other.0;
+                self
+            }
+
+            /// Flips flags of `other`. Returns a mutable reference to `self`.
+            #[inline(always)]
+            pub fn toggle(&mut self, other: Self) -> &mut Self {
+                self.0 ^= other.0;
+                self
+            }
+        }
+
+        impl ::core::ops::BitOr for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitOrAssign for $name {
+            #[inline(always)]
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl ::core::ops::BitAnd for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitand(self, rhs: Self) -> Self::Output {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitAndAssign for $name {
+            #[inline(always)]
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+
+        impl ::core::ops::BitXor for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitXorAssign for $name {
+            #[inline(always)]
+            fn bitxor_assign(&mut self, rhs: Self) {
+                self.0 ^= rhs.0;
+            }
+        }
+
+        impl ::core::ops::Sub for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0 & !rhs.0)
+            }
+        }
+
+        impl ::core::ops::SubAssign for $name {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 &= !rhs.0;
+            }
+        }
+
+        impl ::core::ops::Not for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self::Output {
+                Self(!self.0 & Self::all().0)
+            }
+        }
+
+        impl ::core::iter::FromIterator<$name> for $name {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = $name>>(iter: I) -> Self {
+                iter.into_iter().fold(Self::empty(), |acc, flag| acc | flag)
+            }
+        }
+
+        impl ::core::iter::Extend<$name> for $name {
+            fn extend<I: ::core::iter::IntoIterator<Item = $name>>(&mut self, iter: I) {
+                for flag in iter {
+                    self.insert(flag);
+                }
+            }
+        }
+
+        impl ::core::convert::From<$bitset> for $name {
+            #[inline(always)]
+            fn from(value: $bitset) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $bitset {
+            #[inline(always)]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{}(", stringify!($name))?;
+                let mut first = true;
+                for (name, _) in self.iter_names() {
+                    if !first {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{name}")?;
+                    first = false;
+                }
+                let unknown = self.unknown_bits();
+                if !$crate::bitset::Bitset::is_empty(&unknown) {
+                    if !first {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{:#x}", unknown)?;
+                    first = false;
+                }
+                if first {
+                    write!(f, "empty")?;
+                }
+                write!(f, ")")
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Bitset16, Bitset8};
+
+    flags! {
+        struct Color: Bitset8 {
+            const RED = 0;
+            const GREEN = 1;
+            const BLUE = 7;
+        }
+    }
+
+    flags! {
+        /// Flags with a gap.
+        pub(crate) struct Sparse: Bitset16 {
+            /// The first one.
+            const LOW = 0;
+            const HIGH = 15;
+        }
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Color::RED.bitset(), Bitset8::new(0b00000001));
+        assert_eq!(Color::BLUE.bitset(), Bitset8::new(0b10000000));
+        assert_eq!(Color::all().bitset(), Bitset8::new(0b10000011));
+        assert_eq!(Color::NAMED.len(), 3);
+        assert_eq!(Sparse::all().bitset(), Bitset16::new(0x8001));
+        assert!(Color::empty().is_empty());
+        assert!(Color::all().is_all());
+    }
+
+    #[test]
+    fn operations() {
+        let mut color = Color::RED | Color::GREEN;
+
+        assert_eq!(color - Color::RED, Color::GREEN);
+        assert_eq!(color & Color::GREEN, Color::GREEN);
+        assert_eq!(color ^ Color::all(), Color::BLUE);
+        assert_eq!(!color, Color::BLUE);
+        assert!(color.intersects(Color::GREEN | Color::BLUE));
+        assert!(!color.contains(Color::GREEN | Color::BLUE));
+
+        color
+            .insert(Color::BLUE)
+            .remove(Color::RED)
+            .toggle(Color::GREEN);
+        assert_eq!(color, Color::BLUE);
+
+        color -= Color::BLUE;
+        color |= Color::RED;
+        color ^= Color::GREEN;
+        color &= Color::GREEN;
+        assert_eq!(color, Color::GREEN);
+
+        let collected: Color = [Color::RED, Color::BLUE].into_iter().collect();
+        assert_eq!(collected.iter_names().count(), 2);
+    }
+
+    #[test]
+    fn conversions() {
+        let retained = Color::from_bitset(Bitset8::new(0b01000001));
+        assert_eq!(format!("{retained:?}"), "Color(RED | 0x40)");
+        assert_eq!(
+            Color::from_bitset_truncate(Bitset8::new(0b01000001)),
+            Color::RED
+        );
+        assert_eq!(format!("{:?}", Color::empty()), "Color(empty)");
+        assert_eq!(Color::from_name("GREEN"), Some(Color::GREEN));
+        assert_eq!(Color::from_name("green"), None);
+        assert_eq!(Bitset8::from(Color::from(Bitset8::new(3))), Bitset8::new(3));
+    }
+
+    #[test]
+    fn bitflags_parity() {
+        let bits = Bitset8::new(0b01000001);
+
+        assert_eq!(Color::from_bits(bits), None);
+        assert_eq!(
+            Color::from_bits(Bitset8::new(0b10000001)),
+            Some(Color::RED | Color::BLUE)
+        );
+        assert_eq!(Color::from_bits_truncate(bits), Color::RED);
+        assert_eq!(Color::from_bits_retain(bits).bits(), bits);
+        assert_eq!(
+            Color::from_bits_retain(bits).unknown_bits(),
+            Bitset8::new(0b01000000)
+        );
+        assert_eq!(Color::RED.unknown_bits(), Bitset8::new(0));
+        assert_eq!(
+            Color::from_bits_retain(bits).complement(),
+            Color::GREEN | Color::BLUE
+        );
+    }
+}
@@ -0,0 +1,284 @@
+//! Module containing [`FlagsEnum`], trait for enums, variants of which are flags of a [`Bitset`].
+//!
+//! Enable feature `"derive"` to derive it with `#[derive(FlagsEnum)]`.
+
+use crate::{
+    bitset::Bitset,
+    index::Index,
+    prelude::{
+        Bitset128, Bitset16, Bitset256, Bitset32, Bitset512, Bitset64, Bitset8, BitsetSize, Byteset,
+    },
+};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
+
+#[cfg(feature = "derive")]
+pub use bitworks_derive::FlagsEnum;
+
+/// Trait for fieldless enums, each variant of which is a flag, stored in a bit of [`Bitset`].
+///
+/// # Deriving
+/// With feature `"derive"`, `#[derive(FlagsEnum)]` implements the trait for fieldless enums,
+/// mapping each variant to the bit at its discriminant. Explicit discriminants have to be integer literals.
+/// `Bitset` defaults to the smallest built-in one, fitting all of the variants,
+/// and can be chosen with `#[flags_enum(bitset = Bitset32)]`.
+/// Deriving fails to compile, if the variants don't fit into the chosen `Bitset`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{flags_enum::FlagsEnum, prelude::*};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Access {
+///     Read,
+///     Write,
+///     Execute = 4,
+/// }
+///
+/// impl FlagsEnum for Access {
+///     type Bitset = Bitset8;
+///     const VARIANTS: &'static [Self] = &[Access::Read, Access::Write, Access::Execute];
+///
+///     fn index(self) -> Index8 {
+///         Index8::from_usize(self as usize)
+///     }
+///
+///     fn name(self) -> &'static str {
+///         match self {
+///             Access::Read => "Read",
+///             Access::Write => "Write",
+///             Access::Execute => "Execute",
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Access::Execute.bitset().into_inner(), 0b10000);
+/// assert_eq!(Access::try_from_index(1.try_into()?), Some(Access::Write));
+/// assert_eq!(Access::try_from_index(2.try_into()?), None);
+///
+/// // Variants work with the Bitset like its indeces.
+/// let bitset = Bitset8::from(Access::Read) | Access::Execute;
+/// assert_eq!(bitset.into_inner(), 0b10001);
+/// assert_eq!([Access::Read, Access::Write].into_iter().collect::<Bitset8>().into_inner(), 0b11);
+/// #   Ok(())
+/// # }
+/// ```
+pub trait FlagsEnum: Sized + Copy + 'static {
+    /// `Bitset`, storing the flags.
+    type Bitset: Bitset;
+    /// All of the variants, in ascending order of their [`indeces`][Index].
+    const VARIANTS: &'static [Self];
+
+    /// Returns [`Index`] of the bit of the flag.
+    fn index(self) -> Index<Self::Bitset>;
+
+    /// Returns name of the variant.
+    fn name(self) -> &'static str;
+
+    /// Returns `Bitset` with only the bit of the flag set.
+    #[inline(always)]
+    fn bitset(self) -> Self::Bitset {
+        Self::Bitset::from_index(&self.index())
+    }
+
+    /// Returns variant with the bit at `index`, or [`None`], if there is no such variant.
+    fn try_from_index(index: Index<Self::Bitset>) -> Option<Self> {
+        Self::VARIANTS.iter().copied().find(|v| v.index() == index)
+    }
+}
+
+// Implements conversions, operators and FromIterator with FlagsEnum variants, delegating to ones with Index.
+macro_rules! impl_flags_enum {
+    ($([$($generics:tt)*] $bitset:ty),* $(,)?) => {
+        $(
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> From<E> for $bitset {
+                #[inline(always)]
+                fn from(value: E) -> Self {
+                    value.bitset()
+                }
+            }
+
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> BitAnd<E> for $bitset {
+                type Output = Self;
+
+                #[inline(always)]
+                fn bitand(self, rhs: E) -> Self::Output {
+                    self & rhs.index()
+                }
+            }
+
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> BitAndAssign<E> for $bitset {
+                #[inline(always)]
+                fn bitand_assign(&mut self, rhs: E) {
+                    *self &= rhs.index();
+                }
+            }
+
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> BitOr<E> for $bitset {
+                type Output = Self;
+
+                #[inline(always)]
+                fn bitor(self, rhs: E) -> Self::Output {
+                    self | rhs.index()
+                }
+            }
+
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> BitOrAssign<E> for $bitset {
+                #[inline(always)]
+                fn bitor_assign(&mut self, rhs: E) {
+                    *self |= rhs.index();
+                }
+            }
+
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> BitXor<E> for $bitset {
+                type Output = Self;
+
+                #[inline(always)]
+                fn bitxor(self, rhs: E) -> Self::Output {
+                    self ^ rhs.index()
+                }
+            }
+
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> BitXorAssign<E> for $bitset {
+                #[inline(always)]
+                fn bitxor_assign(&mut self, rhs: E) {
+                    *self ^= rhs.index();
+                }
+            }
+
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> Sub<E> for $bitset {
+                type Output = Self;
+
+                #[inline(always)]
+                fn sub(self, rhs: E) -> Self::Output {
+                    self - rhs.index()
+                }
+            }
+
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> SubAssign<E> for $bitset {
+                #[inline(always)]
+                fn sub_assign(&mut self, rhs: E) {
+                    *self -= rhs.index();
+                }
+            }
+
+            impl<E: FlagsEnum<Bitset = $bitset>, $($generics)*> FromIterator<E> for $bitset {
+                fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+                    iter.into_iter()
+                        .fold(<$bitset>::NONE.clone(), |acc, flag| acc | flag)
+                }
+            }
+        )*
+    };
+}
+
+impl_flags_enum!(
+    [] Bitset8,
+    [] Bitset16,
+    [] Bitset32,
+    [] Bitset64,
+    [] Bitset128,
+    [] Bitset256,
+    [] Bitset512,
+    [] BitsetSize,
+    [const N: usize] Byteset<N>,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Wide {
+        First,
+        Last,
+    }
+
+    impl FlagsEnum for Wide {
+        type Bitset = Byteset<3>;
+        const VARIANTS: &'static [Self] = &[Wide::First, Wide::Last];
+
+        fn index(self) -> Index<Self::Bitset> {
+            match self {
+                Wide::First => Index::from_usize(0),
+                Wide::Last => Index::from_usize(23),
+            }
+        }
+
+        fn name(self) -> &'static str {
+            match self {
+                Wide::First => "First",
+                Wide::Last => "Last",
+            }
+        }
+    }
+
+    #[test]
+    fn operators() {
+        let mut bitset = Byteset::from(Wide::First) | Wide::Last;
+        assert_eq!(bitset, Byteset::new([1, 0, 0x80]));
+
+        bitset ^= Wide::First;
+        assert_eq!(bitset & Wide::Last, bitset);
+        assert_eq!(bitset & Wide::First, Byteset::NONE);
+
+        bitset &= Wide::First;
+        bitset |= Wide::Last;
+        assert_eq!(bitset ^ Wide::Last, Byteset::NONE);
+        assert_eq!(bitset - Wide::Last, Byteset::NONE);
+
+        bitset |= Wide::First;
+        bitset -= Wide::First;
+        assert_eq!(bitset, Byteset::new([0, 0, 0x80]));
+
+        let collected: Byteset<3> = Wide::VARIANTS.iter().copied().collect();
+        assert_eq!(collected.count_ones(), 2);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Pointer {
+        Low,
+        High,
+    }
+
+    impl FlagsEnum for Pointer {
+        type Bitset = BitsetSize;
+        const VARIANTS: &'static [Self] = &[Pointer::Low, Pointer::High];
+
+        fn index(self) -> Index<Self::Bitset> {
+            match self {
+                Pointer::Low => Index::from_usize(0),
+                Pointer::High => Index::from_usize(usize::BITS as usize - 1),
+            }
+        }
+
+        fn name(self) -> &'static str {
+            match self {
+                Pointer::Low => "Low",
+                Pointer::High => "High",
+            }
+        }
+    }
+
+    #[test]
+    fn bitset_size() {
+        let high = 1 << (usize::BITS - 1);
+        let mut bitset = BitsetSize::from(Pointer::Low) | Pointer::High;
+        assert_eq!(bitset, BitsetSize::new(1 | high));
+
+        bitset ^= Pointer::Low;
+        assert_eq!(bitset & Pointer::High, BitsetSize::new(high));
+        assert_eq!(bitset - Pointer::High, BitsetSize::NONE);
+
+        bitset -= Pointer::High;
+        bitset |= Pointer::Low;
+        bitset &= Pointer::Low;
+        bitset ^= Pointer::High;
+        assert_eq!(bitset, BitsetSize::new(1 | high));
+
+        let collected: BitsetSize = Pointer::VARIANTS.iter().copied().collect();
+        assert_eq!(collected, bitset);
+    }
+}
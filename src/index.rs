@@ -1,11 +1,33 @@
 //! Module containing [`Index`].
+//!
+//! All built-in [`Bitset`]s implement [`std::ops::Index`] with both `Index` and [`usize`],
+//! returning reference to [`bool`] value of the bit.
+//!
+//! # Examples
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use bitworks::prelude::*;
+//!
+//! let bitset = Bitset8::new(0b00001010);
+//!
+//! assert!(bitset[1]);
+//! assert!(!bitset[2]);
+//! assert!(bitset[Index8::try_from(3)?]);
+//! #   Ok(())
+//! # }
+//! ```
 
 use crate::{
     bitset,
     error::{ConvError, ConvResult, ConvTarget},
-    prelude::Bitset,
+    prelude::{
+        Bitset, Bitset128, Bitset16, Bitset256, Bitset32, Bitset512, Bitset64, Bitset8, BitsetSize,
+        Byteset,
+    },
 };
-use std::{cmp::Ordering, fmt::Debug, hash::Hash, marker::PhantomData};
+use std::{cmp::Ordering, fmt::Debug, hash::Hash, marker::PhantomData, ops};
 
 /// Struct allowing to safely index `T`, where `T` implements [`Bitset`].
 #[derive(Default)]
@@ -515,3 +537,60 @@ where
         self.0.hash(state);
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for Index<T>
+where
+    T: Bitset,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let value = u.int_in_range(0..=bitset::bit_len::<T>() - 1)?;
+        Ok(Self(value, PhantomData))
+    }
+
+    #[inline(always)]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <usize as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+// Implements ops::Index with Index and usize, returning references to static bool values.
+// Indexing with usize panics, if the value is out of range.
+macro_rules! impl_index_ops {
+    ($([$($generics:tt)*] $bitset:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> ops::Index<Index<Self>> for $bitset {
+                type Output = bool;
+
+                #[inline(always)]
+                fn index(&self, index: Index<Self>) -> &Self::Output {
+                    match bool::from(self.bit(index)) {
+                        true => &true,
+                        false => &false,
+                    }
+                }
+            }
+
+            impl<$($generics)*> ops::Index<usize> for $bitset {
+                type Output = bool;
+
+                #[inline(always)]
+                fn index(&self, index: usize) -> &Self::Output {
+                    &self[Index::<Self>::from_usize(index)]
+                }
+            }
+        )*
+    };
+}
+
+impl_index_ops!(
+    [] Bitset8,
+    [] Bitset16,
+    [] Bitset32,
+    [] Bitset64,
+    [] Bitset128,
+    [] Bitset256,
+    [] Bitset512,
+    [] BitsetSize,
+    [const N: usize] Byteset<N>,
+);
@@ -0,0 +1,1277 @@
+//! Module containing iterators over [`Bitset`] bits: [`Bits`], [`BitsRef`], [`BitsMut`], [`Ones`], [`Zeros`],
+//! [`Ranges`] and [`DrainOnes`], and over [`Bitset`] values: [`Subsets`] and [`Combinations`].
+//!
+//! All built-in `Bitset`s implement [`IntoIterator`], yielding [`Bits`] for values, [`BitsRef`] for references
+//! and [`BitsMut`] for mutable references. They also implement [`Extend`] with [`Bit`], [`bool`] and [`Index`],
+//! setting bits at the positions of set values and at the indices, and [`FromIterator`] with [`usize`]
+//! and [`Index`], setting bits at the indices.
+//!
+//! # Examples
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use bitworks::prelude::*;
+//!
+//! let mut bitset = Bitset8::new(0b00001111);
+//!
+//! for mut bit in &mut bitset {
+//!     *bit = !*bit;
+//! }
+//! assert_eq!((&bitset).into_iter().filter(|bit| **bit == One).count(), 4);
+//! assert_eq!(bitset.into_iter().last(), Some(One));
+//!
+//! bitset.extend([Index8::MIN, Index8::MAX]);
+//! bitset.extend([false, true]);
+//! assert_eq!(bitset.into_inner(), 0b11110011);
+//! #   Ok(())
+//! # }
+//! ```
+
+use crate::{
+    bit::{Bit, BitMut, BitRef},
+    bitset::Bitset,
+    dispatch,
+    index::Index,
+    prelude::{
+        Bitset128, Bitset16, Bitset256, Bitset32, Bitset512, Bitset64, Bitset8, BitsetSize, Byteset,
+    },
+};
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range};
+
+// Length of a word in bits, as used by Bitset::_word.
+const WORD_BITS: usize = 64;
+
+// Mask of the bits inside of the word `w`, that are in range `front..back`.
+#[inline(always)]
+const fn range_mask(w: usize, front: usize, back: usize) -> u64 {
+    let start = w * WORD_BITS;
+    let low = if front > start {
+        !0 << (front - start)
+    } else {
+        !0
+    };
+    let high = if back < start + WORD_BITS {
+        !(!0 << (back - start))
+    } else {
+        !0
+    };
+    low & high
+}
+
+// Finds position of the first bit in `front..back`, for which `word` returns set bit.
+#[inline(always)]
+pub(crate) fn find_first(front: usize, back: usize, word: impl Fn(usize) -> u64) -> Option<usize> {
+    let mut w = front / WORD_BITS;
+    while w * WORD_BITS < back {
+        let bits = word(w) & range_mask(w, front, back);
+        if bits != 0 {
+            return Some(w * WORD_BITS + bits.trailing_zeros() as usize);
+        }
+        w += 1;
+    }
+    None
+}
+
+// Finds position of the last bit in `front..back`, for which `word` returns set bit.
+#[inline(always)]
+pub(crate) fn find_last(front: usize, back: usize, word: impl Fn(usize) -> u64) -> Option<usize> {
+    if front >= back {
+        return None;
+    }
+    let mut w = (back - 1) / WORD_BITS;
+    loop {
+        let bits = word(w) & range_mask(w, front, back);
+        if bits != 0 {
+            return Some(w * WORD_BITS + (WORD_BITS - 1 - bits.leading_zeros() as usize));
+        }
+        if w * WORD_BITS <= front {
+            return None;
+        }
+        w -= 1;
+    }
+}
+
+// Counts set bits in `front..back`, for which `word` returns set bit.
+#[inline(always)]
+pub(crate) fn count_in(front: usize, back: usize, word: impl Fn(usize) -> u64) -> usize {
+    let mut count = 0;
+    let mut w = front / WORD_BITS;
+    while w * WORD_BITS < back {
+        count += (word(w) & range_mask(w, front, back)).count_ones() as usize;
+        w += 1;
+    }
+    count
+}
+
+// Finds position of the `n`-th (starting from 0) bit in `front..back`, for which `word` returns set bit.
+#[inline(always)]
+pub(crate) fn find_nth(
+    front: usize,
+    back: usize,
+    mut n: usize,
+    word: impl Fn(usize) -> u64,
+) -> Option<usize> {
+    let mut w = front / WORD_BITS;
+    while w * WORD_BITS < back {
+        let bits = word(w) & range_mask(w, front, back);
+        let count = bits.count_ones() as usize;
+        if n < count {
+            return Some(w * WORD_BITS + dispatch::select(bits, n as u32) as usize);
+        }
+        n -= count;
+        w += 1;
+    }
+    None
+}
+
+/// Iterator over bits of the [`Bitset`] in [`Bit`] representation.
+///
+/// Returned by [`Bitset::bits`].
+#[derive(Debug, Clone)]
+pub struct Bits<T: Bitset> {
+    bitset: T,
+    front: usize,
+    back: usize,
+}
+
+impl<T> Bits<T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `Bits`, iterating over the whole `bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{iter::Bits, prelude::*};
+    ///
+    /// let bitset = Bitset8::new(0b01010100);
+    /// let mut iter = Bits::new(bitset).skip(2);
+    ///
+    /// assert_eq!(iter.next(), Some(One));
+    /// assert_eq!(iter.next(), Some(Zero));
+    /// assert_eq!(iter.next(), Some(One));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(bitset: T) -> Self {
+        Self {
+            bitset,
+            front: 0,
+            back: T::BYTE_SIZE * 8,
+        }
+    }
+}
+
+impl<T> Iterator for Bits<T>
+where
+    T: Bitset,
+{
+    type Item = Bit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.bitset.bit(Index::<T>::from_usize(self.front - 1)))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.back - self.front
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for Bits<T>
+where
+    T: Bitset,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.bitset.bit(Index::<T>::from_usize(self.back)))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Bits<T> where T: Bitset {}
+
+impl<T> FusedIterator for Bits<T> where T: Bitset {}
+
+/// Iterator over bits of the [`Bitset`] in [`BitRef`] representation.
+///
+/// Returned by [`Bitset::bits_ref`].
+#[derive(Debug, Clone)]
+pub struct BitsRef<'a, T: Bitset> {
+    bitset: &'a T,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> BitsRef<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `BitsRef`, iterating over the whole `bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{iter::BitsRef, prelude::*};
+    ///
+    /// let bitset = Bitset8::new(0b01010100);
+    /// let mut iter = BitsRef::new(&bitset).skip(2);
+    ///
+    /// assert_eq!(iter.next().as_deref(), Some(&One));
+    /// assert_eq!(iter.next().as_deref(), Some(&Zero));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(bitset: &'a T) -> Self {
+        Self {
+            bitset,
+            front: 0,
+            back: T::BYTE_SIZE * 8,
+        }
+    }
+}
+
+impl<'a, T> Iterator for BitsRef<'a, T>
+where
+    T: Bitset,
+{
+    type Item = BitRef<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.bitset.bit_ref(Index::<T>::from_usize(self.front - 1)))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.back - self.front
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for BitsRef<'a, T>
+where
+    T: Bitset,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.bitset.bit_ref(Index::<T>::from_usize(self.back)))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for BitsRef<'a, T> where T: Bitset {}
+
+impl<'a, T> FusedIterator for BitsRef<'a, T> where T: Bitset {}
+
+/// Iterator over bits of the [`Bitset`] in [`BitMut`] representation.
+///
+/// Returned by [`Bitset::bits_mut`].
+#[derive(Debug)]
+pub struct BitsMut<'a, T: Bitset> {
+    bitset: *mut T,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> BitsMut<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `BitsMut`, iterating over the whole `bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{iter::BitsMut, prelude::*};
+    ///
+    /// let mut bitset = Bitset8::NONE;
+    /// BitsMut::new(&mut bitset).step_by(2).for_each(|mut bit| *bit = One);
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b01010101);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(bitset: &'a mut T) -> Self {
+        Self {
+            bitset,
+            front: 0,
+            back: T::BYTE_SIZE * 8,
+            marker: PhantomData,
+        }
+    }
+
+    // Returns BitMut of the bit at `i`. Every bit is yielded at most once.
+    #[inline(always)]
+    fn bit_mut(&mut self, i: usize) -> BitMut<'a, T> {
+        unsafe {
+            self.bitset
+                .as_mut()
+                .unwrap()
+                .bit_mut(Index::<T>::from_usize(i))
+        }
+    }
+}
+
+impl<'a, T> Iterator for BitsMut<'a, T>
+where
+    T: Bitset,
+{
+    type Item = BitMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.bit_mut(self.front - 1))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.back - self.front
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for BitsMut<'a, T>
+where
+    T: Bitset,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.bit_mut(self.back))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for BitsMut<'a, T> where T: Bitset {}
+
+impl<'a, T> FusedIterator for BitsMut<'a, T> where T: Bitset {}
+
+// Implements IntoIterator for values, references and mutable references,
+// yielding Bits, BitsRef and BitsMut respectively.
+macro_rules! impl_into_iterator {
+    ($([$($generics:tt)*] $bitset:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> IntoIterator for $bitset {
+                type Item = Bit;
+                type IntoIter = Bits<Self>;
+
+                #[inline(always)]
+                fn into_iter(self) -> Self::IntoIter {
+                    Bits::new(self)
+                }
+            }
+
+            impl<'a, $($generics)*> IntoIterator for &'a $bitset {
+                type Item = BitRef<'a, $bitset>;
+                type IntoIter = BitsRef<'a, $bitset>;
+
+                #[inline(always)]
+                fn into_iter(self) -> Self::IntoIter {
+                    BitsRef::new(self)
+                }
+            }
+
+            impl<'a, $($generics)*> IntoIterator for &'a mut $bitset {
+                type Item = BitMut<'a, $bitset>;
+                type IntoIter = BitsMut<'a, $bitset>;
+
+                #[inline(always)]
+                fn into_iter(self) -> Self::IntoIter {
+                    BitsMut::new(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_iterator!(
+    [] Bitset8,
+    [] Bitset16,
+    [] Bitset32,
+    [] Bitset64,
+    [] Bitset128,
+    [] Bitset256,
+    [] Bitset512,
+    [] BitsetSize,
+    [const N: usize] Byteset<N>,
+);
+
+/// Extension of [`Iterator`]s over [`Bitset`]s, folding them with Set operations.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::prelude::*;
+///
+/// let permissions = [Bitset8::new(0b0011), Bitset8::new(0b0110)];
+///
+/// assert_eq!(permissions.iter().copied().union().into_inner(), 0b0111);
+/// assert_eq!(permissions.into_iter().intersection().into_inner(), 0b0010);
+/// #   Ok(())
+/// # }
+/// ```
+pub trait BitsetIteratorExt: Iterator
+where
+    Self: Sized,
+    Self::Item: Bitset,
+{
+    /// Returns Set union of all `Bitset`s of the iterator, or [`Bitset::NONE`], if there are none.
+    ///
+    /// Same as [`Bitset::union_of`].
+    #[inline(always)]
+    fn union(self) -> Self::Item {
+        Self::Item::union_of(self)
+    }
+
+    /// Returns Set intersection of all `Bitset`s of the iterator, or [`Bitset::ALL`], if there are none.
+    ///
+    /// Same as [`Bitset::intersection_of`].
+    #[inline(always)]
+    fn intersection(self) -> Self::Item {
+        Self::Item::intersection_of(self)
+    }
+}
+
+impl<I> BitsetIteratorExt for I
+where
+    I: Iterator,
+    I::Item: Bitset,
+{
+}
+
+// Implements FromIterator with usize and Index, setting bits at the indices.
+// usize values outside of the Bitset are skipped.
+macro_rules! impl_from_indices {
+    ($([$($generics:tt)*] $bitset:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> FromIterator<usize> for $bitset {
+                #[inline(always)]
+                fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+                    Self::from_indices(iter)
+                }
+            }
+
+            impl<$($generics)*> FromIterator<Index<Self>> for $bitset {
+                #[inline(always)]
+                fn from_iter<I: IntoIterator<Item = Index<Self>>>(iter: I) -> Self {
+                    let mut result = Self::NONE;
+                    result.extend(iter);
+                    result
+                }
+            }
+        )*
+    };
+}
+
+impl_from_indices!(
+    [] Bitset8,
+    [] Bitset16,
+    [] Bitset32,
+    [] Bitset64,
+    [] Bitset128,
+    [] Bitset256,
+    [] Bitset512,
+    [] BitsetSize,
+    [const N: usize] Byteset<N>,
+);
+
+// Implements Extend with Bit, bool and Index. Bits are ORed in at their positions in the iterator,
+// same as with FromIterator, and indices set bits at them.
+macro_rules! impl_extend {
+    ($([$($generics:tt)*] $bitset:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> Extend<Bit> for $bitset {
+                #[inline(always)]
+                fn extend<I: IntoIterator<Item = Bit>>(&mut self, iter: I) {
+                    *self |= iter.into_iter().collect::<Self>();
+                }
+            }
+
+            impl<$($generics)*> Extend<bool> for $bitset {
+                #[inline(always)]
+                fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+                    self.extend(iter.into_iter().map(Bit::from));
+                }
+            }
+
+            impl<$($generics)*> Extend<Index<Self>> for $bitset {
+                #[inline(always)]
+                fn extend<I: IntoIterator<Item = Index<Self>>>(&mut self, iter: I) {
+                    for index in iter {
+                        self.set(index);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_extend!(
+    [] Bitset8,
+    [] Bitset16,
+    [] Bitset32,
+    [] Bitset64,
+    [] Bitset128,
+    [] Bitset256,
+    [] Bitset512,
+    [] BitsetSize,
+    [const N: usize] Byteset<N>,
+);
+
+/// Iterator over [`indeces`][Index] of the set bits of the [`Bitset`].
+///
+/// Skips over whole words of not set bits, so iterating over sparse `Bitset`s
+/// takes time proportional to the number of set bits, rather than to the size of the `Bitset`.
+///
+/// Returned by [`Bitset::ones`].
+#[derive(Debug, Clone)]
+pub struct Ones<'a, T: Bitset> {
+    bitset: &'a T,
+    front: usize,
+    back: usize,
+    // Not yet yielded bits of the word, containing `front`, limited to `front..back`.
+    word: u64,
+}
+
+impl<'a, T> Ones<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `Ones`, iterating over the whole `bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{iter::Ones, prelude::*};
+    ///
+    /// let bitset = Bitset8::new(0b01010100);
+    /// let mut iter = Ones::new(&bitset);
+    ///
+    /// assert_eq!(iter.next(), Some(2.try_into()?));
+    /// assert_eq!(iter.next(), Some(4.try_into()?));
+    /// assert_eq!(iter.next(), Some(6.try_into()?));
+    /// assert_eq!(iter.next(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(bitset: &'a T) -> Self {
+        Self::in_range(bitset, 0..T::BYTE_SIZE * 8)
+    }
+
+    // Constructs a new value, iterating over bits of `bitset` in `range`.
+    // `range` must be within the Bitset.
+    pub(crate) fn in_range(bitset: &'a T, range: Range<usize>) -> Self {
+        let mut iter = Self {
+            bitset,
+            front: 0,
+            back: range.end,
+            word: 0,
+        };
+        iter.seek(range.start);
+        iter
+    }
+
+    // Moves `front` to `front` and loads the word, containing it.
+    #[inline(always)]
+    fn seek(&mut self, front: usize) {
+        self.front = front.min(self.back);
+        self.word = match self.front < self.back {
+            true => {
+                let w = self.front / WORD_BITS;
+                let word = |w| self.bitset._word(w);
+                word(w) & range_mask(w, self.front, self.back)
+            }
+            false => 0,
+        };
+    }
+}
+
+impl<'a, T> Iterator for Ones<'a, T>
+where
+    T: Bitset,
+{
+    type Item = Index<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            let next = (self.front / WORD_BITS + 1) * WORD_BITS;
+            if next >= self.back {
+                self.seek(self.back);
+                return None;
+            }
+            self.seek(next);
+        }
+        let i = self.front / WORD_BITS * WORD_BITS + self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        self.front = i + 1;
+        if self.front.is_multiple_of(WORD_BITS) {
+            self.seek(self.front);
+        }
+        Some(Index::<T>::from_usize(i))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = count_in(self.front, self.back, |w| self.bitset._word(w));
+        (len, Some(len))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        count_in(self.front, self.back, |w| self.bitset._word(w))
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match find_nth(self.front, self.back, n, |w| self.bitset._word(w)) {
+            Some(i) => {
+                self.seek(i + 1);
+                Some(Index::<T>::from_usize(i))
+            }
+            None => {
+                self.seek(self.back);
+                None
+            }
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Ones<'a, T>
+where
+    T: Bitset,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match find_last(self.front, self.back, |w| self.bitset._word(w)) {
+            Some(i) => {
+                self.back = i;
+                self.word &= range_mask(self.front / WORD_BITS, self.front, self.back);
+                Some(Index::<T>::from_usize(i))
+            }
+            None => {
+                self.back = self.front;
+                self.word = 0;
+                None
+            }
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Ones<'a, T> where T: Bitset {}
+
+impl<'a, T> FusedIterator for Ones<'a, T> where T: Bitset {}
+
+/// Iterator over [`indeces`][Index] of the not set bits of the [`Bitset`].
+///
+/// Skips over whole words of set bits, so iterating over dense `Bitset`s
+/// takes time proportional to the number of not set bits, rather than to the size of the `Bitset`.
+///
+/// Returned by [`Bitset::zeros`].
+#[derive(Debug, Clone)]
+pub struct Zeros<'a, T: Bitset> {
+    bitset: &'a T,
+    front: usize,
+    back: usize,
+    // Not yet yielded bits of the word, containing `front`, limited to `front..back`.
+    word: u64,
+}
+
+impl<'a, T> Zeros<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `Zeros`, iterating over the whole `bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{iter::Zeros, prelude::*};
+    ///
+    /// let bitset = Bitset8::new(0b11010101);
+    /// let mut iter = Zeros::new(&bitset);
+    ///
+    /// assert_eq!(iter.next(), Some(1.try_into()?));
+    /// assert_eq!(iter.next(), Some(3.try_into()?));
+    /// assert_eq!(iter.next(), Some(5.try_into()?));
+    /// assert_eq!(iter.next(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(bitset: &'a T) -> Self {
+        Self::in_range(bitset, 0..T::BYTE_SIZE * 8)
+    }
+
+    // Constructs a new value, iterating over bits of `bitset` in `range`.
+    // `range` must be within the Bitset.
+    pub(crate) fn in_range(bitset: &'a T, range: Range<usize>) -> Self {
+        let mut iter = Self {
+            bitset,
+            front: 0,
+            back: range.end,
+            word: 0,
+        };
+        iter.seek(range.start);
+        iter
+    }
+
+    // Moves `front` to `front` and loads the word, containing it.
+    #[inline(always)]
+    fn seek(&mut self, front: usize) {
+        self.front = front.min(self.back);
+        self.word = match self.front < self.back {
+            true => {
+                let w = self.front / WORD_BITS;
+                let word = |w| !self.bitset._word(w);
+                word(w) & range_mask(w, self.front, self.back)
+            }
+            false => 0,
+        };
+    }
+}
+
+impl<'a, T> Iterator for Zeros<'a, T>
+where
+    T: Bitset,
+{
+    type Item = Index<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            let next = (self.front / WORD_BITS + 1) * WORD_BITS;
+            if next >= self.back {
+                self.seek(self.back);
+                return None;
+            }
+            self.seek(next);
+        }
+        let i = self.front / WORD_BITS * WORD_BITS + self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        self.front = i + 1;
+        if self.front.is_multiple_of(WORD_BITS) {
+            self.seek(self.front);
+        }
+        Some(Index::<T>::from_usize(i))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = count_in(self.front, self.back, |w| !self.bitset._word(w));
+        (len, Some(len))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        count_in(self.front, self.back, |w| !self.bitset._word(w))
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match find_nth(self.front, self.back, n, |w| !self.bitset._word(w)) {
+            Some(i) => {
+                self.seek(i + 1);
+                Some(Index::<T>::from_usize(i))
+            }
+            None => {
+                self.seek(self.back);
+                None
+            }
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Zeros<'a, T>
+where
+    T: Bitset,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match find_last(self.front, self.back, |w| !self.bitset._word(w)) {
+            Some(i) => {
+                self.back = i;
+                self.word &= range_mask(self.front / WORD_BITS, self.front, self.back);
+                Some(Index::<T>::from_usize(i))
+            }
+            None => {
+                self.back = self.front;
+                self.word = 0;
+                None
+            }
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Zeros<'a, T> where T: Bitset {}
+
+impl<'a, T> FusedIterator for Zeros<'a, T> where T: Bitset {}
+
+/// Iterator over maximal ranges of consecutive set bits of the [`Bitset`].
+///
+/// Skips over whole words of set and not set bits, so iterating takes time proportional
+/// to the number of ranges, rather than to the size of the `Bitset`.
+///
+/// Returned by [`Bitset::to_ranges`].
+#[derive(Debug, Clone)]
+pub struct Ranges<'a, T: Bitset> {
+    bitset: &'a T,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Ranges<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `Ranges`, iterating over the whole `bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{iter::Ranges, prelude::*};
+    ///
+    /// let bitset = Bitset8::new(0b11011100);
+    /// let mut iter = Ranges::new(&bitset);
+    ///
+    /// assert_eq!(iter.next(), Some(2..5));
+    /// assert_eq!(iter.next(), Some(6..8));
+    /// assert_eq!(iter.next(), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(bitset: &'a T) -> Self {
+        Self {
+            bitset,
+            front: 0,
+            back: T::BYTE_SIZE * 8,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Ranges<'a, T>
+where
+    T: Bitset,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = find_first(self.front, self.back, |w| self.bitset._word(w));
+        match start {
+            Some(start) => {
+                let end =
+                    find_first(start, self.back, |w| !self.bitset._word(w)).unwrap_or(self.back);
+                self.front = end;
+                Some(start..end)
+            }
+            None => {
+                self.front = self.back;
+                None
+            }
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Ranges<'a, T>
+where
+    T: Bitset,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let last = find_last(self.front, self.back, |w| self.bitset._word(w));
+        match last {
+            Some(last) => {
+                let start = find_last(self.front, last, |w| !self.bitset._word(w))
+                    .map_or(self.front, |i| i + 1);
+                self.back = start;
+                Some(start..last + 1)
+            }
+            None => {
+                self.back = self.front;
+                None
+            }
+        }
+    }
+}
+
+/// Iterator over [`indeces`][Index] of the set bits of the [`Bitset`], unsetting each of them.
+///
+/// When dropped, resets the whole `Bitset` to [`Bitset::NONE`], so it is always empty afterwards,
+/// even if the iterator was not fully consumed. If the iterator is leaked instead, e.g. with [`std::mem::forget`],
+/// the bits not yet yielded stay set.
+///
+/// Returned by [`Bitset::drain_ones`].
+#[derive(Debug)]
+pub struct DrainOnes<'a, T: Bitset> {
+    bitset: &'a mut T,
+}
+
+impl<'a, T> DrainOnes<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `DrainOnes`, draining the whole `bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{iter::DrainOnes, prelude::*};
+    ///
+    /// let mut bitset = Bitset8::new(0b01010100);
+    /// let mut iter = DrainOnes::new(&mut bitset);
+    ///
+    /// assert_eq!(iter.next(), Some(2.try_into()?));
+    /// assert_eq!(iter.next_back(), Some(6.try_into()?));
+    /// drop(iter);
+    ///
+    /// assert!(bitset.is_empty());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(bitset: &'a mut T) -> Self {
+        Self { bitset }
+    }
+}
+
+impl<'a, T> Iterator for DrainOnes<'a, T>
+where
+    T: Bitset,
+{
+    type Item = Index<T>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.bitset.pop_lowest()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bitset.count_ones();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for DrainOnes<'a, T>
+where
+    T: Bitset,
+{
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.bitset.pop_highest()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for DrainOnes<'a, T> where T: Bitset {}
+
+impl<'a, T> FusedIterator for DrainOnes<'a, T> where T: Bitset {}
+
+impl<'a, T> Drop for DrainOnes<'a, T>
+where
+    T: Bitset,
+{
+    fn drop(&mut self) {
+        *self.bitset = T::NONE.clone();
+    }
+}
+
+/// Iterator over all subsets of the mask [`Bitset`], in ascending order, starting from the empty one.
+///
+/// Steps with the carry-rippler trick, `next = (current - mask) & mask`, a word at a time.
+///
+/// Returned by [`Bitset::subsets`].
+#[derive(Debug, Clone)]
+pub struct Subsets<T: Bitset> {
+    mask: T,
+    next: Option<T>,
+}
+
+impl<T> Subsets<T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `Subsets`, iterating over all `2^n` subsets of `mask` with `n` set bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{iter::Subsets, prelude::*};
+    ///
+    /// let subsets: Vec<_> = Subsets::new(Bitset8::new(0b1010))
+    ///     .map(|s| s.into_inner())
+    ///     .collect();
+    ///
+    /// assert_eq!(subsets, [0b0000, 0b0010, 0b1000, 0b1010]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(mask: T) -> Self {
+        Self {
+            mask,
+            next: Some(T::NONE.clone()),
+        }
+    }
+}
+
+impl<T> Iterator for Subsets<T>
+where
+    T: Bitset,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        let len = T::BYTE_SIZE * 8;
+
+        // (current - mask) & mask == ((current | !mask) + 1) & mask
+        let mut next = T::NONE.clone();
+        let mut carry = true;
+        for w in 0..len.div_ceil(WORD_BITS) {
+            let mask = self.mask._word(w);
+            let (sum, overflow) = (current._word(w) | !mask).overflowing_add(carry as u64);
+            carry = overflow;
+            next._set_bits(
+                w * WORD_BITS,
+                (len - w * WORD_BITS).min(WORD_BITS),
+                sum & mask,
+            );
+        }
+
+        // Carry out of the last word means wrapping around to the empty subset.
+        if !carry {
+            self.next = Some(next);
+        }
+        Some(current)
+    }
+}
+
+// Returns binomial coefficient `n` choose `k`, or [`None`], if it doesn't fit into `usize`.
+fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        // Exact at every step: product of `i + 1` consecutive integers is divisible by `(i + 1)!`.
+        result = result.checked_mul((n - i) as u128)? / (i + 1) as u128;
+    }
+    usize::try_from(result).ok()
+}
+
+/// Iterator over all subsets of the mask [`Bitset`] with exactly `k` set bits, in ascending order.
+///
+/// Steps like Gosper's hack, generalized to the bits of the mask: the lowest run of set bits
+/// of the current subset is cleared, the next bit of the mask after it is set,
+/// and the rest of the run is moved down to the lowest bits of the mask.
+///
+/// Returned by [`Bitset::combinations`].
+#[derive(Debug, Clone)]
+pub struct Combinations<T: Bitset> {
+    mask: T,
+    next: Option<T>,
+    remaining: Option<usize>,
+}
+
+impl<T> Combinations<T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `Combinations`, iterating over all `n` choose `k` subsets of `mask`
+    /// with exactly `k` set bits, where `n` is count of set bits of `mask`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{iter::Combinations, prelude::*};
+    ///
+    /// let combinations: Vec<_> = Combinations::new(Bitset8::new(0b1011), 2)
+    ///     .map(|s| s.into_inner())
+    ///     .collect();
+    ///
+    /// assert_eq!(combinations, [0b0011, 0b1001, 0b1010]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(mask: T, k: usize) -> Self {
+        let next = match k {
+            0 => Some(T::NONE.clone()),
+            _ => mask
+                .select(k - 1)
+                .map(|last| mask.clone().remove_range(last.into_inner() + 1..).build()),
+        };
+        let remaining = binomial(mask.count_ones(), k);
+        Self {
+            mask,
+            next,
+            remaining,
+        }
+    }
+}
+
+impl<T> Iterator for Combinations<T>
+where
+    T: Bitset,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        self.remaining = self.remaining.map(|r| r - 1);
+
+        let len = T::BYTE_SIZE * 8;
+        if let Some(low) = current.lowest_one() {
+            // First bit of the mask past the lowest run of set bits.
+            let free = find_first(low.into_inner() + 1, len, |w| {
+                self.mask._word(w) & !current._word(w)
+            });
+            if let Some(free) = free {
+                let run = current.rank(Index::<T>::from_usize(free));
+                let mut next = current.clone();
+                next.remove_range(..free).set(Index::<T>::from_usize(free));
+                if run > 1 {
+                    let last = self.mask.select(run - 2).map_or(0, |i| i.into_inner());
+                    next = next.union(self.mask.clone().remove_range(last + 1..).build());
+                }
+                self.next = Some(next);
+            }
+        }
+        Some(current)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (&self.next, self.remaining) {
+            (None, _) => (0, Some(0)),
+            (Some(_), Some(r)) => (r, Some(r)),
+            (Some(_), None) => (usize::MAX, None),
+        }
+    }
+
+    /// Returns count of the remaining subsets, computed with binomial coefficients, without iterating.
+    ///
+    /// # Panics
+    /// Panics, if the count doesn't fit into `usize`.
+    #[inline(always)]
+    fn count(self) -> usize {
+        match self.next {
+            None => 0,
+            Some(_) => self
+                .remaining
+                .expect("count of combinations overflowed usize"),
+        }
+    }
+}
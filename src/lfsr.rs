@@ -0,0 +1,253 @@
+//! Module containing [`Lfsr`], linear-feedback shift register over a [`Bitset`],
+//! e.g. for generating pseudo-random bit sequences and scrambling.
+
+use crate::{bit::Bit, bitset::Bitset};
+use std::iter::FusedIterator;
+
+/// How feedback of the [`Lfsr`] is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LfsrMode {
+    /// Parity of the state bits at tap positions is shifted into the most significant bit.
+    Fibonacci,
+    /// Output bit is shifted out and, if it is set, taps are toggled in the shifted state.
+    Galois,
+}
+
+/// Linear-feedback shift register with the state and the tap polynomial stored in the [`Bitset`].
+///
+/// Every step shifts the state towards the lower indeces and outputs the shifted out least significant bit.
+/// State with no set bits never changes, so the register should be seeded with a non-empty one.
+///
+/// Iterating over `Lfsr` yields output bits, and never ends.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{lfsr::Lfsr, prelude::*};
+///
+/// // x^16 + x^14 + x^13 + x^11 + 1, which has the maximal period.
+/// let mut lfsr = Lfsr::galois(Bitset16::new(0xB400), Bitset16::new(0xACE1));
+///
+/// assert_eq!(lfsr.step(), One);
+/// assert_eq!(lfsr.state().into_inner(), 0xE270);
+///
+/// lfsr.step_n(65534);
+/// assert_eq!(lfsr.state().into_inner(), 0xACE1);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lfsr<T: Bitset> {
+    state: T,
+    taps: T,
+    mode: LfsrMode,
+}
+
+impl<T> Lfsr<T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `Lfsr` in `mode`, with tap polynomial `taps` and initial state `seed`.
+    pub fn new(mode: LfsrMode, taps: T, seed: T) -> Self {
+        Self {
+            state: seed,
+            taps,
+            mode,
+        }
+    }
+
+    /// Constructs a new value of `Lfsr` in [`Fibonacci`][LfsrMode::Fibonacci] mode,
+    /// with bits of `taps` selecting state bits, which parity is fed back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{lfsr::Lfsr, prelude::*};
+    ///
+    /// let mut lfsr = Lfsr::fibonacci(Bitset16::new(0x002D), Bitset16::new(0xACE1));
+    ///
+    /// assert_eq!(lfsr.step(), One);
+    /// assert_eq!(lfsr.state().into_inner(), 0x5670);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn fibonacci(taps: T, seed: T) -> Self {
+        Self::new(LfsrMode::Fibonacci, taps, seed)
+    }
+
+    /// Constructs a new value of `Lfsr` in [`Galois`][LfsrMode::Galois] mode,
+    /// with bits of `taps` toggled in the shifted state, whenever the output bit is set.
+    #[inline(always)]
+    pub fn galois(taps: T, seed: T) -> Self {
+        Self::new(LfsrMode::Galois, taps, seed)
+    }
+
+    /// Returns a reference to the current state.
+    #[inline(always)]
+    pub fn state(&self) -> &T {
+        &self.state
+    }
+
+    /// Returns a reference to the tap polynomial.
+    #[inline(always)]
+    pub fn taps(&self) -> &T {
+        &self.taps
+    }
+
+    /// Returns the mode of the register.
+    #[inline(always)]
+    pub fn mode(&self) -> LfsrMode {
+        self.mode
+    }
+
+    /// Replaces the current state with `seed`. Returns a mutable reference to `self`.
+    #[inline(always)]
+    pub fn reseed(&mut self, seed: T) -> &mut Self {
+        self.state = seed;
+        self
+    }
+
+    /// Advances the register by one step, shifting the state up to 64 bits at a time.
+    /// Returns the output bit.
+    pub fn step(&mut self) -> Bit {
+        let len = T::BYTE_SIZE * 8;
+        let output = self.state._word(0) & 1;
+        let feedback = match self.mode {
+            LfsrMode::Fibonacci => (self.state.intersection_count(&self.taps) & 1) as u64,
+            LfsrMode::Galois => 0,
+        };
+        let toggled = match self.mode {
+            LfsrMode::Fibonacci => 0,
+            LfsrMode::Galois => output,
+        };
+
+        let mut offset = 0;
+        while offset < len {
+            let width = (len - offset).min(64);
+            // Bits above this chunk are read before they are overwritten by the next one.
+            let mut word = self.state._bits(offset + 1, width);
+            if offset + width == len {
+                word |= feedback << (width - 1);
+            }
+            if toggled == 1 {
+                word ^= self.taps._bits(offset, width);
+            }
+            self.state._set_bits(offset, width, word);
+            offset += width;
+        }
+        Bit::from(output == 1)
+    }
+
+    /// Advances the register by `count` steps, discarding the output bits. Returns a mutable reference to `self`.
+    pub fn step_n(&mut self, count: usize) -> &mut Self {
+        for _ in 0..count {
+            self.step();
+        }
+        self
+    }
+}
+
+impl<T> Iterator for Lfsr<T>
+where
+    T: Bitset,
+{
+    type Item = Bit;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.step())
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T> FusedIterator for Lfsr<T> where T: Bitset {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset, Bitset16, Bitset8, Byteset, One, Zero};
+
+    // Reference implementations of 16 bit maximal length registers.
+    fn fibonacci_u16(state: u16) -> u16 {
+        let bit = (state ^ (state >> 2) ^ (state >> 3) ^ (state >> 5)) & 1;
+        (state >> 1) | (bit << 15)
+    }
+
+    fn galois_u16(state: u16) -> u16 {
+        let shifted = state >> 1;
+        if state & 1 == 1 {
+            shifted ^ 0xB400
+        } else {
+            shifted
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        let mut fibonacci = Lfsr::fibonacci(Bitset16::new(0x002D), Bitset16::new(0xACE1));
+        let mut galois = Lfsr::galois(Bitset16::new(0xB400), Bitset16::new(0xACE1));
+        let (mut fibonacci_state, mut galois_state) = (0xACE1u16, 0xACE1u16);
+
+        for _ in 0..1000 {
+            assert_eq!(fibonacci.step(), Bit::from(fibonacci_state & 1 == 1));
+            assert_eq!(galois.step(), Bit::from(galois_state & 1 == 1));
+            fibonacci_state = fibonacci_u16(fibonacci_state);
+            galois_state = galois_u16(galois_state);
+            assert_eq!(fibonacci.state().into_inner(), fibonacci_state);
+            assert_eq!(galois.state().into_inner(), galois_state);
+        }
+    }
+
+    #[test]
+    fn maximal_period() {
+        for mut lfsr in [
+            Lfsr::fibonacci(Bitset16::new(0x002D), Bitset16::new(1)),
+            Lfsr::galois(Bitset16::new(0xB400), Bitset16::new(1)),
+        ] {
+            let seed = *lfsr.state();
+            let period = (1..=u16::MAX as usize)
+                .find(|_| *lfsr.step_n(1).state() == seed)
+                .unwrap();
+
+            assert_eq!(period, 65535);
+        }
+    }
+
+    #[test]
+    fn wide_state() {
+        // State is shifted across the boundary of 64 bit words.
+        let taps = Byteset::<9>::from_indices([0, 6, 47, 53]);
+        let seed = Byteset::<9>::from_indices([0, 63, 64, 71]);
+        let mut lfsr = Lfsr::fibonacci(taps, seed);
+
+        assert_eq!(lfsr.step(), One);
+        assert_eq!(lfsr.state(), &Byteset::<9>::from_indices([62, 63, 70, 71]));
+        assert_eq!(lfsr.step(), Zero);
+        assert_eq!(lfsr.state(), &Byteset::<9>::from_indices([61, 62, 69, 70]));
+    }
+
+    #[test]
+    fn iterator() {
+        let mut lfsr = Lfsr::galois(Bitset8::new(0b10111000), Bitset8::new(0b00000011));
+        let bits: Vec<_> = lfsr.by_ref().take(3).collect();
+
+        assert_eq!(bits, [One, One, Zero]);
+        assert_eq!(lfsr.size_hint(), (usize::MAX, None));
+        assert_eq!(
+            Lfsr::galois(Bitset8::NONE, Bitset8::NONE)
+                .take(4)
+                .collect::<Vec<_>>(),
+            [Zero; 4]
+        );
+        assert_eq!(lfsr.reseed(Bitset8::new(1)).state().into_inner(), 1);
+    }
+}
@@ -1,19 +1,85 @@
 //! Crate meant to provide easy to use bitsets, with some out of the box functionality.
 //!
 //! Enable feature `"serde"` to enable `serde::Serialize` and `serde::Deserialize` for most applicable types.
+//! Alternative representations of bitsets, like hexadecimal strings, are available in `serde_formats`.
+//!
+//! Enable features `"sqlx"` and `"diesel"` to store built-in bitsets in database columns:
+//! fixed size bitsets map to integer columns and `Byteset` to binary columns.
+//!
+//! Enable feature `"bytemuck"` to implement `bytemuck::Pod` and `bytemuck::Zeroable` for built-in bitsets.
+//! Enable feature `"zerocopy"` to derive `zerocopy::AsBytes`, `zerocopy::FromBytes` and `zerocopy::FromZeroes`
+//! for built-in bitsets.
+//!
+//! Enable feature `"arbitrary"` to implement `arbitrary::Arbitrary` for built-in bitsets and `Index`,
+//! for use in fuzz targets.
+//!
+//! Enable feature `"proptest"` to generate bitsets in property tests with `proptest_strategies`.
+//!
+//! Enable feature `"quickcheck"` to implement `quickcheck::Arbitrary` for built-in bitsets and `Index`.
+//! Bitsets shrink by clearing set bits one at a time.
+//!
+//! Enable feature `"rand"` to sample bitsets with `rand`, uniformly or with a fixed count of set bits.
+//!
+//! Enable feature `"rkyv"` to derive `rkyv::Archive`, `rkyv::Serialize` and `rkyv::Deserialize` for built-in bitsets.
+//! Bytes of archived `Byteset`s can be read without deserialization.
+//!
+//! Enable feature `"schemars"` to describe built-in bitsets with `schemars::JsonSchema`,
+//! matching their default `serde` representation.
+//!
+//! Enable feature `"clap"` to parse command line arguments into bitsets with `cli::BitsetValueParser`.
+//!
+//! Enable feature `"derive"` to derive `flags_enum::FlagsEnum` for enums
+//! and `bitset_struct::BitsetStruct` for structs.
 
 pub mod bitset;
 pub mod bitset128;
 pub mod bitset16;
+pub mod bitset256;
 pub mod bitset32;
+pub mod bitset512;
 pub mod bitset64;
 pub mod bitset8;
+pub mod bitset_size;
+pub mod bitset_vec;
 pub mod byteset;
 
+pub mod access;
+pub mod ascii_set;
 pub mod bit;
+pub mod bit_io;
+pub mod bitmap_index;
+pub mod bitset_struct;
+pub mod byte_class;
+#[cfg(feature = "clap")]
+pub mod cli;
+pub mod cursor;
+mod dispatch;
+pub mod display;
+pub mod enum_set;
 pub mod error;
+pub mod expr;
+pub mod flags;
+pub mod flags_enum;
 pub mod index;
+pub mod iter;
+pub mod lfsr;
+pub mod magic;
+pub mod parse;
+pub mod pbm;
+pub mod permissions;
+#[cfg(feature = "proptest")]
+pub mod proptest_strategies;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls;
+#[cfg(feature = "rand")]
+mod rand_impls;
 pub mod safety_markers;
+#[cfg(feature = "serde")]
+pub mod serde_formats;
+pub mod slice;
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+mod sql;
+pub mod stream;
 
 /// Prelude.
 pub mod prelude {
@@ -25,13 +91,19 @@ pub mod prelude {
 
     pub use bitset128::Bitset128;
     pub use bitset16::Bitset16;
+    pub use bitset256::Bitset256;
     pub use bitset32::Bitset32;
+    pub use bitset512::Bitset512;
     pub use bitset64::Bitset64;
     pub use bitset8::Bitset8;
+    pub use bitset_size::BitsetSize;
+    pub use bitset_vec::BitsetVec;
     pub use byteset::Byteset;
 
     pub use index::Index;
 
+    pub use iter::BitsetIteratorExt;
+
     /// Alias for [`Index<Bitset8>`][Index].
     pub type Index8 = Index<Bitset8>;
     /// Alias for [`Index<Bitset16>`][Index].
@@ -42,4 +114,10 @@ pub mod prelude {
     pub type Index64 = Index<Bitset64>;
     /// Alias for [`Index<Bitset128>`][Index].
     pub type Index128 = Index<Bitset128>;
+    /// Alias for [`Index<Bitset256>`][Index].
+    pub type Index256 = Index<Bitset256>;
+    /// Alias for [`Index<Bitset512>`][Index].
+    pub type Index512 = Index<Bitset512>;
+    /// Alias for [`Index<BitsetSize>`][Index].
+    pub type IndexSize = Index<BitsetSize>;
 }
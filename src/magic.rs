@@ -0,0 +1,326 @@
+//! Module containing helpers for generation of magic bitboards over [`Bitset64`]:
+//! [`Candidates`], [`Magic`], [`MagicTable`] and [`PackedMagics`].
+//!
+//! Relevant occupancies of a mask are its subsets, enumerated with [`Bitset::subsets`].
+//!
+//! Magic bitboards map every relevant occupancy of a square to an index into the table of attacks,
+//! with a single multiplication and shift: `((occupancy & mask) * magic) >> shift`.
+
+use crate::{bitset::Bitset, prelude::Bitset64};
+
+/// Infinite iterator over pseudo-random magic number candidates, with few bits set,
+/// as those are more likely to be magic.
+///
+/// Generated with xorshift64*, so the sequence is fully determined by the seed.
+#[derive(Debug, Clone)]
+pub struct Candidates {
+    state: u64,
+}
+
+impl Candidates {
+    /// Constructs a new value of `Candidates` from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed ^ 0x9E3779B97F4A7C15,
+        }
+    }
+
+    // Returns next pseudo-random number of the sequence.
+    fn next_random(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+}
+
+impl Iterator for Candidates {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_random() & self.next_random() & self.next_random())
+    }
+}
+
+/// Magic number of a single square: relevant occupancy `mask`, `magic` multiplier and `shift`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Magic {
+    /// Mask of the relevant occupancy bits.
+    pub mask: Bitset64,
+    /// Magic multiplier.
+    pub magic: u64,
+    /// Shift of the product, equal to `64 - index_bits`.
+    /// Shift of `64` maps every occupancy to the single entry.
+    pub shift: u32,
+}
+
+impl Magic {
+    /// Returns index into the table of attacks for `occupancy`.
+    #[inline(always)]
+    pub fn index(&self, occupancy: Bitset64) -> usize {
+        (occupancy.into_inner() & self.mask.into_inner())
+            .wrapping_mul(self.magic)
+            .checked_shr(self.shift)
+            .unwrap_or(0) as usize
+    }
+}
+
+/// [`Magic`] of a single square with its table of attacks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MagicTable {
+    magic: Magic,
+    attacks: Vec<Bitset64>,
+}
+
+impl MagicTable {
+    /// Tries to build a table of `2^index_bits` entries for relevant occupancy `mask`,
+    /// using `magic` multiplier, where `attacks` returns attacks for every submask of `mask`.
+    ///
+    /// Returns [`None`], if `magic` maps two submasks with different attacks to the same entry,
+    /// or if `index_bits` is greater than `64` or the table of `2^index_bits` entries can't be addressed.
+    /// Table of `index_bits` equal to `0` has a single entry, which is only valid for the empty `mask`.
+    pub fn try_new(
+        mask: Bitset64,
+        magic: u64,
+        index_bits: u32,
+        attacks: impl Fn(Bitset64) -> Bitset64,
+    ) -> Option<Self> {
+        let occupancies: Vec<_> = mask.subsets().map(|o| (o, attacks(o))).collect();
+        Self::from_occupancies(mask, magic, index_bits, &occupancies)
+    }
+
+    // Builds the table from precomputed pairs of occupancy and attacks, or returns None on collision.
+    fn from_occupancies(
+        mask: Bitset64,
+        magic: u64,
+        index_bits: u32,
+        occupancies: &[(Bitset64, Bitset64)],
+    ) -> Option<Self> {
+        let magic = Magic {
+            mask,
+            magic,
+            shift: 64u32.checked_sub(index_bits)?,
+        };
+        let mut table = vec![None; 1usize.checked_shl(index_bits)?];
+        for (occupancy, attack) in occupancies {
+            match &mut table[magic.index(*occupancy)] {
+                Some(existing) if existing != attack => return None,
+                entry => *entry = Some(*attack),
+            }
+        }
+
+        Some(Self {
+            magic,
+            attacks: table
+                .into_iter()
+                .map(|a| a.unwrap_or(Bitset64::NONE))
+                .collect(),
+        })
+    }
+
+    /// Searches `candidates` for a magic number of relevant occupancy `mask`,
+    /// mapping it into a table of `2^index_bits` entries, where `attacks` returns attacks for every submask of `mask`.
+    /// Returns the table of the first found magic number, or [`None`], if `candidates` ran out.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{magic::{Candidates, MagicTable}, prelude::*};
+    ///
+    /// // Blocker on bit 1 or 2 stops the attacks along the line of bits 1..4.
+    /// let attacks = |occupancy: Bitset64| {
+    ///     let mut attack = Bitset64::NONE;
+    ///     for i in 1..4 {
+    ///         attack.set(Index64::from_usize(i));
+    ///         if bool::from(occupancy.bit(Index64::from_usize(i))) {
+    ///             break;
+    ///         }
+    ///     }
+    ///     attack
+    /// };
+    /// let mask = Bitset64::new(0b0110);
+    ///
+    /// let table = MagicTable::find(mask, 2, attacks, Candidates::new(1).take(10_000))
+    ///     .ok_or("no magic found")?;
+    ///
+    /// assert_eq!(table.get(Bitset64::new(0b0100)).into_inner(), 0b0110);
+    /// assert_eq!(table.get(Bitset64::new(0b0000)).into_inner(), 0b1110);
+    /// assert_eq!(table.get(Bitset64::new(0b0010)).into_inner(), 0b0010);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn find(
+        mask: Bitset64,
+        index_bits: u32,
+        attacks: impl Fn(Bitset64) -> Bitset64,
+        candidates: impl IntoIterator<Item = u64>,
+    ) -> Option<Self> {
+        let occupancies: Vec<_> = mask.subsets().map(|o| (o, attacks(o))).collect();
+        candidates
+            .into_iter()
+            // Magic has to move enough bits of the mask into the top bits of the product.
+            .filter(|magic| {
+                (mask.into_inner().wrapping_mul(*magic) & 0xFF00000000000000).count_ones() >= 6
+                    || index_bits < 8
+            })
+            .find_map(|magic| Self::from_occupancies(mask, magic, index_bits, &occupancies))
+    }
+
+    /// Returns the [`Magic`] of the table.
+    #[inline(always)]
+    pub fn magic(&self) -> Magic {
+        self.magic
+    }
+
+    /// Returns entries of the table.
+    #[inline(always)]
+    pub fn attacks(&self) -> &[Bitset64] {
+        &self.attacks
+    }
+
+    /// Returns attacks for `occupancy`.
+    #[inline(always)]
+    pub fn get(&self, occupancy: Bitset64) -> Bitset64 {
+        self.attacks[self.magic.index(occupancy)]
+    }
+}
+
+/// [`MagicTable`]s of multiple squares, packed into a single table of attacks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackedMagics {
+    magics: Vec<(Magic, usize)>,
+    attacks: Vec<Bitset64>,
+}
+
+impl PackedMagics {
+    /// Packs `tables` into a single table of attacks, in order.
+    pub fn new(tables: impl IntoIterator<Item = MagicTable>) -> Self {
+        let mut magics = vec![];
+        let mut attacks = vec![];
+        for table in tables {
+            magics.push((table.magic, attacks.len()));
+            attacks.extend(table.attacks);
+        }
+        Self { magics, attacks }
+    }
+
+    /// Returns the count of packed squares.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.magics.len()
+    }
+
+    /// Returns [`true`], if there are no packed squares, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.magics.is_empty()
+    }
+
+    /// Returns attacks from the `square`-th packed square for `occupancy`.
+    ///
+    /// # Panics
+    /// Panics, if `square` is out of bounds.
+    #[inline(always)]
+    pub fn get(&self, square: usize, occupancy: Bitset64) -> Bitset64 {
+        let (magic, offset) = &self.magics[square];
+        self.attacks[offset + magic.index(occupancy)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::Index64;
+
+    // Rook attacks from `square`, computed by casting rays.
+    fn rook_attacks(square: usize, occupancy: Bitset64) -> Bitset64 {
+        let (file, rank) = ((square % 8) as i32, (square / 8) as i32);
+        let mut attacks = Bitset64::NONE;
+        for (df, dr) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            let (mut f, mut r) = (file + df, rank + dr);
+            while (0..8).contains(&f) && (0..8).contains(&r) {
+                let i = Index64::from_usize((r * 8 + f) as usize);
+                attacks.set(i);
+                if bool::from(occupancy.bit(i)) {
+                    break;
+                }
+                f += df;
+                r += dr;
+            }
+        }
+        attacks
+    }
+
+    // Relevant occupancy mask of the rook on `square`: attacks on empty board without edges.
+    fn rook_mask(square: usize) -> Bitset64 {
+        let (file, rank) = (square % 8, square / 8);
+        let mut mask = Bitset64::NONE;
+        for i in rook_attacks(square, Bitset64::NONE).ones() {
+            let (f, r) = (i.into_inner() % 8, i.into_inner() / 8);
+            if (f == file && (1..7).contains(&r)) || (r == rank && (1..7).contains(&f)) {
+                mask.set(i);
+            }
+        }
+        mask
+    }
+
+    #[test]
+    fn submasks_count() {
+        let mask = rook_mask(27);
+        let all: Vec<_> = mask.subsets().collect();
+
+        assert_eq!(all.len(), 1 << mask.count_ones());
+        assert!(all.iter().all(|s| mask.includes(s)));
+        assert_eq!(Bitset64::NONE.subsets().count(), 1);
+    }
+
+    #[test]
+    fn empty_mask() {
+        let table = MagicTable::try_new(Bitset64::NONE, 0, 0, |_| Bitset64::new(0b111))
+            .expect("empty mask should fit the single entry");
+
+        assert_eq!(table.attacks().len(), 1);
+        assert_eq!(table.magic().shift, 64);
+        assert_eq!(table.get(Bitset64::ALL).into_inner(), 0b111);
+    }
+
+    #[test]
+    fn index_bits_out_of_range() {
+        let mask = Bitset64::new(0b0110);
+
+        assert_eq!(MagicTable::try_new(mask, 1, 65, |o| o), None);
+        assert_eq!(MagicTable::try_new(mask, 1, u32::MAX, |o| o), None);
+        assert_eq!(MagicTable::try_new(mask, 0, 0, |o| o), None);
+    }
+
+    #[test]
+    fn rook_magics() {
+        let squares = [0, 27];
+        let tables: Vec<_> = squares
+            .iter()
+            .map(|&square| {
+                let mask = rook_mask(square);
+                MagicTable::find(
+                    mask,
+                    mask.count_ones() as u32,
+                    |o| rook_attacks(square, o),
+                    Candidates::new(square as u64).take(1_000_000),
+                )
+                .expect("magic should be found")
+            })
+            .collect();
+
+        let packed = PackedMagics::new(tables.clone());
+        assert_eq!(packed.len(), 2);
+
+        for (k, &square) in squares.iter().enumerate() {
+            for occupancy in rook_mask(square).subsets() {
+                let expected = rook_attacks(square, occupancy);
+                assert_eq!(tables[k].get(occupancy), expected);
+                assert_eq!(packed.get(k, occupancy | Bitset64::new(1 << 63)), expected);
+            }
+        }
+    }
+}
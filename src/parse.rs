@@ -0,0 +1,246 @@
+//! Module containing [`ParseBitsetError`], error of parsing [`Bitset`]s from strings.
+//!
+//! All built-in `Bitset`s implement [`FromStr`], accepting binary (`0b1010_1111`), octal (`0o17`)
+//! and hexadecimal (`0xFF`) literals, as well as plain binary digits (`1010_1111`), like the ones
+//! fixed size `Bitset`s are displayed with. Digits can be separated with `_`.
+//! Bit `i` of the literal is bit of the `Bitset` at [`Index`][crate::index::Index] `i`.
+//!
+//! [`Bitset::try_from_str_radix`] parses digits of any power of two radix without a prefix.
+
+use crate::{
+    bitset::{bit_len, Bitset},
+    error::{ConvError, ConvTarget},
+    prelude::{
+        Bitset128, Bitset16, Bitset256, Bitset32, Bitset512, Bitset64, Bitset8, BitsetSize, Byteset,
+    },
+};
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+/// Error of parsing [`Bitset`] from a string. Implements [`Error`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParseBitsetError {
+    /// There are no digits in the string.
+    Empty,
+    /// Character at byte `position` of the string is not a digit of the radix.
+    InvalidDigit {
+        /// Byte position of the character in the parsed string.
+        position: usize,
+        /// The character.
+        found: char,
+    },
+    /// Value doesn't fit into the `Bitset`.
+    Overflow(ConvError),
+}
+
+impl Error for ParseBitsetError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Overflow(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ParseBitsetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "failed to parse Bitset: no digits"),
+            Self::InvalidDigit { position, found } => write!(
+                f,
+                "failed to parse Bitset: invalid digit {found:?} at position {position}"
+            ),
+            Self::Overflow(e) => write!(f, "failed to parse Bitset: {e}"),
+        }
+    }
+}
+
+// Parses `digits` of `radix`, separated with `_`, into Bitset.
+// Positions in errors are offset by `offset`, the length of the skipped prefix.
+pub(crate) fn parse_radix<T>(digits: &str, radix: u32, offset: usize) -> Result<T, ParseBitsetError>
+where
+    T: Bitset,
+{
+    assert!(
+        radix.is_power_of_two() && (2..=32).contains(&radix),
+        "radix must be a power of two in range 2..=32"
+    );
+    let width = radix.trailing_zeros() as usize;
+    let len = bit_len::<T>();
+
+    let mut bitset = T::NONE.clone();
+    let mut k = 0;
+    let mut empty = true;
+    let mut required = 0;
+    for (i, c) in digits.char_indices().rev() {
+        if c == '_' {
+            continue;
+        }
+        let digit = c.to_digit(radix).ok_or(ParseBitsetError::InvalidDigit {
+            position: offset + i,
+            found: c,
+        })? as u64;
+        empty = false;
+
+        if digit != 0 {
+            required = k + (64 - digit.leading_zeros() as usize);
+            if required <= len {
+                bitset._set_bits(k, width.min(len - k), digit);
+            }
+        }
+        k += width;
+    }
+
+    match (empty, required > len) {
+        (true, _) => Err(ParseBitsetError::Empty),
+        (false, true) => Err(ParseBitsetError::Overflow(ConvError::new(
+            ConvTarget::Set(required),
+            ConvTarget::Set(len),
+        ))),
+        (false, false) => Ok(bitset),
+    }
+}
+
+// Parses `s` with optional radix prefix into Bitset. Digits without prefix are binary.
+pub(crate) fn parse_prefixed<T>(s: &str) -> Result<T, ParseBitsetError>
+where
+    T: Bitset,
+{
+    match s.get(..2) {
+        Some("0b" | "0B") => parse_radix(&s[2..], 2, 2),
+        Some("0o" | "0O") => parse_radix(&s[2..], 8, 2),
+        Some("0x" | "0X") => parse_radix(&s[2..], 16, 2),
+        _ => parse_radix(s, 2, 0),
+    }
+}
+
+// Implements FromStr, delegating to parse_prefixed.
+macro_rules! impl_from_str {
+    ($([$($generics:tt)*] $bitset:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> FromStr for $bitset {
+                type Err = ParseBitsetError;
+
+                #[inline(always)]
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    parse_prefixed(s)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str!(
+    [] Bitset8,
+    [] Bitset16,
+    [] Bitset32,
+    [] Bitset64,
+    [] Bitset128,
+    [] Bitset256,
+    [] Bitset512,
+    [] BitsetSize,
+    [const N: usize] Byteset<N>,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    #[test]
+    fn prefixes() -> TestResult {
+        assert_eq!("0b1010_1111".parse::<Bitset8>()?, Bitset8::new(0b10101111));
+        assert_eq!("0B1010".parse::<Bitset8>()?, Bitset8::new(0b1010));
+        assert_eq!("0o17".parse::<Bitset8>()?, Bitset8::new(0o17));
+        assert_eq!("0xFf".parse::<Bitset8>()?, Bitset8::ALL);
+        assert_eq!("1010_1111".parse::<Bitset8>()?, Bitset8::new(0b10101111));
+        assert_eq!("0x0000_01".parse::<Bitset8>()?, Bitset8::new(1));
+
+        assert_eq!(
+            "0x0102030405060708090a0b0c0d0e0f10_11".parse::<Bitset256>()?,
+            Bitset256::new([0x02030405060708090a0b0c0d0e0f1011, 0x01])
+        );
+        assert_eq!("0o7".parse::<Byteset<3>>()?, Byteset::new([7, 0, 0]));
+        assert_eq!(
+            "0x800000".parse::<Byteset<3>>()?,
+            Byteset::new([0, 0, 0x80])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> TestResult {
+        let bitset = Bitset64::new(0xDEAD_BEEF_0123_4567);
+        assert_eq!(bitset.to_string().parse::<Bitset64>()?, bitset);
+        assert_eq!(format!("{bitset:#x}").parse::<Bitset64>()?, bitset);
+        assert_eq!(format!("{bitset:#o}").parse::<Bitset64>()?, bitset);
+
+        let bitset = Bitset512::new(std::array::from_fn(|w| 0x0123_4567_89AB_CDEF << w));
+        assert_eq!(bitset.to_string().parse::<Bitset512>()?, bitset);
+        assert_eq!(format!("{bitset:x}").parse::<Bitset512>()?, bitset);
+        Ok(())
+    }
+
+    #[test]
+    fn radix() -> TestResult {
+        assert_eq!(
+            Bitset16::try_from_str_radix("ff_00", 16)?,
+            Bitset16::new(0xFF00)
+        );
+        assert_eq!(
+            Bitset16::try_from_str_radix("vv", 32)?,
+            Bitset16::new(0x3FF)
+        );
+        assert_eq!(
+            Bitset8::try_from_str_radix("3210", 4)?,
+            Bitset8::new(0b11100100)
+        );
+        assert!(Bitset8::try_from_str_radix("0x1", 16).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("".parse::<Bitset8>(), Err(ParseBitsetError::Empty));
+        assert_eq!("0x__".parse::<Bitset8>(), Err(ParseBitsetError::Empty));
+        assert_eq!(
+            "0b10201".parse::<Bitset8>(),
+            Err(ParseBitsetError::InvalidDigit {
+                position: 4,
+                found: '2'
+            })
+        );
+        assert_eq!(
+            "10 1".parse::<Bitset8>(),
+            Err(ParseBitsetError::InvalidDigit {
+                position: 2,
+                found: ' '
+            })
+        );
+        assert_eq!(
+            "0x1FF".parse::<Bitset8>(),
+            Err(ParseBitsetError::Overflow(ConvError::new(
+                ConvTarget::Set(9),
+                ConvTarget::Set(8)
+            )))
+        );
+        assert_eq!(
+            "0o400".parse::<Bitset8>().map_err(|e| e.to_string()),
+            Err(
+                "failed to parse Bitset: failed to convert from Bitset (size 9) to Bitset (size 8)"
+                    .to_owned()
+            )
+        );
+        assert!("0x1000000".parse::<Byteset<3>>().is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_radix() {
+        let _ = Bitset8::try_from_str_radix("1", 10);
+    }
+}
@@ -0,0 +1,236 @@
+//! Module containing import and export of [`Bitset`]s as netpbm PBM 1-bit images.
+//!
+//! Bitset is treated as an image of rows of `width` bits each, stored one after another:
+//! pixel at column `x` and row `y` is the bit at [`Index`] `y * width + x`.
+//! Set bits are black pixels.
+
+use crate::{bitset::Bitset, index::Index};
+use std::io::{self, BufRead, Read, Write};
+
+// Constructs io::Error of kind `kind` with `message`.
+fn error(kind: io::ErrorKind, message: &str) -> io::Error {
+    io::Error::new(kind, message.to_owned())
+}
+
+/// Writes `height` rows of `width` bits of `bitset` into `writer` as a binary (`P4`) PBM image.
+///
+/// # Errors
+/// Returns [`io::Error`] of kind [`InvalidInput`][io::ErrorKind::InvalidInput],
+/// if the image doesn't fit into the `bitset`, or any error returned by `writer`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{pbm, prelude::*};
+///
+/// let bitset = Bitset16::new(0b1001_0110_0110_1001);
+/// let mut image = vec![];
+/// pbm::write(&bitset, 4, 4, &mut image)?;
+///
+/// assert_eq!(image, b"P4\n4 4\n\x90\x60\x60\x90");
+/// #   Ok(())
+/// # }
+/// ```
+pub fn write<T, W>(bitset: &T, width: usize, height: usize, mut writer: W) -> io::Result<()>
+where
+    T: Bitset,
+    W: Write,
+{
+    if width
+        .checked_mul(height)
+        .is_none_or(|len| len > T::BYTE_SIZE * 8)
+    {
+        return Err(error(
+            io::ErrorKind::InvalidInput,
+            "image doesn't fit into the Bitset",
+        ));
+    }
+
+    write!(writer, "P4\n{width} {height}\n")?;
+    let mut row = vec![0u8; width.div_ceil(8)];
+    for y in 0..height {
+        row.fill(0);
+        for x0 in (0..width).step_by(64) {
+            let mut bits = bitset._bits(y * width + x0, (width - x0).min(64));
+            while bits != 0 {
+                let x = x0 + bits.trailing_zeros() as usize;
+                row[x / 8] |= 0x80 >> (x % 8);
+                bits &= bits - 1;
+            }
+        }
+        writer.write_all(&row)?;
+    }
+    Ok(())
+}
+
+// Reads next token of the PBM header, skipping whitespace and comments.
+fn header_token<R: BufRead>(reader: &mut R) -> io::Result<String> {
+    let mut token = String::new();
+    let mut byte = [0];
+    loop {
+        if reader.read(&mut byte)? == 0 {
+            break;
+        }
+        match byte[0] {
+            b'#' => {
+                reader.read_until(b'\n', &mut vec![])?;
+                if !token.is_empty() {
+                    break;
+                }
+            }
+            b if b.is_ascii_whitespace() => {
+                if !token.is_empty() {
+                    break;
+                }
+            }
+            b => token.push(b as char),
+        }
+    }
+    if token.is_empty() {
+        return Err(error(
+            io::ErrorKind::UnexpectedEof,
+            "unexpected end of PBM header",
+        ));
+    }
+    Ok(token)
+}
+
+/// Reads a PBM image, either plain (`P1`) or binary (`P4`), from `reader` into `Bitset`.
+/// Returns the `Bitset`, width and height of the image.
+///
+/// # Errors
+/// Returns [`io::Error`] of kind [`InvalidData`][io::ErrorKind::InvalidData],
+/// if the image is not a valid PBM image or doesn't fit into the `Bitset`,
+/// or any error returned by `reader`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{pbm, prelude::*};
+///
+/// let image = b"P1\n# cross\n3 3\n0 1 0\n1 1 1\n0 1 0\n";
+/// let (bitset, width, height) = pbm::read::<Bitset16, _>(&image[..])?;
+///
+/// assert_eq!((width, height), (3, 3));
+/// assert_eq!(bitset.into_inner(), 0b010_111_010);
+/// #   Ok(())
+/// # }
+/// ```
+pub fn read<T, R>(reader: R) -> io::Result<(T, usize, usize)>
+where
+    T: Bitset,
+    R: Read,
+{
+    let mut reader = io::BufReader::new(reader);
+    let invalid = |message| error(io::ErrorKind::InvalidData, message);
+
+    let magic = header_token(&mut reader)?;
+    let binary = match magic.as_str() {
+        "P1" => false,
+        "P4" => true,
+        _ => return Err(invalid("not a PBM image")),
+    };
+    let width: usize = header_token(&mut reader)?
+        .parse()
+        .map_err(|_| invalid("invalid PBM width"))?;
+    let height: usize = header_token(&mut reader)?
+        .parse()
+        .map_err(|_| invalid("invalid PBM height"))?;
+    if width
+        .checked_mul(height)
+        .is_none_or(|len| len > T::BYTE_SIZE * 8)
+    {
+        return Err(invalid("image doesn't fit into the Bitset"));
+    }
+
+    let mut bitset = T::NONE.clone();
+    if binary {
+        let mut row = vec![0u8; width.div_ceil(8)];
+        for y in 0..height {
+            reader.read_exact(&mut row)?;
+            for x in 0..width {
+                if row[x / 8] & (0x80 >> (x % 8)) != 0 {
+                    bitset.set(Index::from_usize(y * width + x));
+                }
+            }
+        }
+    } else {
+        let mut i = 0;
+        let mut byte = [0];
+        while i < width * height {
+            if reader.read(&mut byte)? == 0 {
+                return Err(error(
+                    io::ErrorKind::UnexpectedEof,
+                    "unexpected end of PBM data",
+                ));
+            }
+            match byte[0] {
+                b'0' => i += 1,
+                b'1' => {
+                    bitset.set(Index::from_usize(i));
+                    i += 1;
+                }
+                b'#' => {
+                    reader.read_until(b'\n', &mut vec![])?;
+                }
+                b if b.is_ascii_whitespace() => {}
+                _ => return Err(invalid("invalid PBM pixel")),
+            }
+        }
+    }
+    Ok((bitset, width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::Byteset;
+    use std::error::Error;
+
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    #[test]
+    fn round_trip() -> TestResult {
+        let bitset = Byteset::<8>::from_ranges([0..3, 10..12, 25..26, 40..50]);
+        let mut image = vec![];
+        write(&bitset, 10, 5, &mut image)?;
+
+        assert!(image.starts_with(b"P4\n10 5\n"));
+        assert_eq!(image.len(), 8 + 5 * 2);
+
+        let (read_bitset, width, height) = read::<Byteset<8>, _>(&image[..])?;
+        assert_eq!((read_bitset, width, height), (bitset, 10, 5));
+        Ok(())
+    }
+
+    #[test]
+    fn plain() -> TestResult {
+        let image = b"P1 # comment\n 4 2 \n1001\n0 1 1 0";
+        let (bitset, width, height) = read::<Byteset<1>, _>(&image[..])?;
+
+        assert_eq!((width, height), (4, 2));
+        assert_eq!(bitset.into_inner(), [0b01101001]);
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let bitset = Byteset::<1>::NONE;
+
+        let err = write(&bitset, 3, 3, vec![]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        let err = read::<Byteset<1>, _>(&b"P1 3 3 000000000"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let err = read::<Byteset<1>, _>(&b"P2 2 2 0000"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let err = read::<Byteset<1>, _>(&b"P4 4 2 \x90"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,280 @@
+//! Module containing [`Permissions`], Unix file permission bits over [`Bitset16`].
+
+use crate::{
+    access::ReadWriteBits,
+    error::{ConvError, ConvResult, ConvTarget},
+    prelude::Bitset16,
+};
+use std::fmt::{Display, Octal};
+
+/// Class of users, which permissions apply to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Class {
+    /// Owner of the file.
+    User,
+    /// Group of the file.
+    Group,
+    /// Everyone else.
+    Other,
+}
+
+/// Read permission, in the `rwx` bits of a [`Class`].
+pub const READ: u8 = 0b100;
+/// Write permission, in the `rwx` bits of a [`Class`].
+pub const WRITE: u8 = 0b010;
+/// Execute permission, in the `rwx` bits of a [`Class`].
+pub const EXECUTE: u8 = 0b001;
+
+const OTHER: ReadWriteBits<Bitset16, 0, 3> = ReadWriteBits::new();
+const GROUP: ReadWriteBits<Bitset16, 3, 3> = ReadWriteBits::new();
+const USER: ReadWriteBits<Bitset16, 6, 3> = ReadWriteBits::new();
+const STICKY: ReadWriteBits<Bitset16, 9, 1> = ReadWriteBits::new();
+const SETGID: ReadWriteBits<Bitset16, 10, 1> = ReadWriteBits::new();
+const SETUID: ReadWriteBits<Bitset16, 11, 1> = ReadWriteBits::new();
+
+/// Unix file permissions: `rwx` bits for user, group and others, plus setuid, setgid and sticky bits.
+///
+/// Bits are laid out the same way as in the octal mode, so `0o4755` is `rwsr-xr-x`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::permissions::{Class, Permissions, WRITE};
+///
+/// let mut permissions = Permissions::from_mode(0o750)?;
+/// permissions.set(Class::Group, permissions.get(Class::Group) | WRITE);
+///
+/// assert_eq!(permissions.mode(), 0o770);
+/// assert_eq!(permissions.to_string(), "rwxrwx---");
+/// assert_eq!(format!("{permissions:o}"), "770");
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
+#[repr(transparent)]
+pub struct Permissions(Bitset16);
+
+impl Permissions {
+    /// Constructs a new value of `Permissions` from octal `mode`.
+    ///
+    /// # Errors
+    /// This function errors, if `mode` has bits set above `0o7777`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::permissions::Permissions;
+    ///
+    /// assert_eq!(Permissions::from_mode(0o1777)?.to_string(), "rwxrwxrwt");
+    /// assert!(Permissions::from_mode(0o10000).is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub const fn from_mode(mode: u16) -> ConvResult<Self> {
+        if mode <= 0o7777 {
+            Ok(Self(Bitset16::new(mode)))
+        } else {
+            Err(ConvError::new(
+                ConvTarget::Raw(mode as usize),
+                ConvTarget::Set(12),
+            ))
+        }
+    }
+
+    /// Constructs a new value of `Permissions` from octal `mode`, ignoring bits above `0o7777`.
+    #[inline(always)]
+    pub const fn from_mode_truncate(mode: u16) -> Self {
+        Self(Bitset16::new(mode & 0o7777))
+    }
+
+    /// Returns octal mode of `Permissions`.
+    #[inline(always)]
+    pub const fn mode(&self) -> u16 {
+        self.0.into_inner()
+    }
+
+    /// Returns inner [`Bitset16`] of `Permissions`.
+    #[inline(always)]
+    pub const fn into_inner(&self) -> Bitset16 {
+        self.0
+    }
+
+    /// Returns `rwx` bits of `class`, combination of [`READ`], [`WRITE`] and [`EXECUTE`].
+    pub fn get(&self, class: Class) -> u8 {
+        (match class {
+            Class::User => USER.read(&self.0),
+            Class::Group => GROUP.read(&self.0),
+            Class::Other => OTHER.read(&self.0),
+        }) as u8
+    }
+
+    /// Sets `rwx` bits of `class` to `rwx`, combination of [`READ`], [`WRITE`] and [`EXECUTE`].
+    /// Other bits of `rwx` are ignored. Returns a mutable reference to `self`.
+    pub fn set(&mut self, class: Class, rwx: u8) -> &mut Self {
+        match class {
+            Class::User => USER.write(&mut self.0, rwx as u64),
+            Class::Group => GROUP.write(&mut self.0, rwx as u64),
+            Class::Other => OTHER.write(&mut self.0, rwx as u64),
+        }
+        self
+    }
+
+    /// Returns [`true`], if the setuid bit is set, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn setuid(&self) -> bool {
+        SETUID.read(&self.0) != 0
+    }
+
+    /// Sets the setuid bit to `value`. Returns a mutable reference to `self`.
+    #[inline(always)]
+    pub fn set_setuid(&mut self, value: bool) -> &mut Self {
+        SETUID.write(&mut self.0, value as u64);
+        self
+    }
+
+    /// Returns [`true`], if the setgid bit is set, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn setgid(&self) -> bool {
+        SETGID.read(&self.0) != 0
+    }
+
+    /// Sets the setgid bit to `value`. Returns a mutable reference to `self`.
+    #[inline(always)]
+    pub fn set_setgid(&mut self, value: bool) -> &mut Self {
+        SETGID.write(&mut self.0, value as u64);
+        self
+    }
+
+    /// Returns [`true`], if the sticky bit is set, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn sticky(&self) -> bool {
+        STICKY.read(&self.0) != 0
+    }
+
+    /// Sets the sticky bit to `value`. Returns a mutable reference to `self`.
+    #[inline(always)]
+    pub fn set_sticky(&mut self, value: bool) -> &mut Self {
+        STICKY.write(&mut self.0, value as u64);
+        self
+    }
+}
+
+impl TryFrom<u16> for Permissions {
+    type Error = ConvError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::from_mode(value)
+    }
+}
+
+impl From<Permissions> for u16 {
+    fn from(value: Permissions) -> Self {
+        value.mode()
+    }
+}
+
+impl Display for Permissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let special = [
+            (Class::User, self.setuid(), 's'),
+            (Class::Group, self.setgid(), 's'),
+            (Class::Other, self.sticky(), 't'),
+        ];
+        for (class, special, special_char) in special {
+            let rwx = self.get(class);
+            let execute = match (rwx & EXECUTE != 0, special) {
+                (true, true) => special_char,
+                (false, true) => special_char.to_ascii_uppercase(),
+                (true, false) => 'x',
+                (false, false) => '-',
+            };
+            write!(
+                f,
+                "{}{}{execute}",
+                if rwx & READ != 0 { 'r' } else { '-' },
+                if rwx & WRITE != 0 { 'w' } else { '-' },
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Octal for Permissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Octal::fmt(&self.mode(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    #[test]
+    fn classes() -> TestResult {
+        let mut permissions = Permissions::from_mode(0o754)?;
+
+        assert_eq!(permissions.get(Class::User), READ | WRITE | EXECUTE);
+        assert_eq!(permissions.get(Class::Group), READ | EXECUTE);
+        assert_eq!(permissions.get(Class::Other), READ);
+
+        permissions.set(Class::Other, 0).set(Class::User, 0xFF);
+        assert_eq!(permissions.mode(), 0o750);
+        Ok(())
+    }
+
+    #[test]
+    fn special_bits() -> TestResult {
+        let mut permissions = Permissions::from_mode(0o644)?;
+        permissions
+            .set_setuid(true)
+            .set_setgid(true)
+            .set_sticky(true);
+
+        assert_eq!(permissions.mode(), 0o7644);
+        assert_eq!(permissions.to_string(), "rwSr-Sr-T");
+        assert_eq!(Permissions::from_mode(0o6711)?.to_string(), "rws--s--x");
+
+        permissions.set_setgid(false);
+        assert!(permissions.setuid() && !permissions.setgid() && permissions.sticky());
+        Ok(())
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            Permissions::try_from(0o17777),
+            Err(ConvError::new(
+                ConvTarget::Raw(0o17777),
+                ConvTarget::Set(12)
+            ))
+        );
+        assert_eq!(Permissions::from_mode_truncate(0o17777).mode(), 0o7777);
+        assert_eq!(u16::from(Permissions::default()), 0);
+        assert_eq!(
+            format!("{:#o}", Permissions::from_mode_truncate(0o640)),
+            "0o640"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), serde_json::Error> {
+        let permissions = Permissions::from_mode_truncate(0o4755);
+
+        assert_eq!(serde_json::to_string(&permissions)?, "2541");
+        assert_eq!(serde_json::from_str::<Permissions>("2541")?, permissions);
+        // 0o17777, which has bits set above 0o7777.
+        assert!(serde_json::from_str::<Permissions>("8191").is_err());
+        assert!(serde_json::from_str::<Permissions>("65535").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+//! Module containing `proptest` [`Strategy`]s generating [`Bitset`]s.
+//!
+//! All built-in `Bitset`s and [`Index`] also implement [`proptest::arbitrary::Arbitrary`],
+//! so they can be generated with [`proptest::arbitrary::any`].
+//!
+//! Generated `Bitset`s shrink towards [`Bitset::NONE`].
+//!
+//! # Examples
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use bitworks::{prelude::*, proptest_strategies::subset_of};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(flags in subset_of(Bitset8::new(0b00001111)))| {
+//!     prop_assert_eq!(flags & Bitset8::new(0b11110000), Bitset8::NONE);
+//! });
+//! #   Ok(())
+//! # }
+//! ```
+
+use crate::{
+    bitset::{bit_len, Bitset},
+    index::Index,
+    prelude::{
+        Bitset128, Bitset16, Bitset256, Bitset32, Bitset512, Bitset64, Bitset8, BitsetSize, Byteset,
+    },
+};
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::{self, VecStrategy},
+    sample,
+    strategy::{Map, Strategy},
+};
+use std::{fmt::Debug, ops::Range};
+
+// Builds Bitset from `bytes`, least significant first.
+fn from_bytes<T>(bytes: Vec<u8>) -> T
+where
+    T: Bitset,
+{
+    let mut bitset = T::NONE.clone();
+    for (b, byte) in bytes.into_iter().enumerate() {
+        bitset._set_byte(b, byte);
+    }
+    bitset
+}
+
+/// Returns [`Strategy`] generating any value of the `Bitset`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{prelude::*, proptest_strategies::any_bitset};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(bitset in any_bitset::<Byteset<3>>())| {
+///     prop_assert_eq!(bitset.count_ones() + bitset.count_zeros(), 24);
+/// });
+/// #   Ok(())
+/// # }
+/// ```
+pub fn any_bitset<T>() -> impl Strategy<Value = T>
+where
+    T: Bitset + Debug,
+{
+    collection::vec(any::<u8>(), T::BYTE_SIZE).prop_map(from_bytes)
+}
+
+/// Returns [`Strategy`] generating values of the `Bitset` with exactly `k` bits set.
+///
+/// # Panics
+/// Panics, if `k` is greater than the bit size of the `Bitset`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{prelude::*, proptest_strategies::bitset_with_weight};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(bitset in bitset_with_weight::<Bitset64>(5))| {
+///     prop_assert_eq!(bitset.count_ones(), 5);
+/// });
+/// #   Ok(())
+/// # }
+/// ```
+pub fn bitset_with_weight<T>(k: usize) -> impl Strategy<Value = T>
+where
+    T: Bitset + Debug,
+{
+    let len = bit_len::<T>();
+    assert!(k <= len, "k must not exceed the bit size of the Bitset");
+    sample::subsequence((0..len).collect::<Vec<_>>(), k).prop_map(T::from_indices)
+}
+
+/// Returns [`Strategy`] generating subsets of `mask`, from [`Bitset::NONE`] to `mask` itself.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{prelude::*, proptest_strategies::subset_of};
+/// use proptest::prelude::*;
+///
+/// let mask = Bitset16::new(0b1010_0000_0000_0101);
+///
+/// proptest!(|(bitset in subset_of(mask))| {
+///     prop_assert!(bitset.is_subset(&mask));
+/// });
+/// #   Ok(())
+/// # }
+/// ```
+pub fn subset_of<T>(mask: T) -> impl Strategy<Value = T>
+where
+    T: Bitset + Debug,
+{
+    let ones: Vec<_> = mask.ones().map(|i| i.into_inner()).collect();
+    collection::vec(any::<bool>(), ones.len()).prop_map(move |picked| {
+        T::from_indices(
+            ones.iter()
+                .zip(picked)
+                .filter_map(|(&i, picked)| picked.then_some(i)),
+        )
+    })
+}
+
+// Implements Arbitrary, generating the Bitset from arbitrary bytes.
+macro_rules! impl_arbitrary {
+    ($([$($generics:tt)*] $bitset:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> Arbitrary for $bitset {
+                type Parameters = ();
+                type Strategy = Map<VecStrategy<<u8 as Arbitrary>::Strategy>, fn(Vec<u8>) -> Self>;
+
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    collection::vec(any::<u8>(), Self::BYTE_SIZE).prop_map(from_bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary!(
+    [] Bitset8,
+    [] Bitset16,
+    [] Bitset32,
+    [] Bitset64,
+    [] Bitset128,
+    [] Bitset256,
+    [] Bitset512,
+    [] BitsetSize,
+    [const N: usize] Byteset<N>,
+);
+
+impl<T> Arbitrary for Index<T>
+where
+    T: Bitset + Debug + 'static,
+{
+    type Parameters = ();
+    type Strategy = Map<Range<usize>, fn(usize) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (0..bit_len::<T>()).prop_map(Index::from_usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{
+        prop_assert, prop_assert_eq, proptest, strategy::ValueTree, test_runner::TestRunner,
+    };
+
+    proptest! {
+        #[test]
+        fn weight(bitset in bitset_with_weight::<Bitset256>(17)) {
+            prop_assert_eq!(bitset.count_ones(), 17);
+        }
+
+        #[test]
+        fn subsets(bitset in subset_of(Byteset::<3>::new([0b1010, 0, 0x80]))) {
+            prop_assert!(bitset.is_subset(&Byteset::new([0b1010, 0, 0x80])));
+        }
+
+        #[test]
+        fn indices(index in any::<Index<Bitset128>>()) {
+            prop_assert!(index.into_inner() < 128);
+        }
+    }
+
+    #[test]
+    fn shrinks_to_none() {
+        let mut runner = TestRunner::deterministic();
+        let mut tree = any::<Bitset64>().new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current(), Bitset64::NONE);
+
+        let mut tree = subset_of(Bitset64::ALL).new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current(), Bitset64::NONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_weight() {
+        let _ = bitset_with_weight::<Bitset8>(9);
+    }
+}
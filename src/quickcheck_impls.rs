@@ -0,0 +1,119 @@
+//! Module containing `quickcheck::Arbitrary` implementations for built-in [`Bitset`]s and [`Index`].
+//!
+//! `Bitset`s are generated from arbitrary bytes and shrink by clearing their set bits one at a time,
+//! down to [`Bitset::NONE`]. `Index`es shrink towards `0`.
+
+use crate::{
+    bitset::{bit_len, Bitset},
+    index::Index,
+    prelude::{
+        Bitset128, Bitset16, Bitset256, Bitset32, Bitset512, Bitset64, Bitset8, BitsetSize, Byteset,
+    },
+};
+use quickcheck::{Arbitrary, Gen};
+
+// Generates Bitset from arbitrary bytes.
+fn arbitrary_bitset<T>(g: &mut Gen) -> T
+where
+    T: Bitset,
+{
+    let mut bitset = T::NONE.clone();
+    for b in 0..T::BYTE_SIZE {
+        bitset._set_byte(b, u8::arbitrary(g));
+    }
+    bitset
+}
+
+// Returns copies of `bitset`, each with one of its set bits cleared.
+fn shrink_bitset<T>(bitset: &T) -> Box<dyn Iterator<Item = T>>
+where
+    T: Bitset + 'static,
+{
+    let ones: Vec<_> = bitset.ones().collect();
+    let bitset = bitset.clone();
+    Box::new(
+        ones.into_iter()
+            .map(move |i| bitset.clone().unset(i).build()),
+    )
+}
+
+// Implements Arbitrary, delegating to arbitrary_bitset and shrink_bitset.
+macro_rules! impl_arbitrary {
+    ($([$($generics:tt)*] $bitset:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> Arbitrary for $bitset {
+                #[inline(always)]
+                fn arbitrary(g: &mut Gen) -> Self {
+                    arbitrary_bitset(g)
+                }
+
+                #[inline(always)]
+                fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                    shrink_bitset(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary!(
+    [] Bitset8,
+    [] Bitset16,
+    [] Bitset32,
+    [] Bitset64,
+    [] Bitset128,
+    [] Bitset256,
+    [] Bitset512,
+    [] BitsetSize,
+    [const N: usize] Byteset<N>,
+);
+
+impl<T> Arbitrary for Index<T>
+where
+    T: Bitset + 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Index::from_usize(usize::arbitrary(g) % bit_len::<T>())
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.into_inner().shrink().map(Index::from_usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::{quickcheck, TestResult};
+
+    #[test]
+    fn shrink_clears_bits() {
+        let bitset = Bitset8::new(0b10010001);
+        let shrunk: Vec<_> = bitset.shrink().collect();
+        assert_eq!(
+            shrunk,
+            [
+                Bitset8::new(0b10010000),
+                Bitset8::new(0b10000001),
+                Bitset8::new(0b00010001)
+            ]
+        );
+        assert_eq!(Byteset::<3>::NONE.shrink().count(), 0);
+
+        let index = Index::<Bitset8>::from_usize(5);
+        assert!(index.shrink().all(|i| i < index));
+    }
+
+    #[test]
+    fn arbitrary() {
+        let mut g = Gen::new(100);
+        for _ in 0..100 {
+            assert!(Index::<Byteset<3>>::arbitrary(&mut g).into_inner() < 24);
+        }
+
+        fn prop(bitset: Bitset256) -> TestResult {
+            TestResult::from_bool(bitset.count_ones() + bitset.count_zeros() == 256)
+        }
+        quickcheck(prop as fn(Bitset256) -> TestResult);
+    }
+}
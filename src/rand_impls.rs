@@ -0,0 +1,90 @@
+//! Module containing `rand::distributions::Distribution` implementations for built-in [`Bitset`]s.
+//!
+//! [`Standard`] distribution samples every bit of the `Bitset` independently, with equal probability,
+//! same as [`Bitset::random`].
+
+use crate::{
+    bitset::Bitset,
+    prelude::{
+        Bitset128, Bitset16, Bitset256, Bitset32, Bitset512, Bitset64, Bitset8, BitsetSize, Byteset,
+    },
+};
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+// Implements Distribution for Standard, delegating to Bitset::random.
+macro_rules! impl_distribution {
+    ($([$($generics:tt)*] $bitset:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> Distribution<$bitset> for Standard {
+                #[inline(always)]
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $bitset {
+                    <$bitset>::random(rng)
+                }
+            }
+        )*
+    };
+}
+
+impl_distribution!(
+    [] Bitset8,
+    [] Bitset16,
+    [] Bitset32,
+    [] Bitset64,
+    [] Bitset128,
+    [] Bitset256,
+    [] Bitset512,
+    [] BitsetSize,
+    [const N: usize] Byteset<N>,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn standard() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let bitsets: Vec<Bitset512> = (0..64).map(|_| rng.gen()).collect();
+        let ones: usize = bitsets.iter().map(Bitset::count_ones).sum();
+
+        // 32768 fair coin flips stay within a few standard deviations (~90) of the mean.
+        assert!((16384 - 1000..16384 + 1000).contains(&ones));
+        assert_ne!(bitsets[0], bitsets[1]);
+
+        let byteset: Byteset<3> = rng.gen();
+        assert_eq!(byteset.count_ones() + byteset.count_zeros(), 24);
+    }
+
+    #[test]
+    fn count_ones() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut hits = [0usize; 8];
+        for _ in 0..800 {
+            let bitset = Bitset8::random_with_count_ones(&mut rng, 2);
+            assert_eq!(bitset.count_ones(), 2);
+            bitset.ones().for_each(|i| hits[i.into_inner()] += 1);
+        }
+        // Every bit is expected to be set in 200 of 800 samples.
+        assert!(hits.iter().all(|&h| (100..300).contains(&h)));
+
+        assert_eq!(
+            Byteset::<3>::random_with_count_ones(&mut rng, 24),
+            Byteset::ALL
+        );
+        assert_eq!(
+            Bitset256::random_with_count_ones(&mut rng, 0),
+            Bitset256::NONE
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_count_ones() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let _ = Bitset8::random_with_count_ones(&mut rng, 9);
+    }
+}
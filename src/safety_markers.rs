@@ -392,3 +392,36 @@ impl Splits<Size<6>, Size<10>> for Size<16> {}
 impl Splits<Size<10>, Size<6>> for Size<16> {}
 impl Splits<Size<7>, Size<9>> for Size<16> {}
 impl Splits<Size<9>, Size<7>> for Size<16> {}
+
+// Multiword sizes, combining with and splitting into the fixed size Bitsets.
+impl Smaller<Size<24>> for Size<1> {}
+impl Smaller<Size<24>> for Size<2> {}
+impl Smaller<Size<24>> for Size<4> {}
+impl Smaller<Size<24>> for Size<8> {}
+impl Smaller<Size<24>> for Size<16> {}
+impl Smaller<Size<32>> for Size<24> {}
+impl Smaller<Size<40>> for Size<1> {}
+impl Smaller<Size<40>> for Size<2> {}
+impl Smaller<Size<40>> for Size<4> {}
+impl Smaller<Size<40>> for Size<8> {}
+impl Smaller<Size<40>> for Size<16> {}
+impl Smaller<Size<40>> for Size<24> {}
+impl Smaller<Size<40>> for Size<32> {}
+impl Smaller<Size<64>> for Size<24> {}
+impl Smaller<Size<64>> for Size<40> {}
+impl Smaller<Size<128>> for Size<24> {}
+impl Smaller<Size<128>> for Size<40> {}
+
+// 24 splits into
+impl Splits<Size<8>, Size<16>> for Size<24> {}
+impl Splits<Size<16>, Size<8>> for Size<24> {}
+
+// 32 splits into
+impl Splits<Size<8>, Size<24>> for Size<32> {}
+impl Splits<Size<24>, Size<8>> for Size<32> {}
+
+// 40 splits into
+impl Splits<Size<8>, Size<32>> for Size<40> {}
+impl Splits<Size<32>, Size<8>> for Size<40> {}
+impl Splits<Size<16>, Size<24>> for Size<40> {}
+impl Splits<Size<24>, Size<16>> for Size<40> {}
@@ -0,0 +1,670 @@
+//! Module containing alternative `serde` representations of [`Bitset`]s,
+//! usable with `#[serde(with = "...")]` field attribute.
+//!
+//! * [`hex`] — hexadecimal string (`"f00f"`), most significant digit first.
+//! * [`binary`] — binary string (`"1111000000001111"`), most significant bit first.
+//! * [`indices`] — list of indices of set bits (`[0, 1, 2, 3, 12, 13, 14, 15]`).
+//! * [`raw`] — unsigned integer of the smallest fitting width,
+//!   or list of 64 bit words, least significant first, for `Bitset`s longer than 128 bits.
+//!
+//! In formats, which are not human-readable, [`hex`] and [`binary`] serialize the `Bitset` as bytes,
+//! least significant first.
+//!
+//! With feature `"schemars"`, each module also provides `json_schema`, describing the representation,
+//! for use with `#[schemars(schema_with = "...")]` field attribute.
+//!
+//! # Examples
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use bitworks::{prelude::*, serde_formats};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "serde_formats::hex")]
+//!     mask: Bitset32,
+//!     #[serde(with = "serde_formats::indices")]
+//!     enabled: Bitset256,
+//! }
+//! #   Ok(())
+//! # }
+//! ```
+
+use crate::{
+    bitset::{bit_len, Bitset},
+    error::{ConvError, ConvTarget},
+    index::Index,
+    parse::parse_radix,
+};
+use serde::de::{Error, SeqAccess, Visitor};
+use std::marker::PhantomData;
+
+// Formats bits of `bitset` as digits of `width` bits each, most significant digit first.
+fn to_radix_string<T>(bitset: &T, width: usize) -> String
+where
+    T: Bitset,
+{
+    let len = bit_len::<T>();
+    (0..len.div_ceil(width))
+        .rev()
+        .map(|d| {
+            let digit = bitset._bits(d * width, width.min(len - d * width));
+            char::from_digit(digit as u32, 1 << width).expect("digit should be in radix")
+        })
+        .collect()
+}
+
+// Returns bytes of `bitset`, least significant first.
+fn to_bytes<T>(bitset: &T) -> Vec<u8>
+where
+    T: Bitset,
+{
+    (0..T::BYTE_SIZE).map(|b| bitset._byte(b)).collect()
+}
+
+// Error of a value, which requires `required` bits, not fitting into the Bitset.
+fn overflow<T, E>(required: usize) -> E
+where
+    T: Bitset,
+    E: Error,
+{
+    E::custom(ConvError::new(
+        ConvTarget::Set(required),
+        ConvTarget::Set(bit_len::<T>()),
+    ))
+}
+
+// Visitor of digits of `radix`, optionally prefixed with one of `prefixes`.
+struct StrVisitor<T> {
+    radix: u32,
+    prefixes: [&'static str; 2],
+    marker: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for StrVisitor<T>
+where
+    T: Bitset,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a string of base {} digits", self.radix)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let offset = match v.get(..2) {
+            Some(prefix) if self.prefixes.contains(&prefix) => 2,
+            _ => 0,
+        };
+        parse_radix(&v[offset..], self.radix, offset).map_err(E::custom)
+    }
+}
+
+// Visitor of bytes, least significant first.
+struct BytesVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for BytesVisitor<T>
+where
+    T: Bitset,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "an array of {} bytes", T::BYTE_SIZE)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v.len() != T::BYTE_SIZE {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+        let mut bitset = T::NONE.clone();
+        for (b, &byte) in v.iter().enumerate() {
+            bitset._set_byte(b, byte);
+        }
+        Ok(bitset)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Bytes are written directly into the Bitset, so a longer sequence fails on its first extra element.
+        let mut bitset = T::NONE.clone();
+        let mut len = 0;
+        while let Some(byte) = seq.next_element::<u8>()? {
+            if len == T::BYTE_SIZE {
+                return Err(A::Error::invalid_length(len + 1, &self));
+            }
+            bitset._set_byte(len, byte);
+            len += 1;
+        }
+        if len != T::BYTE_SIZE {
+            return Err(A::Error::invalid_length(len, &self));
+        }
+        Ok(bitset)
+    }
+}
+
+// Visitor of indices of set bits.
+struct IndicesVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for IndicesVisitor<T>
+where
+    T: Bitset,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a list of bit indices")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bitset = T::NONE.clone();
+        while let Some(i) = seq.next_element::<usize>()? {
+            bitset.set(Index::<T>::try_from(i).map_err(A::Error::custom)?);
+        }
+        Ok(bitset)
+    }
+}
+
+// Visitor of an unsigned integer, or of 64 bit words, least significant first.
+struct RawVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for RawVisitor<T>
+where
+    T: Bitset,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an unsigned integer or a list of 64 bit words")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_u128(v as u128)
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let len = bit_len::<T>();
+        let required = (128 - v.leading_zeros()) as usize;
+        if required > len {
+            return Err(overflow::<T, E>(required));
+        }
+        let mut bitset = T::NONE.clone();
+        bitset._set_bits(0, len.min(64), v as u64);
+        if len > 64 {
+            bitset._set_bits(64, (len - 64).min(64), (v >> 64) as u64);
+        }
+        Ok(bitset)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let len = bit_len::<T>();
+        let mut bitset = T::NONE.clone();
+        let mut offset = 0;
+        while let Some(word) = seq.next_element::<u64>()? {
+            let required = offset + (64 - word.leading_zeros()) as usize;
+            if word != 0 && required > len {
+                return Err(overflow::<T, A::Error>(required));
+            }
+            if offset < len {
+                bitset._set_bits(offset, (len - offset).min(64), word);
+            }
+            offset += 64;
+        }
+        Ok(bitset)
+    }
+}
+
+/// Hexadecimal string representation, most significant digit first.
+///
+/// Serializes all digits, including leading zeros.
+/// Deserializes digits with optional `0x` prefix, separated with optional `_`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{prelude::Bitset16, serde_formats};
+/// use serde::{Deserialize, Serialize};
+///
+/// // Serialized as `{"mask": "f00f"}` in JSON.
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "serde_formats::hex")]
+///     mask: Bitset16,
+/// }
+/// #   Ok(())
+/// # }
+/// ```
+pub mod hex {
+    use super::*;
+
+    /// Serializes `bitset` as hexadecimal string, or as bytes in formats, which are not human-readable.
+    pub fn serialize<T, S>(bitset: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Bitset,
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&to_radix_string(bitset, 4))
+        } else {
+            serializer.serialize_bytes(&to_bytes(bitset))
+        }
+    }
+
+    /// Deserializes `Bitset` from hexadecimal string, or from bytes in formats, which are not human-readable.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Bitset,
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrVisitor {
+                radix: 16,
+                prefixes: ["0x", "0X"],
+                marker: PhantomData,
+            })
+        } else {
+            deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+        }
+    }
+
+    /// Returns JSON schema of the hexadecimal string, for use with `#[schemars(schema_with = "...")]`.
+    ///
+    /// Requires feature `"schemars"`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema<T>(_: &mut schemars::SchemaGenerator) -> schemars::Schema
+    where
+        T: Bitset,
+    {
+        schemars::json_schema!({
+            "description": format!("{} bit Bitset, as hexadecimal digits", bit_len::<T>()),
+            "type": "string",
+            "pattern": "^(0[xX])?_*[0-9a-fA-F][0-9a-fA-F_]*$",
+        })
+    }
+}
+
+/// Binary string representation, most significant bit first.
+///
+/// Serializes all bits, same as `Display` of fixed size `Bitset`s.
+/// Deserializes digits with optional `0b` prefix, separated with optional `_`.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{prelude::Bitset8, serde_formats};
+/// use serde::{Deserialize, Serialize};
+///
+/// // Serialized as `{"flags": "00001111"}` in JSON.
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "serde_formats::binary")]
+///     flags: Bitset8,
+/// }
+/// #   Ok(())
+/// # }
+/// ```
+pub mod binary {
+    use super::*;
+
+    /// Serializes `bitset` as binary string, or as bytes in formats, which are not human-readable.
+    pub fn serialize<T, S>(bitset: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Bitset,
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&to_radix_string(bitset, 1))
+        } else {
+            serializer.serialize_bytes(&to_bytes(bitset))
+        }
+    }
+
+    /// Deserializes `Bitset` from binary string, or from bytes in formats, which are not human-readable.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Bitset,
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrVisitor {
+                radix: 2,
+                prefixes: ["0b", "0B"],
+                marker: PhantomData,
+            })
+        } else {
+            deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+        }
+    }
+
+    /// Returns JSON schema of the binary string, for use with `#[schemars(schema_with = "...")]`.
+    ///
+    /// Requires feature `"schemars"`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema<T>(_: &mut schemars::SchemaGenerator) -> schemars::Schema
+    where
+        T: Bitset,
+    {
+        schemars::json_schema!({
+            "description": format!("{} bit Bitset, as binary digits", bit_len::<T>()),
+            "type": "string",
+            "pattern": "^(0[bB])?_*[01][01_]*$",
+        })
+    }
+}
+
+/// Representation as a list of [`Index`]es of set bits, in ascending order.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{prelude::Bitset512, serde_formats};
+/// use serde::{Deserialize, Serialize};
+///
+/// // Serialized as `{"channels": [1, 300]}` in JSON.
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "serde_formats::indices")]
+///     channels: Bitset512,
+/// }
+/// #   Ok(())
+/// # }
+/// ```
+pub mod indices {
+    use super::*;
+
+    /// Serializes `bitset` as a list of indices of set bits.
+    pub fn serialize<T, S>(bitset: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Bitset,
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(bitset.ones().map(|i| i.into_inner()))
+    }
+
+    /// Deserializes `Bitset` from a list of indices of set bits, in any order.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Bitset,
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(IndicesVisitor(PhantomData))
+    }
+
+    /// Returns JSON schema of the list of indices, for use with `#[schemars(schema_with = "...")]`.
+    ///
+    /// Requires feature `"schemars"`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema<T>(_: &mut schemars::SchemaGenerator) -> schemars::Schema
+    where
+        T: Bitset,
+    {
+        schemars::json_schema!({
+            "description": format!("{} bit Bitset, as indices of set bits", bit_len::<T>()),
+            "type": "array",
+            "items": {
+                "type": "integer",
+                "minimum": 0,
+                "maximum": bit_len::<T>() - 1,
+            },
+        })
+    }
+}
+
+/// Raw unsigned integer representation.
+///
+/// `Bitset`s up to 128 bits long are serialized as the smallest fitting unsigned integer,
+/// longer ones as a list of 64 bit words, least significant first.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{prelude::Byteset, serde_formats};
+/// use serde::{Deserialize, Serialize};
+///
+/// // Serialized as `{"mode": 420}` in JSON.
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "serde_formats::raw")]
+///     mode: Byteset<2>,
+/// }
+/// #   Ok(())
+/// # }
+/// ```
+pub mod raw {
+    use super::*;
+
+    /// Serializes `bitset` as unsigned integer, or as a list of 64 bit words.
+    pub fn serialize<T, S>(bitset: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Bitset,
+        S: serde::Serializer,
+    {
+        match bit_len::<T>() {
+            len @ 0..=8 => serializer.serialize_u8(bitset._bits(0, len) as u8),
+            len @ 9..=16 => serializer.serialize_u16(bitset._bits(0, len) as u16),
+            len @ 17..=32 => serializer.serialize_u32(bitset._bits(0, len) as u32),
+            len @ 33..=64 => serializer.serialize_u64(bitset._bits(0, len)),
+            len @ 65..=128 => serializer.serialize_u128(
+                bitset._word(0) as u128 | (bitset._bits(64, len - 64) as u128) << 64,
+            ),
+            len => serializer.collect_seq((0..len.div_ceil(64)).map(|w| bitset._word(w))),
+        }
+    }
+
+    /// Deserializes `Bitset` from unsigned integer, or from a list of 64 bit words.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Bitset,
+        D: serde::Deserializer<'de>,
+    {
+        let visitor = RawVisitor(PhantomData);
+        match bit_len::<T>() {
+            0..=8 => deserializer.deserialize_u8(visitor),
+            9..=16 => deserializer.deserialize_u16(visitor),
+            17..=32 => deserializer.deserialize_u32(visitor),
+            33..=64 => deserializer.deserialize_u64(visitor),
+            65..=128 => deserializer.deserialize_u128(visitor),
+            _ => deserializer.deserialize_seq(visitor),
+        }
+    }
+
+    /// Returns JSON schema of the unsigned integer or of the list of 64 bit words,
+    /// for use with `#[schemars(schema_with = "...")]`.
+    ///
+    /// Requires feature `"schemars"`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema<T>(generator: &mut schemars::SchemaGenerator) -> schemars::Schema
+    where
+        T: Bitset,
+    {
+        match bit_len::<T>() {
+            len @ 0..=64 => schemars::json_schema!({
+                "type": "integer",
+                "minimum": 0,
+                "maximum": u64::MAX.checked_shr(64 - len as u32).unwrap_or(0),
+            }),
+            65..=128 => schemars::json_schema!({
+                "type": "integer",
+                "minimum": 0,
+            }),
+            len => schemars::json_schema!({
+                "type": "array",
+                "items": generator.subschema_for::<u64>(),
+                "maxItems": len.div_ceil(64),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset16, Bitset256, Bitset8, Byteset};
+    use serde::de::{
+        value::{Error as ValueError, MapDeserializer, SeqDeserializer},
+        IntoDeserializer,
+    };
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    fn str_de(s: &str) -> impl serde::Deserializer<'_, Error = ValueError> {
+        s.into_deserializer()
+    }
+
+    #[test]
+    fn strings() -> TestResult {
+        let bitset = Bitset16::new(0xF00F);
+        assert_eq!(to_radix_string(&bitset, 4), "f00f");
+        assert_eq!(to_radix_string(&bitset, 1), bitset.to_string());
+        assert_eq!(
+            to_radix_string(&Byteset::<3>::new([0x01, 0x02, 0xA3]), 4),
+            "a30201"
+        );
+
+        assert_eq!(hex::deserialize::<Bitset16, _>(str_de("f00f"))?, bitset);
+        assert_eq!(hex::deserialize::<Bitset16, _>(str_de("0xF0_0F"))?, bitset);
+        assert_eq!(
+            binary::deserialize::<Bitset16, _>(str_de("0b1111000000001111"))?,
+            bitset
+        );
+        assert!(hex::deserialize::<Bitset8, _>(str_de("1ff")).is_err());
+        assert!(binary::deserialize::<Bitset8, _>(str_de("0x1")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn bytes() -> TestResult {
+        let bitset = Byteset::<3>::new([0x01, 0x02, 0xA3]);
+        assert_eq!(to_bytes(&bitset), [0x01, 0x02, 0xA3]);
+        assert_eq!(to_bytes(&Bitset16::new(0x0102)), [0x02, 0x01]);
+
+        let visitor = BytesVisitor::<Bitset16>(PhantomData);
+        assert_eq!(
+            visitor.visit_bytes::<ValueError>(&[0x02, 0x01])?,
+            Bitset16::new(0x0102)
+        );
+        let visitor = BytesVisitor::<Bitset16>(PhantomData);
+        assert!(visitor.visit_bytes::<ValueError>(&[0x02]).is_err());
+
+        let de = SeqDeserializer::<_, ValueError>::new([0x02u8, 0x01].into_iter());
+        let visitor = BytesVisitor::<Bitset16>(PhantomData);
+        assert_eq!(visitor.visit_seq(de)?, Bitset16::new(0x0102));
+
+        let de = SeqDeserializer::<_, ValueError>::new([0x02u8].into_iter());
+        let visitor = BytesVisitor::<Bitset16>(PhantomData);
+        assert!(visitor.visit_seq(de).is_err());
+
+        // Endless sequence is rejected on its third element.
+        let de = SeqDeserializer::<_, ValueError>::new(std::iter::repeat(0u8));
+        let visitor = BytesVisitor::<Bitset16>(PhantomData);
+        let error = visitor.visit_seq(de).unwrap_err();
+        assert!(error.to_string().contains("invalid length 3"));
+        Ok(())
+    }
+
+    #[test]
+    fn indices() -> TestResult {
+        let de = SeqDeserializer::<_, ValueError>::new([255usize, 0, 3].into_iter());
+        let bitset: Bitset256 = indices::deserialize(de)?;
+        assert_eq!(bitset, Bitset256::new([0b1001, 1 << 127]));
+
+        let de = SeqDeserializer::<_, ValueError>::new([8usize].into_iter());
+        assert!(indices::deserialize::<Bitset8, _>(de).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn raw() -> TestResult {
+        let de = IntoDeserializer::<ValueError>::into_deserializer(0x0102u16);
+        assert_eq!(raw::deserialize::<Bitset16, _>(de)?, Bitset16::new(0x0102));
+
+        let de = IntoDeserializer::<ValueError>::into_deserializer(0x01_0000u32);
+        assert!(raw::deserialize::<Byteset<2>, _>(de).is_err());
+
+        let de = SeqDeserializer::<_, ValueError>::new([1u64, 0, 0, 1 << 63].into_iter());
+        assert_eq!(
+            raw::deserialize::<Bitset256, _>(de)?,
+            Bitset256::new([1, 1 << 127])
+        );
+        let de = SeqDeserializer::<_, ValueError>::new([0u64, 0, 0, 0, 1].into_iter());
+        assert!(raw::deserialize::<Bitset256, _>(de).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn with_attribute() -> TestResult {
+        #[derive(Debug, serde::Deserialize)]
+        struct Config {
+            #[serde(with = "hex")]
+            mask: Bitset16,
+            #[serde(with = "binary")]
+            flags: Bitset8,
+        }
+
+        let de = MapDeserializer::<_, ValueError>::new(
+            [("mask", "ff00"), ("flags", "0b101")].into_iter(),
+        );
+        let config: Config = serde::Deserialize::deserialize(de)?;
+        assert_eq!(config.mask, Bitset16::new(0xFF00));
+        assert_eq!(config.flags, Bitset8::new(0b101));
+        Ok(())
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schemas() {
+        #[allow(dead_code)]
+        #[derive(schemars::JsonSchema)]
+        struct Config {
+            #[schemars(schema_with = "hex::json_schema::<Bitset16>")]
+            mask: Bitset16,
+            #[schemars(schema_with = "indices::json_schema::<Bitset256>")]
+            channels: Bitset256,
+            #[schemars(schema_with = "raw::json_schema::<Byteset<3>>")]
+            mode: Byteset<3>,
+            #[schemars(schema_with = "raw::json_schema::<Bitset256>")]
+            words: Bitset256,
+        }
+
+        let schema = schemars::schema_for!(Config);
+        let properties = schema.get("properties").unwrap();
+        assert_eq!(properties["mask"]["type"], "string");
+        assert_eq!(
+            properties["mask"]["pattern"],
+            "^(0[xX])?_*[0-9a-fA-F][0-9a-fA-F_]*$"
+        );
+        assert_eq!(properties["channels"]["items"]["maximum"], 255);
+        assert_eq!(properties["mode"]["maximum"], 0xFF_FFFF);
+        assert_eq!(properties["words"]["maxItems"], 4);
+    }
+}
@@ -0,0 +1,728 @@
+//! Module containing [`BitSliceRef`] and [`BitSliceMut`], borrowed views over a range of [`Bitset`] bits.
+//!
+//! Positions inside of the views are relative to their start, so bits `8..16` of the `Bitset`
+//! are bits `0..8` of the view.
+//!
+//! Views are returned by [`Bitset::slice`] and [`Bitset::slice_mut`], rather than by [`std::ops::Index`]
+//! with ranges, since `Index` can only return references to values, stored inside of the `Bitset`.
+
+use crate::{
+    bit::Bit,
+    bitset::{clamp_range, clamp_range_to, Bitset},
+    iter::{count_in, Ones, Zeros},
+};
+use std::{
+    fmt::{Debug, Display},
+    ops::{BitAndAssign, BitOrAssign, BitXorAssign, RangeBounds},
+};
+
+/// Borrowed view over a range of bits of the [`Bitset`].
+///
+/// Returned by [`Bitset::slice`].
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{prelude::*, slice::BitSliceRef};
+///
+/// fn low_nibble_ones(slice: BitSliceRef<'_, impl Bitset>) -> usize {
+///     slice.slice(..4).count_ones()
+/// }
+///
+/// let register = Bitset16::new(0b0011_0111_0000_0001);
+///
+/// assert_eq!(low_nibble_ones(register.slice(8..16)), 3);
+/// assert_eq!(register.slice(8..16).to_string(), "00110111");
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct BitSliceRef<'a, T: Bitset> {
+    bitset: &'a T,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> BitSliceRef<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `BitSliceRef` over bits of `bitset` in `range`.
+    ///
+    /// `range` is clamped to the size of the `Bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{prelude::*, slice::BitSliceRef};
+    ///
+    /// let bitset = Bitset8::new(0b11110000);
+    ///
+    /// assert_eq!(BitSliceRef::new(&bitset, 2..6).to_string(), "1100");
+    /// assert_eq!(BitSliceRef::new(&bitset, 6..).len(), 2);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(bitset: &'a T, range: impl RangeBounds<usize>) -> Self {
+        let range = clamp_range::<T>(range);
+        Self {
+            bitset,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Returns the range of bits of the `Bitset`, the `BitSliceRef` views.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset16::NONE;
+    ///
+    /// assert_eq!(bitset.slice(4..12).slice(2..).range(), 6..12);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the number of bits in the `BitSliceRef`.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns [`true`], if the `BitSliceRef` has no bits, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the bit at `index`, relative to the start of the `BitSliceRef`.
+    ///
+    /// # Panics
+    /// Panics, if `index` is out of range of the `BitSliceRef`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b00010000);
+    ///
+    /// assert_eq!(bitset.slice(4..).bit(0), One);
+    /// assert_eq!(bitset.slice(4..).bit(1), Zero);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn bit(&self, index: usize) -> Bit {
+        self.get(index)
+            .expect("index was out of range of the BitSliceRef")
+    }
+
+    /// Returns [`Some`] bit at `index`, relative to the start of the `BitSliceRef`,
+    /// or [`None`], if `index` is out of range.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b00010000);
+    ///
+    /// assert_eq!(bitset.slice(4..6).get(0), Some(One));
+    /// assert_eq!(bitset.slice(4..6).get(2), None);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<Bit> {
+        match index < self.len() {
+            true => Some(Bit::from(self.bitset._bits(self.start + index, 1) == 1)),
+            false => None,
+        }
+    }
+
+    /// Returns a narrower `BitSliceRef` over `range`, relative to the start of `self`.
+    ///
+    /// `range` is clamped to the size of `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset16::new(0b0000_1010_0000_0000);
+    /// let byte = bitset.slice(8..);
+    ///
+    /// assert_eq!(byte.slice(..4).to_string(), "1010");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let range = clamp_range_to(range, self.len());
+        Self {
+            bitset: self.bitset,
+            start: self.start + range.start,
+            end: self.start + range.end,
+        }
+    }
+
+    /// Returns iterator over bits of the `BitSliceRef`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset8::new(0b00000110);
+    /// let bits: Vec<Bit> = bitset.slice(1..4).bits().collect();
+    ///
+    /// assert_eq!(bits, [One, One, Zero]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn bits(&self) -> impl DoubleEndedIterator<Item = Bit> + ExactSizeIterator + 'a {
+        let bitset = self.bitset;
+        (self.start..self.end).map(|i| Bit::from(bitset._bits(i, 1) == 1))
+    }
+
+    /// Returns iterator over positions of the set bits, relative to the start of the `BitSliceRef`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset16::new(0b0000_0101_0000_0001);
+    /// let ones: Vec<usize> = bitset.slice(8..).ones().collect();
+    ///
+    /// assert_eq!(ones, [0, 2]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn ones(&self) -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator + 'a {
+        let start = self.start;
+        Ones::in_range(self.bitset, self.range()).map(move |i| usize::from(i) - start)
+    }
+
+    /// Returns iterator over positions of the not set bits, relative to the start of the `BitSliceRef`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset16::new(0b0000_0101_0000_0001);
+    /// let zeros: Vec<usize> = bitset.slice(8..12).zeros().collect();
+    ///
+    /// assert_eq!(zeros, [1, 3]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn zeros(&self) -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator + 'a {
+        let start = self.start;
+        Zeros::in_range(self.bitset, self.range()).map(move |i| usize::from(i) - start)
+    }
+
+    /// Returns the number of set bits in the `BitSliceRef`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let bitset = Bitset16::new(0b0111_0000_0000_0011);
+    ///
+    /// assert_eq!(bitset.slice(8..).count_ones(), 3);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn count_ones(&self) -> usize {
+        count_in(self.start, self.end, |w| self.bitset._word(w))
+    }
+
+    /// Returns the number of not set bits in the `BitSliceRef`.
+    #[inline(always)]
+    pub fn count_zeros(&self) -> usize {
+        self.len() - self.count_ones()
+    }
+
+    // Returns `w`-th word of the view, with bits past its end not set.
+    #[inline(always)]
+    fn word(&self, w: usize) -> u64 {
+        let offset = self.start + w * 64;
+        self.bitset._bits(offset, (self.end - offset).min(64))
+    }
+}
+
+impl<'a, 'b, T, U> PartialEq<BitSliceRef<'b, U>> for BitSliceRef<'a, T>
+where
+    T: Bitset,
+    U: Bitset,
+{
+    fn eq(&self, other: &BitSliceRef<'b, U>) -> bool {
+        self.len() == other.len()
+            && (0..self.len().div_ceil(64)).all(|w| self.word(w) == other.word(w))
+    }
+}
+
+impl<'a, T> Eq for BitSliceRef<'a, T> where T: Bitset {}
+
+impl<'a, T> Debug for BitSliceRef<'a, T>
+where
+    T: Bitset,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BitSliceRef({:?}, 0b{self})", self.range())
+    }
+}
+
+impl<'a, T> Display for BitSliceRef<'a, T>
+where
+    T: Bitset,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.bits()
+            .rev()
+            .try_for_each(|bit| write!(f, "{}", u8::from(bool::from(bit))))
+    }
+}
+
+/// Mutable borrowed view over a range of bits of the [`Bitset`].
+///
+/// All modifications are confined to the range of the view.
+///
+/// Returned by [`Bitset::slice_mut`].
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::prelude::*;
+///
+/// let header = Bitset8::new(0b1010);
+/// let mut packet = Byteset::<4>::NONE;
+///
+/// packet.slice_mut(8..16).copy_from(header.slice(..));
+/// packet.slice_mut(24..).fill(One);
+///
+/// assert_eq!(packet.into_inner(), [0, 0b1010, 0, 0xFF]);
+/// #   Ok(())
+/// # }
+/// ```
+pub struct BitSliceMut<'a, T: Bitset> {
+    bitset: &'a mut T,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> BitSliceMut<'a, T>
+where
+    T: Bitset,
+{
+    /// Constructs a new value of `BitSliceMut` over bits of `bitset` in `range`.
+    ///
+    /// `range` is clamped to the size of the `Bitset`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::{prelude::*, slice::BitSliceMut};
+    ///
+    /// let mut bitset = Bitset8::NONE;
+    /// BitSliceMut::new(&mut bitset, 2..6).fill(One);
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b00111100);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(bitset: &'a mut T, range: impl RangeBounds<usize>) -> Self {
+        let range = clamp_range::<T>(range);
+        Self {
+            bitset,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Returns the range of bits of the `Bitset`, the `BitSliceMut` views.
+    #[inline(always)]
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the number of bits in the `BitSliceMut`.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns [`true`], if the `BitSliceMut` has no bits, and [`false`] otherwise.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns read-only [`BitSliceRef`] view over the same bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let mut bitset = Bitset16::NONE;
+    /// let mut slice = bitset.slice_mut(4..12);
+    /// slice.set_bit(0, One).set_bit(7, One);
+    ///
+    /// assert_eq!(slice.as_slice().to_string(), "10000001");
+    /// assert_eq!(slice.as_slice().count_ones(), 2);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn as_slice(&self) -> BitSliceRef<'_, T> {
+        BitSliceRef {
+            bitset: self.bitset,
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    /// Returns a narrower `BitSliceMut` over `range`, relative to the start of `self`.
+    ///
+    /// `range` is clamped to the size of `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let mut bitset = Bitset16::NONE;
+    /// let mut high = bitset.slice_mut(8..);
+    /// high.slice_mut(..4).fill(One);
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b0000_1111_0000_0000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> BitSliceMut<'_, T> {
+        let range = clamp_range_to(range, self.len());
+        BitSliceMut {
+            bitset: self.bitset,
+            start: self.start + range.start,
+            end: self.start + range.end,
+        }
+    }
+
+    /// Returns the bit at `index`, relative to the start of the `BitSliceMut`.
+    ///
+    /// # Panics
+    /// Panics, if `index` is out of range of the `BitSliceMut`.
+    #[inline(always)]
+    pub fn bit(&self, index: usize) -> Bit {
+        self.as_slice().bit(index)
+    }
+
+    /// Replaces the bit at `index`, relative to the start of the `BitSliceMut`, with `value`.
+    /// Returns a mutable reference to `self`.
+    ///
+    /// # Panics
+    /// Panics, if `index` is out of range of the `BitSliceMut`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let mut bitset = Bitset8::NONE;
+    /// bitset.slice_mut(4..).set_bit(1, One).set_bit(3, One);
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b10100000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn set_bit(&mut self, index: usize, value: Bit) -> &mut Self {
+        assert!(
+            index < self.len(),
+            "index was out of range of the BitSliceMut"
+        );
+        self.bitset
+            ._set_bits(self.start + index, 1, bool::from(value) as u64);
+        self
+    }
+
+    /// Sets all bits of the `BitSliceMut` to `value`. Returns a mutable reference to `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let mut bitset = Bitset8::ALL;
+    /// bitset.slice_mut(..3).fill(Zero);
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b11111000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn fill(&mut self, value: Bit) -> &mut Self {
+        let bits = match value {
+            Bit::One => !0,
+            Bit::Zero => 0,
+        };
+        self.apply(|_, _| bits)
+    }
+
+    /// Copies bits of `other` into the `BitSliceMut`. Returns a mutable reference to `self`.
+    ///
+    /// # Panics
+    /// Panics, if `other` has different length.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::prelude::*;
+    ///
+    /// let source = Bitset16::new(0b0000_0110_0000_0000);
+    /// let mut bitset = Bitset8::NONE;
+    /// bitset.slice_mut(2..6).copy_from(source.slice(8..12));
+    ///
+    /// assert_eq!(bitset.into_inner(), 0b00011000);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn copy_from<U>(&mut self, other: BitSliceRef<'_, U>) -> &mut Self
+    where
+        U: Bitset,
+    {
+        self.assert_same_len(&other);
+        self.apply(|_, w| other.word(w))
+    }
+
+    // Panics, if `other` has different length.
+    #[inline(always)]
+    fn assert_same_len<U>(&self, other: &BitSliceRef<'_, U>)
+    where
+        U: Bitset,
+    {
+        assert_eq!(self.len(), other.len(), "slices must have the same length");
+    }
+
+    // Replaces each word of the view with `f(word, w)`, up to 64 bits at a time.
+    fn apply(&mut self, mut f: impl FnMut(u64, usize) -> u64) -> &mut Self {
+        let mut w = 0;
+        let mut offset = self.start;
+        while offset < self.end {
+            let width = (self.end - offset).min(64);
+            let word = f(self.bitset._bits(offset, width), w);
+            let word = match width {
+                64 => word,
+                _ => word & !(!0 << width),
+            };
+            self.bitset._set_bits(offset, width, word);
+            offset += width;
+            w += 1;
+        }
+        self
+    }
+}
+
+// Implements bitwise assign operator between BitSliceMut and BitSliceRef, using `$op` on words.
+macro_rules! impl_slice_assign {
+    ($($trait:ident $method:ident $op:tt),* $(,)?) => {
+        $(
+            impl<'a, 'b, T, U> $trait<BitSliceRef<'b, U>> for BitSliceMut<'a, T>
+            where
+                T: Bitset,
+                U: Bitset,
+            {
+                /// # Panics
+                /// Panics, if `rhs` has different length.
+                fn $method(&mut self, rhs: BitSliceRef<'b, U>) {
+                    self.assert_same_len(&rhs);
+                    self.apply(|word, w| word $op rhs.word(w));
+                }
+            }
+        )*
+    };
+}
+
+impl_slice_assign!(
+    BitAndAssign bitand_assign &,
+    BitOrAssign bitor_assign |,
+    BitXorAssign bitxor_assign ^,
+);
+
+impl<'a, T> Debug for BitSliceMut<'a, T>
+where
+    T: Bitset,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BitSliceMut({:?}, 0b{})", self.range(), self.as_slice())
+    }
+}
+
+impl<'a, T> Display for BitSliceMut<'a, T>
+where
+    T: Bitset,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.as_slice(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset128, Bitset16, Bitset64, Bitset8, Byteset};
+
+    #[test]
+    fn views() {
+        let bitset = Bitset128::new(0xFF << 60 | 0b1011);
+
+        let slice = bitset.slice(56..72);
+        assert_eq!(slice.len(), 16);
+        assert_eq!(slice.count_ones(), 8);
+        assert_eq!(slice.count_zeros(), 8);
+        assert_eq!(slice.to_string(), "0000111111110000");
+        assert_eq!(
+            slice.ones().collect::<Vec<_>>(),
+            (4..12).collect::<Vec<_>>()
+        );
+        assert_eq!(slice.zeros().next_back(), Some(15));
+        assert_eq!(slice.bit(4), Bit::One);
+        assert_eq!(slice.get(16), None);
+
+        let slice = bitset.slice(..=3);
+        assert_eq!(slice.to_string(), "1011");
+        assert_eq!(format!("{slice:?}"), "BitSliceRef(0..4, 0b1011)");
+
+        let empty = bitset.slice(128..);
+        assert!(empty.is_empty());
+        assert_eq!(empty.to_string(), "");
+        assert_eq!(bitset.slice(120..200).len(), 8);
+    }
+
+    #[test]
+    fn comparison() {
+        let a = Bitset128::new(0xABCD_EF01_2345_6789 << 30);
+        let b = Bitset64::new(0xABCD_EF01_2345_6789);
+        let c = Byteset::<3>::new([0, 0x89, 0x67]);
+
+        assert_eq!(a.slice(30..94), b.slice(..));
+        assert_eq!(a.slice(40..94), b.slice(10..));
+        assert_eq!(c.slice(8..), b.slice(..16));
+        assert_eq!(c.slice(8..).slice(4..12), a.slice(34..42));
+        assert_ne!(a.slice(30..94), b.slice(..63));
+        assert_ne!(a.slice(31..95), b.slice(..));
+        assert_eq!(Bitset8::NONE.slice(2..2), Bitset16::ALL.slice(9..9));
+    }
+
+    #[test]
+    fn mutable_views() {
+        let mut bitset = Byteset::<12>::NONE;
+
+        bitset.slice_mut(4..92).fill(Bit::One);
+        assert_eq!(bitset.count_ones(), 88);
+        assert_eq!(bitset.slice(..4).count_ones(), 0);
+        assert_eq!(bitset.slice(92..).count_ones(), 0);
+
+        let mut slice = bitset.slice_mut(60..70);
+        slice
+            .fill(Bit::Zero)
+            .set_bit(0, Bit::One)
+            .set_bit(9, Bit::One);
+        assert_eq!(slice.to_string(), "1000000001");
+        assert_eq!(format!("{slice:?}"), "BitSliceMut(60..70, 0b1000000001)");
+        assert_eq!(bitset.count_ones(), 80);
+
+        let source = Bitset128::new(0xF0F0_F0F0_F0F0_F0F0_F0F0 << 3);
+        bitset.slice_mut(10..90).copy_from(source.slice(3..83));
+        assert_eq!(bitset.slice(10..90), source.slice(3..83));
+        assert_eq!(bitset.slice(4..10).count_ones(), 6);
+        assert_eq!(bitset.slice(90..92).count_ones(), 2);
+
+        let mut high = bitset.slice_mut(48..);
+        high.slice_mut(..8).fill(Bit::Zero);
+        assert_eq!(high.bit(7), Bit::Zero);
+        assert!(bitset.slice(48..56).ones().next().is_none());
+    }
+
+    #[test]
+    fn assign_ops() {
+        let mut bitset = Bitset16::new(0b1100_1100_1010_1010);
+        let mask = Bitset8::new(0b0000_1111);
+
+        let mut low = bitset.slice_mut(..8);
+        low &= mask.slice(..);
+        assert_eq!(bitset.into_inner(), 0b1100_1100_0000_1010);
+
+        let mut high = bitset.slice_mut(8..);
+        high |= mask.slice(..);
+        assert_eq!(bitset.into_inner(), 0b1100_1111_0000_1010);
+
+        let mut middle = bitset.slice_mut(4..12);
+        middle ^= Bitset8::ALL.slice(..);
+        assert_eq!(bitset.into_inner(), 0b1100_0000_1111_1010);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_from_different_length() {
+        let mut bitset = Bitset16::NONE;
+        bitset.slice_mut(..8).copy_from(Bitset8::ALL.slice(1..));
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_bit_out_of_range() {
+        let mut bitset = Bitset8::NONE;
+        bitset.slice_mut(..4).set_bit(4, Bit::One);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bit_out_of_range() {
+        let _ = Bitset8::ALL.slice(2..4).bit(2);
+    }
+}
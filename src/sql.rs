@@ -0,0 +1,304 @@
+//! Module containing database column type implementations for built-in [`Bitset`][crate::bitset::Bitset]s.
+//!
+//! `Bitset8` and `Bitset16` map to `SMALLINT`, `Bitset32` to `INTEGER` and `Bitset64` to `BIGINT` columns,
+//! reinterpreting bits of the unsigned inner value as signed.
+//! `Bitset128` and `Byteset` map to binary (`BYTEA`/`BLOB`) columns, with bytes in little-endian order.
+//!
+//! `diesel::serialize::ToSql` of `Bitset8` and `Bitset128` converts them into temporary values,
+//! so it's only implemented for backends, which write bind values into raw bytes, like PostgreSQL and MySQL.
+
+use crate::{
+    error::{ConvError, ConvResult, ConvTarget},
+    prelude::{Bitset128, Bitset16, Bitset32, Bitset64, Bitset8, Byteset},
+};
+
+// Converts the value of a `SMALLINT` column into `Bitset8`, rejecting values out of range of `u8`.
+fn bitset8_from_small_int(value: i16) -> ConvResult<Bitset8> {
+    u8::try_from(value)
+        .map(Bitset8::new)
+        .map_err(|_| ConvError::new(ConvTarget::Set(16), ConvTarget::Set(8)))
+}
+
+// Converts little-endian bytes of a binary column into `Bitset128`, rejecting values of the wrong length.
+fn bitset128_from_bytes(bytes: &[u8]) -> ConvResult<Bitset128> {
+    let inner = bytes
+        .try_into()
+        .map_err(|_| length_error(bytes.len(), 16))?;
+    Ok(Bitset128::new(u128::from_le_bytes(inner)))
+}
+
+// Converts bytes of a binary column into `Byteset`, rejecting values of the wrong length.
+fn byteset_from_bytes<const N: usize>(bytes: &[u8]) -> ConvResult<Byteset<N>> {
+    let inner = bytes.try_into().map_err(|_| length_error(bytes.len(), N))?;
+    Ok(Byteset::new(inner))
+}
+
+// Error of decoding a binary value of the wrong length.
+fn length_error(len: usize, expected: usize) -> ConvError {
+    ConvError::new(ConvTarget::Set(len * 8), ConvTarget::Set(expected * 8))
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use super::*;
+    use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+    // Implements sqlx traits for the integer-backed Bitset, delegating to the signed integer type.
+    macro_rules! impl_integer {
+        ($bitset:ty, $int:ty, $decode:expr) => {
+            impl<DB> Type<DB> for $bitset
+            where
+                DB: Database,
+                $int: Type<DB>,
+            {
+                fn type_info() -> DB::TypeInfo {
+                    <$int as Type<DB>>::type_info()
+                }
+
+                fn compatible(ty: &DB::TypeInfo) -> bool {
+                    <$int as Type<DB>>::compatible(ty)
+                }
+            }
+
+            impl<'q, DB> Encode<'q, DB> for $bitset
+            where
+                DB: Database,
+                $int: Encode<'q, DB>,
+            {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+                ) -> Result<IsNull, BoxDynError> {
+                    <$int as Encode<'q, DB>>::encode(self.0 as $int, buf)
+                }
+            }
+
+            impl<'r, DB> Decode<'r, DB> for $bitset
+            where
+                DB: Database,
+                $int: Decode<'r, DB>,
+            {
+                fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                    let decode: fn($int) -> Result<Self, BoxDynError> = $decode;
+                    decode(<$int as Decode<'r, DB>>::decode(value)?)
+                }
+            }
+        };
+    }
+
+    impl_integer!(Bitset8, i16, |v| Ok(bitset8_from_small_int(v)?));
+    impl_integer!(Bitset16, i16, |v| Ok(Self(v as u16)));
+    impl_integer!(Bitset32, i32, |v| Ok(Self(v as u32)));
+    impl_integer!(Bitset64, i64, |v| Ok(Self(v as u64)));
+
+    // Implements sqlx traits for the Bitset, stored as bytes.
+    macro_rules! impl_binary {
+        ([$($generics:tt)*], $bitset:ty, $to_bytes:expr, $from_bytes:expr) => {
+            impl<DB, $($generics)*> Type<DB> for $bitset
+            where
+                DB: Database,
+                Vec<u8>: Type<DB>,
+            {
+                fn type_info() -> DB::TypeInfo {
+                    <Vec<u8> as Type<DB>>::type_info()
+                }
+
+                fn compatible(ty: &DB::TypeInfo) -> bool {
+                    <Vec<u8> as Type<DB>>::compatible(ty)
+                }
+            }
+
+            impl<'q, DB, $($generics)*> Encode<'q, DB> for $bitset
+            where
+                DB: Database,
+                Vec<u8>: Encode<'q, DB>,
+            {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+                ) -> Result<IsNull, BoxDynError> {
+                    let to_bytes: fn(&Self) -> Vec<u8> = $to_bytes;
+                    <Vec<u8> as Encode<'q, DB>>::encode(to_bytes(self), buf)
+                }
+            }
+
+            impl<'r, DB, $($generics)*> Decode<'r, DB> for $bitset
+            where
+                DB: Database,
+                Vec<u8>: Decode<'r, DB>,
+            {
+                fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                    let bytes = <Vec<u8> as Decode<'r, DB>>::decode(value)?;
+                    let from_bytes: fn(&[u8]) -> ConvResult<Self> = $from_bytes;
+                    Ok(from_bytes(&bytes)?)
+                }
+            }
+        };
+    }
+
+    impl_binary!(
+        [],
+        Bitset128,
+        |b| b.0.to_le_bytes().to_vec(),
+        bitset128_from_bytes
+    );
+    impl_binary!(
+        [const N: usize],
+        Byteset<N>,
+        |b| b.0.to_vec(),
+        byteset_from_bytes
+    );
+}
+
+#[cfg(feature = "diesel")]
+mod diesel_impls {
+    use super::*;
+    use diesel::{
+        backend::Backend,
+        deserialize::{self, FromSql},
+        query_builder::bind_collector::RawBytesBindCollector,
+        serialize::{self, Output, ToSql},
+        sql_types::{BigInt, Binary, Integer, SmallInt},
+    };
+
+    impl<DB> ToSql<SmallInt, DB> for Bitset8
+    where
+        for<'c> DB: Backend<BindCollector<'c> = RawBytesBindCollector<DB>>,
+        i16: ToSql<SmallInt, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            (self.0 as i16).to_sql(&mut out.reborrow())
+        }
+    }
+
+    impl<DB> FromSql<SmallInt, DB> for Bitset8
+    where
+        DB: Backend,
+        i16: FromSql<SmallInt, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            Ok(bitset8_from_small_int(i16::from_sql(bytes)?)?)
+        }
+    }
+
+    // Implements diesel traits for the integer-backed Bitset, delegating to the signed integer type
+    // of the same size.
+    macro_rules! impl_integer {
+        ($bitset:ty, $sql:ty, $uint:ty, $int:ty) => {
+            impl<DB> ToSql<$sql, DB> for $bitset
+            where
+                DB: Backend,
+                $int: ToSql<$sql, DB>,
+            {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                    // SAFETY: signed and unsigned integers of the same size have the same layout.
+                    let value = unsafe { &*(&self.0 as *const $uint as *const $int) };
+                    value.to_sql(out)
+                }
+            }
+
+            impl<DB> FromSql<$sql, DB> for $bitset
+            where
+                DB: Backend,
+                $int: FromSql<$sql, DB>,
+            {
+                fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                    Ok(Self(<$int>::from_sql(bytes)? as $uint))
+                }
+            }
+        };
+    }
+
+    impl_integer!(Bitset16, SmallInt, u16, i16);
+    impl_integer!(Bitset32, Integer, u32, i32);
+    impl_integer!(Bitset64, BigInt, u64, i64);
+
+    impl<DB> ToSql<Binary, DB> for Bitset128
+    where
+        for<'c> DB: Backend<BindCollector<'c> = RawBytesBindCollector<DB>>,
+        [u8]: ToSql<Binary, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.0.to_le_bytes()[..].to_sql(&mut out.reborrow())
+        }
+    }
+
+    impl<DB> FromSql<Binary, DB> for Bitset128
+    where
+        DB: Backend,
+        Vec<u8>: FromSql<Binary, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            Ok(bitset128_from_bytes(&Vec::<u8>::from_sql(bytes)?)?)
+        }
+    }
+
+    impl<DB, const N: usize> ToSql<Binary, DB> for Byteset<N>
+    where
+        DB: Backend,
+        [u8]: ToSql<Binary, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.0[..].to_sql(out)
+        }
+    }
+
+    impl<DB, const N: usize> FromSql<Binary, DB> for Byteset<N>
+    where
+        DB: Backend,
+        Vec<u8>: FromSql<Binary, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            Ok(byteset_from_bytes(&Vec::<u8>::from_sql(bytes)?)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitset::Bitset;
+
+    #[test]
+    fn small_int() {
+        for value in 0..=255 {
+            let bitset = bitset8_from_small_int(value).unwrap();
+            assert_eq!(bitset.into_inner() as i16, value);
+        }
+        for value in [-1, 256, i16::MIN, i16::MAX] {
+            assert_eq!(
+                bitset8_from_small_int(value),
+                Err(ConvError::new(ConvTarget::Set(16), ConvTarget::Set(8)))
+            );
+        }
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let bitset = Bitset128::new(0x0102030405060708090A0B0C0D0E0F10);
+        let bytes = bitset.into_inner().to_le_bytes();
+
+        assert_eq!(bytes[0], 0x10);
+        assert_eq!(bitset128_from_bytes(&bytes), Ok(bitset));
+
+        let byteset = Byteset::<3>::new([1, 2, 3]);
+        assert_eq!(byteset_from_bytes::<3>(&byteset.into_inner()), Ok(byteset));
+        assert_eq!(byteset_from_bytes::<0>(&[]), Ok(Byteset::<0>::NONE));
+    }
+
+    #[test]
+    fn binary_wrong_length() {
+        assert_eq!(
+            bitset128_from_bytes(&[0; 15]),
+            Err(ConvError::new(ConvTarget::Set(120), ConvTarget::Set(128)))
+        );
+        assert_eq!(
+            bitset128_from_bytes(&[0; 17]),
+            Err(ConvError::new(ConvTarget::Set(136), ConvTarget::Set(128)))
+        );
+        assert_eq!(
+            byteset_from_bytes::<3>(&[1, 2]),
+            Err(ConvError::new(ConvTarget::Set(16), ConvTarget::Set(24)))
+        );
+    }
+}
@@ -0,0 +1,367 @@
+//! Module containing lazy combinators over streams of [`u64`] words: [`AndWords`], [`OrWords`]
+//! and [`WordOnes`].
+//!
+//! Words can be given as [`u64`]s or as any type convertible into it, like [`Bitset64`][crate::prelude::Bitset64].
+//! Word `i` of a stream holds bits `64 * i..64 * (i + 1)`.
+//! Combinators process one word at a time, so bitmaps can be combined and iterated over,
+//! without ever materializing them whole.
+//!
+//! Streams are fused, so once a stream ends, it is treated as ended for good.
+
+use std::iter::{Fuse, FusedIterator};
+
+/// Iterator over bitwise AND of words of multiple streams.
+///
+/// Ends, when any of the streams ends, as the missing words are treated as empty.
+///
+/// Returned by [`and`].
+#[derive(Debug, Clone)]
+pub struct AndWords<I> {
+    streams: Vec<Fuse<I>>,
+}
+
+/// Iterator over bitwise OR of words of multiple streams.
+///
+/// Ends, when all of the streams end, as the missing words are treated as empty.
+///
+/// Returned by [`or`].
+#[derive(Debug, Clone)]
+pub struct OrWords<I> {
+    streams: Vec<Fuse<I>>,
+}
+
+/// Iterator over indeces of the set bits in a stream of words.
+///
+/// Skips over not set bits a whole word at a time.
+#[derive(Debug, Clone)]
+pub struct WordOnes<I> {
+    words: Fuse<I>,
+    // Count of words taken from `words`.
+    taken: usize,
+    current: u64,
+}
+
+/// Lazily combines `streams` of words with bitwise AND.
+/// If there are no streams, resulting stream is empty.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::stream;
+///
+/// let a = vec![0b1100u64, !0, 0b1];
+/// let b = vec![0b0110u64, 0b1];
+///
+/// let words: Vec<u64> = stream::and([a, b]).collect();
+///
+/// assert_eq!(words, [0b0100, 0b1]);
+/// #   Ok(())
+/// # }
+/// ```
+pub fn and<S>(streams: impl IntoIterator<Item = S>) -> AndWords<S::IntoIter>
+where
+    S: IntoIterator,
+    S::Item: Into<u64>,
+{
+    AndWords {
+        streams: streams.into_iter().map(|s| s.into_iter().fuse()).collect(),
+    }
+}
+
+/// Lazily combines `streams` of words with bitwise OR.
+/// If there are no streams, resulting stream is empty.
+///
+/// # Examples
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use bitworks::{prelude::*, stream};
+///
+/// let a = vec![Bitset64::new(0b1100), Bitset64::new(0b1)];
+/// let b = vec![Bitset64::new(0b0110)];
+///
+/// let words: Vec<u64> = stream::or([a, b]).collect();
+///
+/// assert_eq!(words, [0b1110, 0b1]);
+/// #   Ok(())
+/// # }
+/// ```
+pub fn or<S>(streams: impl IntoIterator<Item = S>) -> OrWords<S::IntoIter>
+where
+    S: IntoIterator,
+    S::Item: Into<u64>,
+{
+    OrWords {
+        streams: streams.into_iter().map(|s| s.into_iter().fuse()).collect(),
+    }
+}
+
+impl<I> AndWords<I>
+where
+    I: Iterator,
+    I::Item: Into<u64>,
+{
+    /// Returns iterator over indeces of the set bits of the combined stream.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::stream;
+    ///
+    /// let a = vec![0b1100u64, 0b11];
+    /// let b = vec![0b0110u64, 0b10];
+    ///
+    /// let ones: Vec<usize> = stream::and([a, b]).ones().collect();
+    ///
+    /// assert_eq!(ones, [2, 65]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn ones(self) -> WordOnes<Self> {
+        WordOnes::new(self)
+    }
+}
+
+impl<I> OrWords<I>
+where
+    I: Iterator,
+    I::Item: Into<u64>,
+{
+    /// Returns iterator over indeces of the set bits of the combined stream.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::stream;
+    ///
+    /// let a = vec![0b0100u64];
+    /// let b = vec![0b0000u64, 0b10];
+    ///
+    /// let ones: Vec<usize> = stream::or([a, b]).ones().collect();
+    ///
+    /// assert_eq!(ones, [2, 65]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn ones(self) -> WordOnes<Self> {
+        WordOnes::new(self)
+    }
+}
+
+impl<I> Iterator for AndWords<I>
+where
+    I: Iterator,
+    I::Item: Into<u64>,
+{
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.streams.is_empty() {
+            return None;
+        }
+        let mut word = !0;
+        for stream in &mut self.streams {
+            word &= stream.next()?.into();
+        }
+        Some(word)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.streams
+            .iter()
+            .map(Iterator::size_hint)
+            .reduce(|(lo_a, hi_a), (lo_b, hi_b)| {
+                let hi = match (hi_a, hi_b) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (hi, None) | (None, hi) => hi,
+                };
+                (lo_a.min(lo_b), hi)
+            })
+            .unwrap_or((0, Some(0)))
+    }
+}
+
+impl<I> Iterator for OrWords<I>
+where
+    I: Iterator,
+    I::Item: Into<u64>,
+{
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut word = None;
+        for stream in &mut self.streams {
+            if let Some(w) = stream.next() {
+                word = Some(word.unwrap_or(0) | w.into());
+            }
+        }
+        word
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.streams
+            .iter()
+            .map(Iterator::size_hint)
+            .reduce(|(lo_a, hi_a), (lo_b, hi_b)| {
+                let hi = match (hi_a, hi_b) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    _ => None,
+                };
+                (lo_a.max(lo_b), hi)
+            })
+            .unwrap_or((0, Some(0)))
+    }
+}
+
+impl<I> FusedIterator for AndWords<I>
+where
+    I: Iterator,
+    I::Item: Into<u64>,
+{
+}
+
+impl<I> FusedIterator for OrWords<I>
+where
+    I: Iterator,
+    I::Item: Into<u64>,
+{
+}
+
+impl<I> WordOnes<I>
+where
+    I: Iterator,
+    I::Item: Into<u64>,
+{
+    /// Constructs a new value of `WordOnes`, iterating over the whole stream of `words`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use bitworks::stream::WordOnes;
+    ///
+    /// let ones: Vec<usize> = WordOnes::new([0b101u64, 0, 1 << 63].into_iter()).collect();
+    ///
+    /// assert_eq!(ones, [0, 2, 191]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn new(words: I) -> Self {
+        Self {
+            words: words.fuse(),
+            taken: 0,
+            current: 0,
+        }
+    }
+}
+
+impl<I> Iterator for WordOnes<I>
+where
+    I: Iterator,
+    I::Item: Into<u64>,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.current = self.words.next()?.into();
+            self.taken += 1;
+        }
+        let i = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some((self.taken - 1) * 64 + i)
+    }
+}
+
+impl<I> FusedIterator for WordOnes<I>
+where
+    I: Iterator,
+    I::Item: Into<u64>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Bitset, Bitset64, Byteset};
+
+    #[test]
+    fn and_or_words() {
+        let a = [0b1111u64, 0b1010, 0b1];
+        let b = [0b0101u64, 0b0110];
+
+        assert_eq!(
+            and([a.to_vec(), b.to_vec()]).collect::<Vec<_>>(),
+            [0b0101, 0b0010]
+        );
+        assert_eq!(
+            or([a.to_vec(), b.to_vec()]).collect::<Vec<_>>(),
+            [0b1111, 0b1110, 0b1]
+        );
+        assert_eq!(and([a.to_vec(), b.to_vec()]).size_hint(), (2, Some(2)));
+        assert_eq!(or([a.to_vec(), b.to_vec()]).size_hint(), (3, Some(3)));
+        assert_eq!(and(Vec::<Vec<u64>>::new()).next(), None);
+        assert_eq!(or(Vec::<Vec<u64>>::new()).next(), None);
+    }
+
+    #[test]
+    fn ones_match_bitset() {
+        let a = Byteset::<32>::from_ranges([3..70, 100..140, 250..256]);
+        let b = Byteset::<32>::from_ranges([0..10, 64..130, 200..256]);
+        let words = |bitset: &Byteset<32>| {
+            (0..4)
+                .map(|w| Bitset64::new(bitset._word(w)))
+                .collect::<Vec<_>>()
+        };
+
+        let expected: Vec<usize> = a.intersection(b).ones().map(|i| i.into_inner()).collect();
+        assert_eq!(
+            and([words(&a), words(&b)]).ones().collect::<Vec<_>>(),
+            expected
+        );
+
+        let expected: Vec<usize> = a.union(b).ones().map(|i| i.into_inner()).collect();
+        assert_eq!(
+            or([words(&a), words(&b)]).ones().collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    // Stream, which yields a word again after every `None`.
+    struct Flaky(bool);
+
+    impl Iterator for Flaky {
+        type Item = u64;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.0 = !self.0;
+            self.0.then_some(1)
+        }
+    }
+
+    #[test]
+    fn fused() {
+        let mut words = and([Flaky(false), Flaky(false)]);
+        assert_eq!(words.next(), Some(1));
+        assert_eq!(words.next(), None);
+        assert_eq!(words.next(), None);
+
+        let mut words = or([Flaky(false), Flaky(true)]);
+        assert_eq!(words.next(), Some(1));
+        assert_eq!(words.next(), None);
+        assert_eq!(words.next(), None);
+
+        let mut ones = WordOnes::new(Flaky(false));
+        assert_eq!(ones.next(), Some(0));
+        assert_eq!(ones.next(), None);
+        assert_eq!(ones.next(), None);
+    }
+}
@@ -0,0 +1,103 @@
+use bitworks::{bitset_struct::BitsetStruct, error::ConvError, prelude::*};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Data,
+    Ack,
+    Reset = 3,
+}
+
+impl From<Kind> for u64 {
+    fn from(kind: Kind) -> u64 {
+        kind as u64
+    }
+}
+
+impl TryFrom<u64> for Kind {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, ()> {
+        match value {
+            0 => Ok(Kind::Data),
+            1 => Ok(Kind::Ack),
+            3 => Ok(Kind::Reset),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, bitworks_derive::BitsetStruct)]
+#[bitset_struct(bitset = Bitset16)]
+struct Header {
+    #[bits(4)]
+    version: u8,
+    urgent: bool,
+    #[bits(2)]
+    kind: Kind,
+    #[bits(9)]
+    length: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, bitworks_derive::BitsetStruct)]
+#[bitset_struct(bitset = Byteset<12>)]
+struct Wide {
+    #[bits(30)]
+    low: u32,
+    #[bits(64)]
+    high: u64,
+}
+
+#[test]
+fn round_trip() -> Result<(), ConvError> {
+    let header = Header {
+        version: 0b1010,
+        urgent: true,
+        kind: Kind::Reset,
+        length: 0b100000001,
+    };
+    let bits = header.to_bits()?;
+
+    assert_eq!(bits, Bitset16::new(0b1000000011111010));
+    assert_eq!(Header::from_bits(&bits)?, header);
+    assert_eq!(Header::WIDTH, 16);
+
+    let wide = Wide {
+        low: 1 << 29,
+        high: u64::MAX,
+    };
+    let bits = wide.to_bits()?;
+
+    assert_eq!(bits.count_ones(), 65);
+    assert_eq!(Wide::from_bits(&bits)?, wide);
+    Ok(())
+}
+
+#[test]
+fn accessors() -> Result<(), ConvError> {
+    let mut bits = Bitset16::NONE;
+
+    Header::set_kind(&mut bits, &Kind::Ack)?;
+    Header::set_urgent(&mut bits, &true)?;
+
+    assert_eq!(bits, Bitset16::new(0b0110000));
+    assert_eq!(Header::get_kind(&bits)?, Kind::Ack);
+    assert!(Header::get_urgent(&bits)?);
+    assert_eq!(Header::get_length(&bits)?, 0);
+    Ok(())
+}
+
+#[test]
+fn overflow() {
+    let header = Header {
+        version: 16,
+        urgent: false,
+        kind: Kind::Data,
+        length: 0,
+    };
+
+    let mut bits = Bitset16::NONE;
+
+    assert!(header.to_bits().is_err());
+    assert!(Header::set_length(&mut bits, &512).is_err());
+    assert!(Header::get_kind(&Bitset16::new(0b1000000)).is_err());
+}
@@ -0,0 +1,43 @@
+use bitworks::{flags_enum::FlagsEnum, prelude::*};
+
+#[derive(Debug, Clone, Copy, PartialEq, bitworks_derive::FlagsEnum)]
+enum Color {
+    Red,
+    Green,
+    Blue = 5,
+    Alpha,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, bitworks_derive::FlagsEnum)]
+#[flags_enum(bitset = Byteset<4>)]
+enum Wide {
+    Last = 31,
+    First = 0,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, bitworks_derive::FlagsEnum)]
+enum Big {
+    Low,
+    High = 100,
+}
+
+#[test]
+fn derived() {
+    assert_eq!(Color::Red.index(), Index8::from_usize(0));
+    assert_eq!(Color::Green.index(), Index8::from_usize(1));
+    assert_eq!(Color::Alpha.bitset(), Bitset8::new(0b1000000));
+    assert_eq!(
+        Color::VARIANTS,
+        [Color::Red, Color::Green, Color::Blue, Color::Alpha]
+    );
+    assert_eq!(
+        Color::try_from_index(Index8::from_usize(5)),
+        Some(Color::Blue)
+    );
+
+    assert_eq!(Wide::VARIANTS, [Wide::First, Wide::Last]);
+    assert_eq!(Wide::Last.bitset(), Byteset::new([0, 0, 0, 0b10000000]));
+
+    assert_eq!(Big::High.bitset(), Bitset128::new(1 << 100));
+    assert_eq!(Color::Alpha.name(), "Alpha");
+}